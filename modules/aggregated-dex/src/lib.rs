@@ -17,6 +17,17 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 //! # Aggregated DEX Module
+//!
+//! ## Overview
+//!
+//! Aggregated DEX module swaps through a combination of Acala DEX and Taiga stable asset pools.
+//!
+//! It also maintains limit orders and TWAP orders. The owner reserves the supply currency and a
+//! deposit in native currency when placing the order, and the order is matched against the pools
+//! by `module_idle_scheduler` in `on_idle`. Limit orders are filled (partially if the pools cannot
+//! absorb the whole remaining amount) once the price reaches the limit price, TWAP orders swap a
+//! fixed amount every interval. Orders can be cancelled by the owner, and the remaining reserved
+//! amount and the deposit are released when the order is closed, at the latest when it expires.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -24,11 +35,18 @@
 
 use frame_support::{pallet_prelude::*, transactional};
 use frame_system::pallet_prelude::*;
-use module_support::{AggregatedSwapPath, DEXManager, RebasedStableAssetError, Swap, SwapLimit};
+use module_support::{
+	AggregatedSwapPath, DEXManager, DispatchableTask, IdleScheduler, Price, RebasedStableAssetError, Swap, SwapLimit,
+};
 use nutsfinance_stable_asset::traits::StableAsset as StableAssetT;
-use primitives::{Balance, CurrencyId};
-use sp_runtime::traits::{Convert, Zero};
-use sp_std::{marker::PhantomData, vec::Vec};
+use orml_traits::NamedMultiReservableCurrency;
+use parity_scale_codec::FullCodec;
+use primitives::{task::TaskResult, Balance, CurrencyId, Nonce, ReserveIdentifier};
+use sp_runtime::{
+	traits::{Convert, Saturating, Zero},
+	ArithmeticError, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 mod mock;
 mod tests;
@@ -38,13 +56,68 @@ pub use module::*;
 pub use weights::WeightInfo;
 
 pub type SwapPath = AggregatedSwapPath<CurrencyId>;
+pub type OrderId = u64;
+
+/// The maximum number of halvings tried to find a partial fill for a limit order.
+pub const MAX_PARTIAL_FILL_ATTEMPTS: u32 = 5;
+
+/// The kind of an order.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum OrderKind<BlockNumber> {
+	/// Swap as much as possible once the price reaches the limit price.
+	Limit,
+	/// Swap `amount_per_interval` every `interval` blocks, as long as the price is not below the
+	/// limit price.
+	Twap {
+		interval: BlockNumber,
+		amount_per_interval: Balance,
+		next_execution: BlockNumber,
+	},
+}
+
+/// An order waiting to be matched against the pools.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct Order<BlockNumber> {
+	/// The aggregated swap paths used to fill the order.
+	pub paths: Vec<SwapPath>,
+	/// The supply currency, reserved from the owner.
+	pub supply_currency_id: CurrencyId,
+	/// The target currency.
+	pub target_currency_id: CurrencyId,
+	/// The supply amount still reserved and waiting to be swapped.
+	pub remaining_supply_amount: Balance,
+	/// The supply amount already swapped.
+	pub filled_supply_amount: Balance,
+	/// The target amount already received.
+	pub received_target_amount: Balance,
+	/// The minimum target amount per unit of supply currency.
+	pub limit_price: Price,
+	/// The kind of the order.
+	pub kind: OrderKind<BlockNumber>,
+	/// The order is closed and the remaining amount unreserved at this block.
+	pub expiry: BlockNumber,
+	/// The native currency reserved from the owner for the storage of the order.
+	pub deposit: Balance,
+}
 
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
+	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::AggregatedDex;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency to reserve the supply amount of orders
+		type Currency: NamedMultiReservableCurrency<
+			Self::AccountId,
+			CurrencyId = CurrencyId,
+			Balance = Balance,
+			ReserveIdentifier = ReserveIdentifier,
+		>;
+
 		/// DEX
 		type DEX: DEXManager<Self::AccountId, Balance, CurrencyId>;
 
@@ -68,6 +141,32 @@ pub mod module {
 		#[pallet::constant]
 		type SwapPathLimit: Get<u32>;
 
+		/// The maximum number of open orders per account
+		#[pallet::constant]
+		type MaxOrdersPerAccount: Get<u32>;
+
+		/// The maximum number of blocks that an order can stay open
+		#[pallet::constant]
+		type MaxOrderDuration: Get<BlockNumberFor<Self>>;
+
+		/// The deposit reserved in native currency for the storage of an order
+		#[pallet::constant]
+		type OrderDeposit: Get<Balance>;
+
+		/// The native currency id
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// The limit for length of trading path of DEX, the same as `TradingPathLimit` of DEX
+		#[pallet::constant]
+		type DexTradingPathLimit: Get<u32>;
+
+		/// Dispatchable tasks
+		type Task: DispatchableTask + FullCodec + Debug + Clone + PartialEq + TypeInfo + From<AggregatedDexTask<Self>>;
+
+		/// Idle scheduler to match the orders.
+		type IdleScheduler: IdleScheduler<Nonce, Self::Task>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -81,6 +180,47 @@ pub mod module {
 		InvalidTokenIndex,
 		/// The SwapPath is invalid.
 		InvalidSwapPath,
+		/// The order amount is invalid.
+		InvalidOrderAmount,
+		/// The expiry of the order is invalid.
+		InvalidExpiry,
+		/// The interval or amount per interval of the TWAP order is invalid.
+		InvalidTwapParameters,
+		/// The account has too many open orders.
+		TooManyOrders,
+		/// The order does not exist.
+		OrderNotFound,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An order is placed.
+		OrderPlaced {
+			owner: T::AccountId,
+			order_id: OrderId,
+			order: Order<BlockNumberFor<T>>,
+		},
+		/// An order is filled, maybe partially.
+		OrderFilled {
+			owner: T::AccountId,
+			order_id: OrderId,
+			supply_amount: Balance,
+			target_amount: Balance,
+			remaining_supply_amount: Balance,
+		},
+		/// An order is cancelled by the owner.
+		OrderCancelled {
+			owner: T::AccountId,
+			order_id: OrderId,
+			unreserved_amount: Balance,
+		},
+		/// An order is expired.
+		OrderExpired {
+			owner: T::AccountId,
+			order_id: OrderId,
+			unreserved_amount: Balance,
+		},
 	}
 
 	/// The specific swap paths for  AggregatedSwap do aggreated_swap to swap TokenA to TokenB
@@ -91,6 +231,21 @@ pub mod module {
 	pub type AggregatedSwapPaths<T: Config> =
 		StorageMap<_, Twox64Concat, (CurrencyId, CurrencyId), BoundedVec<SwapPath, T::SwapPathLimit>, OptionQuery>;
 
+	/// The open orders.
+	///
+	/// Orders: double_map (owner: AccountId, order_id: OrderId) => Order
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, OrderId, Order<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The order id used to index orders.
+	///
+	/// NextOrderId: OrderId
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
@@ -111,7 +266,7 @@ pub mod module {
 				SwapPath::Dex(v) => u + (v.len() as u32),
 				SwapPath::Taiga(_, _, _) => u + 1
 			})
-		))]
+		).saturating_add(Pallet::<T>::concentrated_swap_weight(paths)))]
 		pub fn swap_with_exact_supply(
 			origin: OriginFor<T>,
 			paths: Vec<SwapPath>,
@@ -131,7 +286,7 @@ pub mod module {
 				SwapPath::Dex(v) => u + (v.len() as u32),
 				SwapPath::Taiga(_, _, _) => u + 1
 			})
-		))]
+		).saturating_add(Pallet::<T>::concentrated_swap_weight(paths)))]
 		pub fn swap_with_exact_target(
 			origin: OriginFor<T>,
 			paths: Vec<SwapPath>,
@@ -176,10 +331,304 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Place a limit order. The supply amount is reserved, and the order is filled in
+		/// `on_idle` once the paths can swap at least `limit_price` target per supply.
+		///
+		/// - `paths`: aggregated swap path.
+		/// - `supply_amount`: total supply amount of the order.
+		/// - `limit_price`: minimum target amount per unit of supply amount.
+		/// - `expiry`: the block at which the order expires.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_limit_order())]
+		pub fn place_limit_order(
+			origin: OriginFor<T>,
+			paths: Vec<SwapPath>,
+			#[pallet::compact] supply_amount: Balance,
+			limit_price: Price,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_place_order(&who, paths, supply_amount, limit_price, OrderKind::Limit, expiry)?;
+			Ok(())
+		}
+
+		/// Place a TWAP order. The supply amount is reserved, and `amount_per_interval` is swapped
+		/// in `on_idle` every `interval` blocks, skipping the interval if the paths cannot swap at
+		/// least `limit_price` target per supply.
+		///
+		/// - `paths`: aggregated swap path.
+		/// - `supply_amount`: total supply amount of the order.
+		/// - `amount_per_interval`: supply amount swapped every interval.
+		/// - `interval`: the number of blocks between two swaps.
+		/// - `limit_price`: minimum target amount per unit of supply amount.
+		/// - `expiry`: the block at which the order expires.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_twap_order())]
+		pub fn place_twap_order(
+			origin: OriginFor<T>,
+			paths: Vec<SwapPath>,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] amount_per_interval: Balance,
+			interval: BlockNumberFor<T>,
+			limit_price: Price,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!interval.is_zero() && !amount_per_interval.is_zero(),
+				Error::<T>::InvalidTwapParameters
+			);
+			let kind = OrderKind::Twap {
+				interval,
+				amount_per_interval,
+				next_execution: frame_system::Pallet::<T>::block_number(),
+			};
+			Self::do_place_order(&who, paths, supply_amount, limit_price, kind, expiry)?;
+			Ok(())
+		}
+
+		/// Cancel an open order and unreserve the remaining supply amount and the deposit.
+		///
+		/// - `order_id`: the id of the order.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = Orders::<T>::take(&who, order_id).ok_or(Error::<T>::OrderNotFound)?;
+			let unreserved_amount = Self::unreserve_order(&who, &order);
+
+			Self::deposit_event(Event::OrderCancelled {
+				owner: who,
+				order_id,
+				unreserved_amount,
+			});
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_place_order(
+		who: &T::AccountId,
+		paths: Vec<SwapPath>,
+		supply_amount: Balance,
+		limit_price: Price,
+		kind: OrderKind<BlockNumberFor<T>>,
+		expiry: BlockNumberFor<T>,
+	) -> sp_std::result::Result<OrderId, DispatchError> {
+		ensure!(!supply_amount.is_zero(), Error::<T>::InvalidOrderAmount);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			expiry > now && expiry <= now.saturating_add(T::MaxOrderDuration::get()),
+			Error::<T>::InvalidExpiry
+		);
+		ensure!(
+			paths.len() <= T::SwapPathLimit::get() as usize,
+			Error::<T>::InvalidSwapPath
+		);
+		let (supply_currency_id, target_currency_id) = Self::check_swap_paths(&paths)?;
+		ensure!(
+			paths.iter().all(|path| match path {
+				SwapPath::Dex(dex_path) => dex_path.len() <= T::DexTradingPathLimit::get() as usize,
+				SwapPath::Taiga(_, _, _) => true,
+			}),
+			Error::<T>::InvalidSwapPath
+		);
+		// the paths must be able to swap now, the limit price is checked when matching.
+		ensure!(
+			Self::get_aggregated_swap_amount(&paths, SwapLimit::ExactSupply(supply_amount, Zero::zero())).is_some(),
+			Error::<T>::CannotSwap
+		);
+		ensure!(
+			(Orders::<T>::iter_prefix_values(who).count() as u32) < T::MaxOrdersPerAccount::get(),
+			Error::<T>::TooManyOrders
+		);
+
+		let deposit = T::OrderDeposit::get();
+		T::Currency::reserve_named(&RESERVE_ID, T::GetNativeCurrencyId::get(), who, deposit)?;
+		T::Currency::reserve_named(&RESERVE_ID, supply_currency_id, who, supply_amount)?;
+
+		let order_id = NextOrderId::<T>::get();
+		NextOrderId::<T>::put(order_id.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+
+		let order = Order {
+			paths,
+			supply_currency_id,
+			target_currency_id,
+			remaining_supply_amount: supply_amount,
+			filled_supply_amount: Zero::zero(),
+			received_target_amount: Zero::zero(),
+			limit_price,
+			kind,
+			expiry,
+			deposit,
+		};
+		Orders::<T>::insert(who, order_id, &order);
+
+		T::IdleScheduler::schedule(
+			AggregatedDexTask::MatchOrder {
+				owner: who.clone(),
+				order_id,
+			}
+			.into(),
+		)?;
+
+		Self::deposit_event(Event::OrderPlaced {
+			owner: who.clone(),
+			order_id,
+			order,
+		});
+		Ok(order_id)
+	}
+
+	/// Try to fill the order. Returns whether the order is closed.
+	fn do_match_order(who: &T::AccountId, order_id: OrderId) -> sp_std::result::Result<bool, DispatchError> {
+		let mut order = match Orders::<T>::get(who, order_id) {
+			Some(order) => order,
+			// cancelled
+			None => return Ok(true),
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if now >= order.expiry {
+			Orders::<T>::remove(who, order_id);
+			let unreserved_amount = Self::unreserve_order(who, &order);
+
+			Self::deposit_event(Event::OrderExpired {
+				owner: who.clone(),
+				order_id,
+				unreserved_amount,
+			});
+			return Ok(true);
+		}
+
+		let fill_amount = match order.kind {
+			OrderKind::Limit => Self::get_limit_order_fill_amount(&order),
+			OrderKind::Twap {
+				interval,
+				amount_per_interval,
+				next_execution,
+			} => {
+				if now < next_execution {
+					return Ok(false);
+				}
+				order.kind = OrderKind::Twap {
+					interval,
+					amount_per_interval,
+					next_execution: now.saturating_add(interval),
+				};
+
+				let amount = amount_per_interval.min(order.remaining_supply_amount);
+				Self::get_aggregated_swap_amount(
+					&order.paths,
+					SwapLimit::ExactSupply(amount, order.limit_price.saturating_mul_int(amount)),
+				)
+				.map(|_| amount)
+			}
+		};
+
+		if let Some(supply_amount) = fill_amount {
+			let (_, target_amount) = Self::do_fill_order(who, &order, supply_amount)?;
+			order.remaining_supply_amount = order.remaining_supply_amount.saturating_sub(supply_amount);
+			order.filled_supply_amount = order.filled_supply_amount.saturating_add(supply_amount);
+			order.received_target_amount = order.received_target_amount.saturating_add(target_amount);
+
+			Self::deposit_event(Event::OrderFilled {
+				owner: who.clone(),
+				order_id,
+				supply_amount,
+				target_amount,
+				remaining_supply_amount: order.remaining_supply_amount,
+			});
+		} else if matches!(order.kind, OrderKind::Limit) {
+			// nothing changed
+			return Ok(false);
+		}
+
+		if order.remaining_supply_amount.is_zero() {
+			Orders::<T>::remove(who, order_id);
+			Self::unreserve_order(who, &order);
+			Ok(true)
+		} else {
+			Orders::<T>::insert(who, order_id, order);
+			Ok(false)
+		}
+	}
+
+	/// Find the largest amount, halving the remaining supply amount, that meets the limit price.
+	fn get_limit_order_fill_amount(order: &Order<BlockNumberFor<T>>) -> Option<Balance> {
+		let mut amount = order.remaining_supply_amount;
+		for _ in 0..MAX_PARTIAL_FILL_ATTEMPTS {
+			if amount.is_zero() {
+				break;
+			}
+			if Self::get_aggregated_swap_amount(
+				&order.paths,
+				SwapLimit::ExactSupply(amount, order.limit_price.saturating_mul_int(amount)),
+			)
+			.is_some()
+			{
+				return Some(amount);
+			}
+			amount /= 2;
+		}
+		None
+	}
+
+	#[transactional]
+	fn do_fill_order(
+		who: &T::AccountId,
+		order: &Order<BlockNumberFor<T>>,
+		supply_amount: Balance,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let remaining = T::Currency::unreserve_named(&RESERVE_ID, order.supply_currency_id, who, supply_amount);
+		ensure!(remaining.is_zero(), Error::<T>::CannotSwap);
+
+		Self::do_aggregated_swap(
+			who,
+			&order.paths,
+			SwapLimit::ExactSupply(supply_amount, order.limit_price.saturating_mul_int(supply_amount)),
+		)
+	}
+
+	/// Unreserve the remaining supply amount and the deposit of the closed order, returns the
+	/// unreserved supply amount.
+	fn unreserve_order(who: &T::AccountId, order: &Order<BlockNumberFor<T>>) -> Balance {
+		T::Currency::unreserve_named(&RESERVE_ID, T::GetNativeCurrencyId::get(), who, order.deposit);
+		let remaining = T::Currency::unreserve_named(
+			&RESERVE_ID,
+			order.supply_currency_id,
+			who,
+			order.remaining_supply_amount,
+		);
+		order.remaining_supply_amount.saturating_sub(remaining)
+	}
+
+	/// Get the weight of matching an order by `paths`. At worst all the attempts to find the fill
+	/// amount fail but the last one, and then the order is filled. Every attempt and the swap can
+	/// go through the ticks of the concentrated liquidity pools on `paths`.
+	pub fn match_order_weight(paths: &[SwapPath]) -> Weight {
+		let u = paths.iter().fold(0, |u, swap_path| match swap_path {
+			SwapPath::Dex(v) => u + (v.len() as u32),
+			SwapPath::Taiga(_, _, _) => u + 1,
+		});
+		<T as Config>::WeightInfo::match_order(u)
+			.saturating_add(<T as Config>::WeightInfo::swap_with_exact_supply(u))
+			.saturating_add(
+				Self::concentrated_swap_weight(paths)
+					.saturating_mul(MAX_PARTIAL_FILL_ATTEMPTS.saturating_add(1).into()),
+			)
+	}
+
+	/// Get the extra weight of swapping by `paths` through the concentrated liquidity pools on them.
+	pub fn concentrated_swap_weight(paths: &[SwapPath]) -> Weight {
+		paths.iter().fold(Weight::zero(), |weight, swap_path| match swap_path {
+			SwapPath::Dex(path) => weight.saturating_add(T::DEX::get_concentrated_swap_weight(path)),
+			SwapPath::Taiga(_, _, _) => weight,
+		})
+	}
+
 	fn check_swap_paths(paths: &[SwapPath]) -> sp_std::result::Result<(CurrencyId, CurrencyId), DispatchError> {
 		ensure!(!paths.is_empty(), Error::<T>::InvalidSwapPath);
 		let mut supply_currency_id: Option<CurrencyId> = None;
@@ -240,7 +689,10 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 
-	fn get_aggregated_swap_amount(paths: &[SwapPath], swap_limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+	pub fn get_aggregated_swap_amount(
+		paths: &[SwapPath],
+		swap_limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
 		Self::check_swap_paths(paths).ok()?;
 
 		match swap_limit {
@@ -378,6 +830,54 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum AggregatedDexTask<T: Config> {
+	/// Match the order against the pools.
+	MatchOrder { owner: T::AccountId, order_id: OrderId },
+}
+
+impl<T: Config> DispatchableTask for AggregatedDexTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			AggregatedDexTask::MatchOrder { owner, order_id } => {
+				let used_weight = Pallet::<T>::orders(&owner, order_id).map_or_else(
+					|| T::DbWeight::get().reads(1),
+					|order| Pallet::<T>::match_order_weight(&order.paths),
+				);
+				if weight.any_lt(used_weight) {
+					// wait for the next block
+					return TaskResult {
+						result: Ok(()),
+						used_weight: Weight::zero(),
+						finished: false,
+					};
+				}
+
+				match Pallet::<T>::do_match_order(&owner, order_id) {
+					Ok(finished) => TaskResult {
+						result: Ok(()),
+						used_weight,
+						finished,
+					},
+					// the order will be retried, and closed at expiry at the latest.
+					Err(e) => TaskResult {
+						result: Err(e),
+						used_weight,
+						finished: false,
+					},
+				}
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: Config> From<AggregatedDexTask<T>> for () {
+	fn from(_task: AggregatedDexTask<T>) -> Self {
+		unimplemented!()
+	}
+}
+
 /// Swap by Acala DEX which has specific joints.
 pub struct DexSwap<T>(PhantomData<T>);
 impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for DexSwap<T> {
@@ -429,6 +929,19 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for DexSwap<T> {
 	) -> Result<(Balance, Balance), DispatchError> {
 		Err(Error::<T>::CannotSwap.into())
 	}
+
+	// The best price path is one of the direct path and the paths via joints.
+	fn get_concentrated_swap_weight(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Weight {
+		T::DexSwapJointList::get().into_iter().fold(
+			T::DEX::get_concentrated_swap_weight(&[supply_currency_id, target_currency_id]),
+			|weight, joint| {
+				let mut path = sp_std::vec![supply_currency_id];
+				path.extend(joint);
+				path.push(target_currency_id);
+				weight.max(T::DEX::get_concentrated_swap_weight(&path))
+			},
+		)
+	}
 }
 
 /// Swap by Taiga pool.
@@ -630,6 +1143,10 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for EitherDexOrTaigaSwap
 	) -> Result<(Balance, Balance), DispatchError> {
 		Err(Error::<T>::CannotSwap.into())
 	}
+
+	fn get_concentrated_swap_weight(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Weight {
+		DexSwap::<T>::get_concentrated_swap_weight(supply_currency_id, target_currency_id)
+	}
 }
 
 /// Choose the best price to execute swap:
@@ -734,6 +1251,17 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for AggregatedSwap<T> {
 	) -> Result<(Balance, Balance), DispatchError> {
 		Pallet::<T>::do_aggregated_swap(who, swap_path, limit)
 	}
+
+	fn get_concentrated_swap_weight(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Weight {
+		let dex_weight = DexSwap::<T>::get_concentrated_swap_weight(supply_currency_id, target_currency_id);
+		Pallet::<T>::aggregated_swap_paths((supply_currency_id, target_currency_id)).map_or(dex_weight, |paths| {
+			dex_weight.max(Pallet::<T>::concentrated_swap_weight(&paths))
+		})
+	}
+
+	fn get_concentrated_swap_weight_by_aggregated_path(swap_path: &[SwapPath]) -> Weight {
+		Pallet::<T>::concentrated_swap_weight(swap_path)
+	}
 }
 
 pub struct RebasedStableAssetErrorConvertor<T>(PhantomData<T>);
@@ -30,8 +30,11 @@ use frame_system::EnsureSignedBy;
 pub use module_support::{ExchangeRate, RebasedStableAsset};
 use orml_tokens::ConvertBalance;
 pub use orml_traits::{parameter_type_with_key, MultiCurrency};
-use primitives::{Amount, TokenSymbol, TradingPair};
-use sp_runtime::{traits::IdentityLookup, AccountId32, ArithmeticError, BuildStorage, FixedPointNumber};
+use primitives::{define_combined_task, Amount, BlockNumber, TokenSymbol, TradingPair};
+use sp_runtime::{
+	traits::{BlockNumberProvider, IdentityLookup},
+	AccountId32, ArithmeticError, BuildStorage, FixedPointNumber,
+};
 
 pub type AccountId = AccountId32;
 
@@ -41,6 +44,7 @@ mod aggregated_dex {
 
 pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
 pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
@@ -69,8 +73,8 @@ impl orml_tokens::Config for Runtime {
 	type ExistentialDeposits = ExistentialDeposits;
 	type CurrencyHooks = ();
 	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -81,6 +85,7 @@ ord_parameter_types! {
 parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
+	pub const TreasuryAccount: AccountId = AccountId32::new([10u8; 32]);
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![];
 }
 
@@ -96,6 +101,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Admin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type TreasuryAccount = TreasuryAccount;
+	type FlashSwapEvmBridge = ();
 }
 
 pub struct EnsurePoolAssetId;
@@ -170,12 +177,53 @@ parameter_types! {
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 }
 
+define_combined_task! {
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum ScheduledTasks {
+		AggregatedDexTask(AggregatedDexTask<Runtime>),
+	}
+}
+
+pub struct MockBlockNumberProvider;
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Zero::zero()
+	}
+}
+
+parameter_types! {
+	pub MinimumWeightRemainInBlock: Weight = Weight::zero();
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Index = Nonce;
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = MinimumWeightRemainInBlock;
+	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
+	type DisableBlockThreshold = ConstU32<6>;
+}
+
 impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
 	type DEX = Dex;
 	type StableAsset = StableAssetWrapper;
 	type GovernanceOrigin = EnsureSignedBy<Admin, AccountId>;
 	type DexSwapJointList = DexSwapJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxOrdersPerAccount = ConstU32<2>;
+	type MaxOrderDuration = ConstU64<100>;
+	type OrderDeposit = ConstU128<1_000>;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type DexTradingPathLimit = ConstU32<4>;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -191,6 +239,7 @@ frame_support::construct_runtime!(
 		Dex: module_dex,
 		Tokens: orml_tokens,
 		StableAsset: nutsfinance_stable_asset,
+		IdleScheduler: module_idle_scheduler,
 	}
 );
 
@@ -24,6 +24,7 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::*;
 use nutsfinance_stable_asset::traits::StableAsset as StableAssetT;
+use orml_traits::{MultiReservableCurrency, NamedMultiReservableCurrency};
 use sp_runtime::traits::BadOrigin;
 
 fn set_dex_swap_joint_list(joints: Vec<Vec<CurrencyId>>) {
@@ -1301,3 +1302,312 @@ fn aggregated_swap_swap_work() {
 		);
 	});
 }
+
+#[test]
+fn limit_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 1_000_000u128));
+		let limit_price = Price::saturating_from_rational(95, 100);
+
+		// no native currency for the deposit
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				100_000,
+				limit_price,
+				10
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+		assert_ok!(Tokens::deposit(ACA, &ALICE, 10_000));
+
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				0,
+				limit_price,
+				10
+			),
+			Error::<Runtime>::InvalidOrderAmount
+		);
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				100_000,
+				limit_price,
+				1
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				100_000,
+				limit_price,
+				102
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, DOT])],
+				100_000,
+				limit_price,
+				10
+			),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD, LDOT, DOT, AUSD])],
+				100_000,
+				limit_price,
+				10
+			),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, LDOT])],
+				100_000,
+				limit_price,
+				10
+			),
+			Error::<Runtime>::CannotSwap
+		);
+
+		assert_ok!(AggregatedDex::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			vec![SwapPath::Dex(vec![DOT, AUSD])],
+			100_000,
+			limit_price,
+			10
+		));
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 100_000);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 1_000);
+		assert_eq!(Tokens::reserved_balance_named(&RESERVE_ID, DOT, &ALICE), 100_000);
+		assert_eq!(Tokens::reserved_balance_named(&RESERVE_ID, ACA, &ALICE), 1_000);
+		assert_eq!(AggregatedDex::next_order_id(), 1);
+		assert_eq!(
+			IdleScheduler::tasks(0),
+			Some(ScheduledTasks::AggregatedDexTask(AggregatedDexTask::MatchOrder {
+				owner: ALICE,
+				order_id: 0
+			}))
+		);
+
+		// cancel the order
+		assert_ok!(AggregatedDex::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			vec![SwapPath::Dex(vec![DOT, AUSD])],
+			100_000,
+			limit_price,
+			10
+		));
+		assert_noop!(
+			AggregatedDex::place_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				100_000,
+				limit_price,
+				10
+			),
+			Error::<Runtime>::TooManyOrders
+		);
+		assert_noop!(
+			AggregatedDex::cancel_order(RuntimeOrigin::signed(BOB), 1),
+			Error::<Runtime>::OrderNotFound
+		);
+		assert_ok!(AggregatedDex::cancel_order(RuntimeOrigin::signed(ALICE), 1));
+		System::assert_last_event(RuntimeEvent::AggregatedDex(crate::Event::OrderCancelled {
+			owner: ALICE,
+			order_id: 1,
+			unreserved_amount: 100_000,
+		}));
+		assert_eq!(AggregatedDex::orders(ALICE, 1), None);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 100_000);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 1_000);
+
+		// partially filled, the whole amount exceeds the limit price
+		IdleScheduler::on_idle(1, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::AggregatedDex(crate::Event::OrderFilled {
+			owner: ALICE,
+			order_id: 0,
+			supply_amount: 50_000,
+			target_amount: 47_619,
+			remaining_supply_amount: 50_000,
+		}));
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 50_000);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 47_619);
+		assert_eq!(IdleScheduler::tasks(1), None);
+		let order = AggregatedDex::orders(ALICE, 0).unwrap();
+		assert_eq!(order.remaining_supply_amount, 50_000);
+		assert_eq!(order.filled_supply_amount, 50_000);
+		assert_eq!(order.received_target_amount, 47_619);
+
+		// the price is below the limit price
+		System::set_block_number(2);
+		IdleScheduler::on_idle(2, Weight::MAX);
+		assert_eq!(AggregatedDex::orders(ALICE, 0), Some(order));
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 50_000);
+
+		// the price recovers and the order is filled
+		assert_ok!(Dex::swap_with_exact_supply(
+			RuntimeOrigin::signed(BOB),
+			vec![AUSD, DOT],
+			200_000,
+			0
+		));
+		System::set_block_number(3);
+		IdleScheduler::on_idle(3, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::AggregatedDex(crate::Event::OrderFilled {
+			owner: ALICE,
+			order_id: 0,
+			supply_amount: 50_000,
+			target_amount: 62_781,
+			remaining_supply_amount: 0,
+		}));
+		assert_eq!(AggregatedDex::orders(ALICE, 0), None);
+		assert_eq!(IdleScheduler::tasks(0), None);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 110_400);
+
+		// expired
+		assert_ok!(AggregatedDex::place_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			vec![SwapPath::Dex(vec![DOT, AUSD])],
+			100_000,
+			Price::saturating_from_integer(2),
+			5
+		));
+		IdleScheduler::on_idle(3, Weight::MAX);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 100_000);
+		System::set_block_number(5);
+		IdleScheduler::on_idle(5, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::AggregatedDex(crate::Event::OrderExpired {
+			owner: ALICE,
+			order_id: 2,
+			unreserved_amount: 100_000,
+		}));
+		assert_eq!(AggregatedDex::orders(ALICE, 2), None);
+		assert_eq!(IdleScheduler::tasks(2), None);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(ACA, &ALICE), 10_000);
+	});
+}
+
+#[test]
+fn twap_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 1_000_000u128));
+		assert_ok!(Tokens::deposit(ACA, &ALICE, 10_000));
+		let limit_price = Price::saturating_from_rational(97, 100);
+
+		assert_noop!(
+			AggregatedDex::place_twap_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				30_000,
+				10_000,
+				0,
+				limit_price,
+				8
+			),
+			Error::<Runtime>::InvalidTwapParameters
+		);
+		assert_noop!(
+			AggregatedDex::place_twap_order(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, AUSD])],
+				30_000,
+				0,
+				2,
+				limit_price,
+				8
+			),
+			Error::<Runtime>::InvalidTwapParameters
+		);
+
+		assert_ok!(AggregatedDex::place_twap_order(
+			RuntimeOrigin::signed(ALICE),
+			vec![SwapPath::Dex(vec![DOT, AUSD])],
+			30_000,
+			10_000,
+			2,
+			limit_price,
+			8
+		));
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 30_000);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 1_000);
+
+		IdleScheduler::on_idle(1, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::AggregatedDex(crate::Event::OrderFilled {
+			owner: ALICE,
+			order_id: 0,
+			supply_amount: 10_000,
+			target_amount: 9_900,
+			remaining_supply_amount: 20_000,
+		}));
+		assert_eq!(
+			AggregatedDex::orders(ALICE, 0).unwrap().kind,
+			OrderKind::Twap {
+				interval: 2,
+				amount_per_interval: 10_000,
+				next_execution: 3,
+			}
+		);
+
+		// wait for the next interval
+		System::set_block_number(2);
+		IdleScheduler::on_idle(2, Weight::MAX);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 20_000);
+
+		System::set_block_number(3);
+		IdleScheduler::on_idle(3, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::AggregatedDex(crate::Event::OrderFilled {
+			owner: ALICE,
+			order_id: 0,
+			supply_amount: 10_000,
+			target_amount: 9_706,
+			remaining_supply_amount: 10_000,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 19_606);
+
+		// the price is below the limit price, skip this interval
+		System::set_block_number(5);
+		IdleScheduler::on_idle(5, Weight::MAX);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 10_000);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 19_606);
+		assert_eq!(
+			AggregatedDex::orders(ALICE, 0).unwrap().kind,
+			OrderKind::Twap {
+				interval: 2,
+				amount_per_interval: 10_000,
+				next_execution: 7,
+			}
+		);
+
+		// expired
+		System::set_block_number(8);
+		IdleScheduler::on_idle(8, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::AggregatedDex(crate::Event::OrderExpired {
+			owner: ALICE,
+			order_id: 0,
+			unreserved_amount: 10_000,
+		}));
+		assert_eq!(AggregatedDex::orders(ALICE, 0), None);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 0);
+	});
+}
@@ -49,6 +49,10 @@ pub trait WeightInfo {
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
 	fn update_aggregated_swap_paths(u: u32, ) -> Weight;
+	fn place_limit_order() -> Weight;
+	fn place_twap_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn match_order(u: u32, ) -> Weight;
 }
 
 /// Weights for module_aggregated_dex using the Acala node and recommended hardware.
@@ -79,6 +83,31 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	fn place_limit_order() -> Weight {
+		// Estimated including the deposit and the quote of the paths, not benchmarked yet.
+		Weight::from_parts(68_214_000, 0)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	fn place_twap_order() -> Weight {
+		// Estimated including the deposit and the quote of the paths, not benchmarked yet.
+		Weight::from_parts(68_637_000, 0)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	fn cancel_order() -> Weight {
+		// Estimated including the deposit, not benchmarked yet.
+		Weight::from_parts(38_120_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	fn match_order(u: u32, ) -> Weight {
+		// Estimated from five failed quotes of `swap_with_exact_supply` by the paths, not benchmarked yet.
+		Weight::from_parts(21_583_000, 0)
+			.saturating_add(Weight::from_parts(36_000_000, 0).saturating_mul(u as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(u as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -108,4 +137,29 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	fn place_limit_order() -> Weight {
+		// Estimated including the deposit and the quote of the paths, not benchmarked yet.
+		Weight::from_parts(68_214_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	fn place_twap_order() -> Weight {
+		// Estimated including the deposit and the quote of the paths, not benchmarked yet.
+		Weight::from_parts(68_637_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	fn cancel_order() -> Weight {
+		// Estimated including the deposit, not benchmarked yet.
+		Weight::from_parts(38_120_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	fn match_order(u: u32, ) -> Weight {
+		// Estimated from five failed quotes of `swap_with_exact_supply` by the paths, not benchmarked yet.
+		Weight::from_parts(21_583_000, 0)
+			.saturating_add(Weight::from_parts(36_000_000, 0).saturating_mul(u as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(u as u64)))
+	}
 }
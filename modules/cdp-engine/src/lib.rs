@@ -439,7 +439,10 @@ pub mod module {
 		/// - `currency_id`: CDP's collateral type.
 		/// - `who`: CDP's owner.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::liquidate_by_auction(<T as Config>::CDPTreasury::max_auction()))]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::liquidate_by_auction(<T as Config>::CDPTreasury::max_auction())
+				.max(Pallet::<T>::liquidate_by_dex_weight(*currency_id))
+		)]
 		pub fn liquidate(
 			origin: OriginFor<T>,
 			currency_id: CurrencyId,
@@ -1291,7 +1294,23 @@ impl<T: Config> Pallet<T> {
 			bad_debt_value,
 			target_amount: target_stable_amount,
 		});
-		Ok(T::WeightInfo::liquidate_by_dex())
+		Ok(Self::liquidate_by_dex_weight(currency_id))
+	}
+
+	/// Get the weight of liquidating the CDP of `currency_id` by DEX, which includes the weight of
+	/// going through concentrated liquidity pools.
+	pub fn liquidate_by_dex_weight(currency_id: CurrencyId) -> Weight {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let concentrated_swap_weight = match currency_id {
+			CurrencyId::DexShare(dex_share_0, dex_share_1) => {
+				let token_0: CurrencyId = dex_share_0.into();
+				let token_1: CurrencyId = dex_share_1.into();
+				T::Swap::get_concentrated_swap_weight(token_0, stable_currency_id)
+					.saturating_add(T::Swap::get_concentrated_swap_weight(token_1, stable_currency_id))
+			}
+			_ => T::Swap::get_concentrated_swap_weight(currency_id, stable_currency_id),
+		};
+		T::WeightInfo::liquidate_by_dex().saturating_add(concentrated_swap_weight)
 	}
 
 	pub fn handle_liquidated_collateral(
@@ -248,6 +248,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type TreasuryAccount = TreasuryAccount;
+	type FlashSwapEvmBridge = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
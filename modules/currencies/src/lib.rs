@@ -30,7 +30,8 @@ use frame_support::{
 			WithdrawConsequence,
 		},
 		BalanceStatus as Status, Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance,
-		LockableCurrency as PalletLockableCurrency, ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
+		LockableCurrency as PalletLockableCurrency, NamedReservableCurrency,
+		ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
 	},
 	transactional,
 };
@@ -41,12 +42,13 @@ use orml_traits::{
 	currency::{OnDust, TransferAll},
 	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency,
 	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
+	NamedBasicReservableCurrency, NamedMultiReservableCurrency,
 };
-use parity_scale_codec::Codec;
-use primitives::{evm::EvmAddress, CurrencyId};
+use parity_scale_codec::{Codec, EncodeLike};
+use primitives::{evm::EvmAddress, CurrencyId, ReserveIdentifier};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
+	traits::{Bounded, CheckedAdd, CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
 	DispatchError, DispatchResult,
 };
 use sp_std::{fmt::Debug, marker, result, vec::Vec};
@@ -66,6 +68,8 @@ type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_s
 pub mod module {
 	use super::*;
 
+	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::Currencies;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -112,6 +116,17 @@ pub mod module {
 
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, CurrencyId, BalanceOf<Self>>;
+
+		/// Currency for the allowance deposit reserved.
+		type DepositCurrency: NamedReservableCurrency<
+			Self::AccountId,
+			Balance = BalanceOf<Self>,
+			ReserveIdentifier = ReserveIdentifier,
+		>;
+
+		/// Reserved amount of the owner per allowance.
+		#[pallet::constant]
+		type DepositPerAllowance: Get<BalanceOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -128,6 +143,8 @@ pub mod module {
 		RealOriginNotFound,
 		/// Deposit result is not expected
 		DepositFailed,
+		/// The allowance is not enough
+		AllowanceExceeded,
 	}
 
 	#[pallet::event]
@@ -158,8 +175,38 @@ pub mod module {
 			who: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Allowance approved.
+		Approved {
+			currency_id: CurrencyId,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
+	/// The amount of currency that the spender is allowed to transfer on behalf of the owner, and
+	/// the deposit reserved from the owner for it.
+	///
+	/// Allowances: double_map CurrencyId, (owner, spender) => Option<(Balance, Balance)>
+	#[pallet::storage]
+	pub type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId),
+		(BalanceOf<T>, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// The nonces of owners used for the EIP-2612 permits.
+	///
+	/// PermitNonces: double_map CurrencyId, AccountId => u64
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonces)]
+	pub type PermitNonces<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -291,6 +338,84 @@ impl<T: Config> Pallet<T> {
 		let origin = T::EVMBridge::get_real_or_xcm_origin().ok_or(Error::<T>::RealOriginNotFound)?;
 		Ok(T::AddressMapping::get_or_create_evm_address(&origin))
 	}
+
+	/// The allowance of `spender` over the `owner`'s `currency_id`.
+	pub fn allowances<K: EncodeLike<(T::AccountId, T::AccountId)>>(
+		currency_id: CurrencyId,
+		owner_and_spender: K,
+	) -> BalanceOf<T> {
+		Allowances::<T>::get(currency_id, owner_and_spender).map_or_else(Zero::zero, |(amount, _)| amount)
+	}
+
+	/// Set `amount` as the allowance of `spender` over the `owner`'s `currency_id`. The deposit is
+	/// reserved from `owner` for a new allowance, and unreserved when the allowance is set to zero.
+	#[transactional]
+	pub fn approve(
+		currency_id: CurrencyId,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Allowances::<T>::try_mutate_exists(currency_id, (owner, spender), |maybe_allowance| -> DispatchResult {
+			if amount.is_zero() {
+				if let Some((_, deposit)) = maybe_allowance.take() {
+					T::DepositCurrency::unreserve_named(&RESERVE_ID, owner, deposit);
+				}
+			} else if let Some((allowance, _)) = maybe_allowance {
+				*allowance = amount;
+			} else {
+				let deposit = T::DepositPerAllowance::get();
+				T::DepositCurrency::reserve_named(&RESERVE_ID, owner, deposit)?;
+				*maybe_allowance = Some((amount, deposit));
+			}
+			Ok(())
+		})?;
+		Self::deposit_event(Event::<T>::Approved {
+			currency_id,
+			owner: owner.clone(),
+			spender: spender.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Transfer `amount` of `currency_id` from `from` to `to` by `spender`, the allowance
+	/// will be decreased unless it's the max value. The deposit is unreserved when the allowance
+	/// is used up.
+	#[transactional]
+	pub fn transfer_from(
+		currency_id: CurrencyId,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Allowances::<T>::try_mutate_exists(currency_id, (from, spender), |maybe_allowance| -> DispatchResult {
+			let Some((allowance, deposit)) = maybe_allowance else {
+				ensure!(amount.is_zero(), Error::<T>::AllowanceExceeded);
+				return Ok(());
+			};
+			if *allowance != BalanceOf::<T>::max_value() {
+				*allowance = allowance.checked_sub(&amount).ok_or(Error::<T>::AllowanceExceeded)?;
+				if allowance.is_zero() {
+					T::DepositCurrency::unreserve_named(&RESERVE_ID, from, *deposit);
+					*maybe_allowance = None;
+				}
+			}
+			Ok(())
+		})?;
+
+		<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, from, to, amount)
+	}
+
+	/// Consume the permit nonce of `owner` and return the consumed one.
+	pub fn use_permit_nonce(currency_id: CurrencyId, owner: &T::AccountId) -> u64 {
+		PermitNonces::<T>::mutate(currency_id, owner, |nonce| {
+			let current = *nonce;
+			*nonce = nonce.saturating_add(1);
+			current
+		})
+	}
 }
 
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
@@ -767,6 +892,102 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	}
 }
 
+impl<T: Config> NamedMultiReservableCurrency<T::AccountId> for Pallet<T>
+where
+	T::MultiCurrency: NamedMultiReservableCurrency<T::AccountId, ReserveIdentifier = ReserveIdentifier>,
+	T::NativeCurrency: NamedBasicReservableCurrency<T::AccountId, ReserveIdentifier>,
+{
+	type ReserveIdentifier = ReserveIdentifier;
+
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Self::Balance {
+		match currency_id {
+			CurrencyId::Erc20(_) => value,
+			native if native == T::GetNativeCurrencyId::get() => {
+				<T::NativeCurrency as NamedBasicReservableCurrency<_, _>>::slash_reserved_named(id, who, value)
+			}
+			_ => {
+				<T::MultiCurrency as NamedMultiReservableCurrency<_>>::slash_reserved_named(id, currency_id, who, value)
+			}
+		}
+	}
+
+	fn reserved_balance_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+	) -> Self::Balance {
+		match currency_id {
+			CurrencyId::Erc20(_) => Default::default(),
+			native if native == T::GetNativeCurrencyId::get() => {
+				<T::NativeCurrency as NamedBasicReservableCurrency<_, _>>::reserved_balance_named(id, who)
+			}
+			_ => <T::MultiCurrency as NamedMultiReservableCurrency<_>>::reserved_balance_named(id, currency_id, who),
+		}
+	}
+
+	fn reserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> DispatchResult {
+		match currency_id {
+			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			native if native == T::GetNativeCurrencyId::get() => {
+				<T::NativeCurrency as NamedBasicReservableCurrency<_, _>>::reserve_named(id, who, value)
+			}
+			_ => <T::MultiCurrency as NamedMultiReservableCurrency<_>>::reserve_named(id, currency_id, who, value),
+		}
+	}
+
+	fn unreserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Self::Balance {
+		match currency_id {
+			CurrencyId::Erc20(_) => value,
+			native if native == T::GetNativeCurrencyId::get() => {
+				<T::NativeCurrency as NamedBasicReservableCurrency<_, _>>::unreserve_named(id, who, value)
+			}
+			_ => <T::MultiCurrency as NamedMultiReservableCurrency<_>>::unreserve_named(id, currency_id, who, value),
+		}
+	}
+
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError> {
+		match currency_id {
+			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			native if native == T::GetNativeCurrencyId::get() => <T::NativeCurrency as NamedBasicReservableCurrency<
+				_,
+				_,
+			>>::repatriate_reserved_named(
+				id, slashed, beneficiary, value, status
+			),
+			_ => <T::MultiCurrency as NamedMultiReservableCurrency<_>>::repatriate_reserved_named(
+				id,
+				currency_id,
+				slashed,
+				beneficiary,
+				value,
+				status,
+			),
+		}
+	}
+}
+
 /// impl fungiles for Pallet<T>
 impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 	type AssetId = CurrencyId;
@@ -1624,6 +1845,41 @@ where
 	}
 }
 
+// Adapt `frame_support::traits::NamedReservableCurrency`
+impl<T, AccountId, Currency, Amount, Moment, ReserveIdentifier>
+	NamedBasicReservableCurrency<AccountId, ReserveIdentifier> for BasicCurrencyAdapter<T, Currency, Amount, Moment>
+where
+	Currency: NamedReservableCurrency<AccountId, ReserveIdentifier = ReserveIdentifier>,
+	T: Config,
+{
+	fn slash_reserved_named(id: &ReserveIdentifier, who: &AccountId, value: Self::Balance) -> Self::Balance {
+		let (_, gap) = <Currency as NamedReservableCurrency<_>>::slash_reserved_named(id, who, value);
+		gap
+	}
+
+	fn reserved_balance_named(id: &ReserveIdentifier, who: &AccountId) -> Self::Balance {
+		<Currency as NamedReservableCurrency<_>>::reserved_balance_named(id, who)
+	}
+
+	fn reserve_named(id: &ReserveIdentifier, who: &AccountId, value: Self::Balance) -> DispatchResult {
+		<Currency as NamedReservableCurrency<_>>::reserve_named(id, who, value)
+	}
+
+	fn unreserve_named(id: &ReserveIdentifier, who: &AccountId, value: Self::Balance) -> Self::Balance {
+		<Currency as NamedReservableCurrency<_>>::unreserve_named(id, who, value)
+	}
+
+	fn repatriate_reserved_named(
+		id: &ReserveIdentifier,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError> {
+		<Currency as NamedReservableCurrency<_>>::repatriate_reserved_named(id, slashed, beneficiary, value, status)
+	}
+}
+
 /// impl fungile for Currency<T, GetCurrencyId>
 type FungibleBalanceOf<A, Currency> = <Currency as fungible::Inspect<A>>::Balance;
 impl<T, Currency, Amount, Moment> fungible::Inspect<T::AccountId> for BasicCurrencyAdapter<T, Currency, Amount, Moment>
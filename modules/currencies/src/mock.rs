@@ -96,8 +96,8 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyHooks = CurrencyHooks<Runtime>;
 	type WeightInfo = ();
 	type MaxLocks = ConstU32<100>;
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -212,6 +212,8 @@ impl Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = crate::TransferDust<Runtime, DustAccount>;
+	type DepositCurrency = PalletBalances;
+	type DepositPerAllowance = ConstU128<2>;
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -385,6 +385,41 @@ fn multi_reservable_currency_should_work() {
 		});
 }
 
+#[test]
+fn named_multi_reservable_currency_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			let id = ReserveIdentifier::Honzon;
+			assert_ok!(Currencies::reserve_named(&id, X_TOKEN_ID, &alice(), 30));
+			assert_ok!(Currencies::reserve_named(&id, NATIVE_CURRENCY_ID, &alice(), 40));
+			assert_eq!(Currencies::reserved_balance_named(&id, X_TOKEN_ID, &alice()), 30);
+			assert_eq!(
+				Currencies::reserved_balance_named(&id, NATIVE_CURRENCY_ID, &alice()),
+				40
+			);
+			assert_eq!(Currencies::reserved_balance_named(&RESERVE_ID, X_TOKEN_ID, &alice()), 0);
+			assert_eq!(Currencies::reserved_balance(X_TOKEN_ID, &alice()), 30);
+			assert_eq!(Currencies::reserved_balance(NATIVE_CURRENCY_ID, &alice()), 40);
+
+			assert_eq!(Currencies::unreserve_named(&id, X_TOKEN_ID, &alice(), 50), 20);
+			assert_eq!(Currencies::unreserve_named(&id, NATIVE_CURRENCY_ID, &alice(), 10), 0);
+			assert_eq!(Currencies::reserved_balance_named(&id, X_TOKEN_ID, &alice()), 0);
+			assert_eq!(
+				Currencies::reserved_balance_named(&id, NATIVE_CURRENCY_ID, &alice()),
+				30
+			);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 100);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &alice()), 70);
+
+			assert_noop!(
+				Currencies::reserve_named(&id, CurrencyId::Erc20(erc20_address()), &alice(), 1),
+				Error::<Runtime>::Erc20InvalidOperation
+			);
+		});
+}
+
 #[test]
 fn native_currency_lockable_should_work() {
 	ExtBuilder::default()
@@ -461,6 +496,99 @@ fn multi_currency_extended_should_work() {
 		});
 }
 
+#[test]
+fn approve_and_transfer_from_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &CHARLIE, 10),
+				Error::<Runtime>::AllowanceExceeded
+			);
+
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &alice(), &bob(), 30));
+			System::assert_last_event(RuntimeEvent::Currencies(crate::Event::Approved {
+				currency_id: X_TOKEN_ID,
+				owner: alice(),
+				spender: bob(),
+				amount: 30,
+			}));
+			assert_eq!(Currencies::allowances(X_TOKEN_ID, (alice(), bob())), 30);
+			assert_eq!(<Balances as PalletReservableCurrency<_>>::reserved_balance(&alice()), 2);
+
+			// updating an allowance doesn't reserve again
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &alice(), &bob(), 30));
+			assert_eq!(<Balances as PalletReservableCurrency<_>>::reserved_balance(&alice()), 2);
+
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &CHARLIE, 20));
+			assert_eq!(Currencies::allowances(X_TOKEN_ID, (alice(), bob())), 10);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 80);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &CHARLIE), 20);
+
+			assert_noop!(
+				Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &CHARLIE, 20),
+				Error::<Runtime>::AllowanceExceeded
+			);
+
+			// used up allowance is removed and the deposit is unreserved
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &CHARLIE, 10));
+			assert_eq!(Allowances::<Runtime>::get(X_TOKEN_ID, (alice(), bob())), None);
+			assert_eq!(Currencies::allowances(X_TOKEN_ID, (alice(), bob())), 0);
+			assert_eq!(<Balances as PalletReservableCurrency<_>>::reserved_balance(&alice()), 0);
+
+			// max allowance is not decreased
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &alice(), &bob(), u128::MAX));
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &CHARLIE, 20));
+			assert_eq!(Currencies::allowances(X_TOKEN_ID, (alice(), bob())), u128::MAX);
+			assert_eq!(<Balances as PalletReservableCurrency<_>>::reserved_balance(&alice()), 2);
+		});
+}
+
+#[test]
+fn approve_zero_should_remove_allowance() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			// approving zero without an allowance reserves nothing
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &alice(), &bob(), 0));
+			assert_eq!(Allowances::<Runtime>::get(X_TOKEN_ID, (alice(), bob())), None);
+			assert_eq!(<Balances as PalletReservableCurrency<_>>::reserved_balance(&alice()), 0);
+
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &alice(), &bob(), 30));
+			assert_eq!(Allowances::<Runtime>::get(X_TOKEN_ID, (alice(), bob())), Some((30, 2)));
+			assert_eq!(<Balances as PalletReservableCurrency<_>>::reserved_balance(&alice()), 2);
+
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &alice(), &bob(), 0));
+			System::assert_last_event(RuntimeEvent::Currencies(crate::Event::Approved {
+				currency_id: X_TOKEN_ID,
+				owner: alice(),
+				spender: bob(),
+				amount: 0,
+			}));
+			assert_eq!(Allowances::<Runtime>::get(X_TOKEN_ID, (alice(), bob())), None);
+			assert_eq!(<Balances as PalletReservableCurrency<_>>::reserved_balance(&alice()), 0);
+
+			// owner without free native balance can't approve
+			assert_noop!(
+				Currencies::approve(X_TOKEN_ID, &CHARLIE, &bob(), 30),
+				pallet_balances::Error::<Runtime>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn use_permit_nonce_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Currencies::permit_nonces(X_TOKEN_ID, alice()), 0);
+		assert_eq!(Currencies::use_permit_nonce(X_TOKEN_ID, &alice()), 0);
+		assert_eq!(Currencies::use_permit_nonce(X_TOKEN_ID, &alice()), 1);
+		assert_eq!(Currencies::permit_nonces(X_TOKEN_ID, alice()), 2);
+		assert_eq!(Currencies::permit_nonces(DOT, alice()), 0);
+	});
+}
+
 #[test]
 fn native_currency_should_work() {
 	ExtBuilder::default()
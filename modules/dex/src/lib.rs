@@ -26,6 +26,10 @@
 //! liquidation by auction when the liquidity is sufficient. And providing
 //! market making liquidity for DEX will also receive stable currency as
 //! additional reward for its participation in the CDP liquidation.
+//!
+//! Besides the constant product pools, a trading pair can also have a concentrated
+//! liquidity pool, whose liquidity is provided to specific price ticks. Each step of a
+//! swap path goes through the pool which gives the better price.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::too_many_arguments)]
@@ -35,15 +39,19 @@
 
 use frame_support::{pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use module_support::{DEXBootstrap, DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Ratio, SwapLimit};
+use module_support::{
+	DEXBootstrap, DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, FlashSwapEvmBridge, InvokeContext, Price,
+	Ratio, SwapLimit,
+};
 use orml_traits::{Happened, MultiCurrency, MultiCurrencyExtended};
 use parity_scale_codec::MaxEncodedLen;
 use primitives::{Balance, CurrencyId, TradingPair};
 use scale_info::TypeInfo;
 use sp_core::{H160, U256};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Saturating, Zero},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, One, Saturating, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Rounding, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{prelude::*, vec};
 
@@ -87,6 +95,54 @@ impl<Balance, BlockNumber> Default for TradingPairStatus<Balance, BlockNumber> {
 	}
 }
 
+/// The maximum number of ticks that a concentrated liquidity position can span.
+pub const MAX_CONCENTRATED_POSITION_TICKS: u32 = 100;
+/// The maximum number of ticks that a swap can go through in a concentrated liquidity pool.
+pub const MAX_CONCENTRATED_SWAP_TICKS: u32 = 100;
+/// The maximum absolute value of the tick of concentrated liquidity pool.
+pub const MAX_CONCENTRATED_TICK: i32 = 100_000;
+
+/// Parameters and state of the concentrated liquidity pool of TradingPair.
+///
+/// Each tick is a price range holding its own liquidity, the price of token 0 in token 1 at
+/// `tick` is `base_price * (1 + tick_step) ^ tick`. Ticks above `current_tick` only hold token
+/// 0 and ticks below it only hold token 1.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct ConcentratedPoolInfo {
+	/// The price of token 0 in token 1 at tick 0.
+	pub base_price: Price,
+	/// The price increase rate between adjacent ticks.
+	pub tick_step: Ratio,
+	/// The tick which the pool is trading at.
+	pub current_tick: i32,
+	/// The lowest tick that has been provided liquidity, it's raised when the liquidity of the
+	/// lowest ticks is all removed.
+	pub lowest_tick: i32,
+	/// The highest tick that has been provided liquidity, it's lowered when the liquidity of the
+	/// highest ticks is all removed.
+	pub highest_tick: i32,
+}
+
+/// Liquidity of a tick in the concentrated liquidity pool.
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct ConcentratedTick {
+	/// The amount of token 0.
+	pub reserve_0: Balance,
+	/// The amount of token 1.
+	pub reserve_1: Balance,
+	/// The total shares of the tick held by the positions.
+	pub total_shares: Balance,
+}
+
+/// The kind of liquidity pool that a swap of the trading pair goes through.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+enum PoolKind {
+	/// The constant product pool in `LiquidityPool`.
+	ConstantProduct,
+	/// The concentrated liquidity pool in `ConcentratedPools`.
+	Concentrated,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -98,7 +154,8 @@ pub mod module {
 		/// Currency for transfer currencies
 		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
-		/// Trading fee rate
+		/// Default trading fee rate, used by the trading pairs without a
+		/// specific fee rate in `ExchangeFees`.
 		/// The first item of the tuple is the numerator of the fee rate, second
 		/// item is the denominator, fee_rate = numerator / denominator,
 		/// use (u32, u32) over `Rate` type to minimize internal division
@@ -133,6 +190,13 @@ pub mod module {
 
 		/// Event handler which calls when update liquidity pool.
 		type OnLiquidityPoolUpdated: Happened<(TradingPair, Balance, Balance)>;
+
+		/// The account to receive the protocol fee, which is minted as dex share.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// EVM bridge to call back the receiver of flash swap.
+		type FlashSwapEvmBridge: FlashSwapEvmBridge;
 	}
 
 	#[pallet::error]
@@ -183,6 +247,18 @@ pub mod module {
 		NotAllowedRefund,
 		/// Cannot swap
 		CannotSwap,
+		/// The exchange fee is invalid
+		InvalidExchangeFee,
+		/// The protocol fee rate is invalid
+		InvalidProtocolFeeRate,
+		/// The concentrated liquidity pool of trading pair is already created
+		ConcentratedPoolAlreadyCreated,
+		/// The concentrated liquidity pool of trading pair is not created
+		ConcentratedPoolNotCreated,
+		/// The parameters of concentrated liquidity pool are invalid
+		InvalidConcentratedPoolParameters,
+		/// The tick range is invalid
+		InvalidTickRange,
 	}
 
 	#[pallet::event]
@@ -247,6 +323,63 @@ pub mod module {
 			accumulated_provision_0: Balance,
 			accumulated_provision_1: Balance,
 		},
+		/// Update exchange fee of trading pair, `None` means using the default exchange fee.
+		UpdateExchangeFee {
+			trading_pair: TradingPair,
+			exchange_fee: Option<(u32, u32)>,
+		},
+		/// Update protocol fee rate of trading pair, `None` means the protocol fee is off.
+		UpdateProtocolFeeRate {
+			trading_pair: TradingPair,
+			protocol_fee_rate: Option<(u32, u32)>,
+		},
+		/// The protocol fee accrued from swaps is minted as dex share to the receiver.
+		ProtocolFeeMinted {
+			trading_pair: TradingPair,
+			receiver: T::AccountId,
+			share_amount: Balance,
+		},
+		/// Concentrated liquidity pool of trading pair is created.
+		ConcentratedPoolCreated {
+			trading_pair: TradingPair,
+			base_price: Price,
+			tick_step: Ratio,
+		},
+		/// Add liquidity to the ticks of concentrated liquidity pool success.
+		AddConcentratedLiquidity {
+			who: T::AccountId,
+			currency_0: CurrencyId,
+			pool_0: Balance,
+			currency_1: CurrencyId,
+			pool_1: Balance,
+			lower_tick: i32,
+			upper_tick: i32,
+		},
+		/// Remove liquidity from the ticks of concentrated liquidity pool success.
+		RemoveConcentratedLiquidity {
+			who: T::AccountId,
+			currency_0: CurrencyId,
+			pool_0: Balance,
+			currency_1: CurrencyId,
+			pool_1: Balance,
+			lower_tick: i32,
+			upper_tick: i32,
+		},
+		/// Flash swap success.
+		FlashSwap {
+			trader: T::AccountId,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			supply_amount: Balance,
+			target_amount: Balance,
+		},
+		/// The protocol fee of a swap in concentrated liquidity pool is transferred to the receiver.
+		ConcentratedProtocolFeeCharged {
+			trading_pair: TradingPair,
+			receiver: T::AccountId,
+			currency_id: CurrencyId,
+			amount: Balance,
+		},
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -281,6 +414,50 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// Exchange fee of TradingPair, the trading pair uses `GetExchangeFee` if it's not set.
+	///
+	/// ExchangeFees: map TradingPair => Option<(u32, u32)>
+	#[pallet::storage]
+	#[pallet::getter(fn exchange_fees)]
+	pub type ExchangeFees<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (u32, u32), OptionQuery>;
+
+	/// The share of the exchange fee that goes to the protocol for TradingPair.
+	///
+	/// ProtocolFeeRates: map TradingPair => Option<(u32, u32)>
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee_rates)]
+	pub type ProtocolFeeRates<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (u32, u32), OptionQuery>;
+
+	/// The product of the liquidity pool (k = x * y) as of the last protocol fee settlement.
+	///
+	/// ProtocolFeeKLast: map TradingPair => U256
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee_k_last)]
+	pub type ProtocolFeeKLast<T: Config> = StorageMap<_, Twox64Concat, TradingPair, U256, ValueQuery>;
+
+	/// Concentrated liquidity pool for TradingPair.
+	///
+	/// ConcentratedPools: map TradingPair => Option<ConcentratedPoolInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn concentrated_pools)]
+	pub type ConcentratedPools<T: Config> = StorageMap<_, Twox64Concat, TradingPair, ConcentratedPoolInfo, OptionQuery>;
+
+	/// Liquidity of the ticks of concentrated liquidity pool.
+	///
+	/// ConcentratedTicks: double_map TradingPair, Tick => ConcentratedTick
+	#[pallet::storage]
+	#[pallet::getter(fn concentrated_ticks)]
+	pub type ConcentratedTicks<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, TradingPair, Twox64Concat, i32, ConcentratedTick, ValueQuery>;
+
+	/// The shares of ticks of concentrated liquidity pool held by the account.
+	///
+	/// ConcentratedPositions: double_map AccountId, (TradingPair, Tick) => Share
+	#[pallet::storage]
+	#[pallet::getter(fn concentrated_positions)]
+	pub type ConcentratedPositions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, (TradingPair, i32), Balance, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -350,16 +527,20 @@ pub mod module {
 		/// - `supply_amount`: exact supply amount.
 		/// - `min_target_amount`: acceptable minimum target amount.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_supply(path.len() as u32))]
+		#[pallet::weight(Pallet::<T>::swap_with_exact_supply_weight(
+			path.len() as u32,
+			Pallet::<T>::concentrated_swap_ticks_of_path(path),
+		))]
 		pub fn swap_with_exact_supply(
 			origin: OriginFor<T>,
 			path: Vec<CurrencyId>,
 			#[pallet::compact] supply_amount: Balance,
 			#[pallet::compact] min_target_amount: Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
-			Ok(())
+			let (_, concentrated_ticks) =
+				Self::do_swap_with_exact_supply(&who, &path, supply_amount, min_target_amount)?;
+			Ok(Some(Self::swap_with_exact_supply_weight(path.len() as u32, concentrated_ticks)).into())
 		}
 
 		/// Trading with DEX, swap with exact target amount
@@ -368,16 +549,20 @@ pub mod module {
 		/// - `target_amount`: exact target amount.
 		/// - `max_supply_amount`: acceptable maximum supply amount.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::swap_with_exact_target(path.len() as u32))]
+		#[pallet::weight(Pallet::<T>::swap_with_exact_target_weight(
+			path.len() as u32,
+			Pallet::<T>::concentrated_swap_ticks_of_path(path),
+		))]
 		pub fn swap_with_exact_target(
 			origin: OriginFor<T>,
 			path: Vec<CurrencyId>,
 			#[pallet::compact] target_amount: Balance,
 			#[pallet::compact] max_supply_amount: Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
-			Ok(())
+			let (_, concentrated_ticks) =
+				Self::do_swap_with_exact_target(&who, &path, target_amount, max_supply_amount)?;
+			Ok(Some(Self::swap_with_exact_target_weight(path.len() as u32, concentrated_ticks)).into())
 		}
 
 		/// Add liquidity to Enabled trading pair.
@@ -664,6 +849,7 @@ pub mod module {
 					Self::try_mutate_liquidity_pool(&trading_pair, |(pool_0, pool_1)| -> DispatchResult {
 						*pool_0 = pool_0.checked_add(total_provision_0).ok_or(ArithmeticError::Overflow)?;
 						*pool_1 = pool_1.checked_add(total_provision_1).ok_or(ArithmeticError::Overflow)?;
+						Self::update_protocol_fee_k_last(&trading_pair, *pool_0, *pool_1);
 						Ok(())
 					})?;
 
@@ -803,6 +989,201 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Update the exchange fee of a trading pair.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `exchange_fee`: the (numerator, denominator) of the exchange fee rate, `None` means using
+		///   the default exchange fee.
+		#[pallet::call_index(13)]
+		#[pallet::weight((<T as Config>::WeightInfo::update_exchange_fee(), DispatchClass::Operational))]
+		pub fn update_exchange_fee(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			exchange_fee: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			if let Some((fee_numerator, fee_denominator)) = exchange_fee {
+				ensure!(
+					!fee_denominator.is_zero() && fee_numerator < fee_denominator,
+					Error::<T>::InvalidExchangeFee
+				);
+			}
+
+			ExchangeFees::<T>::set(trading_pair, exchange_fee);
+			Self::deposit_event(Event::UpdateExchangeFee {
+				trading_pair,
+				exchange_fee,
+			});
+			Ok(())
+		}
+
+		/// Update the protocol fee rate of a trading pair. The protocol fee is a share of the
+		/// exchange fee, it's minted as dex share to `TreasuryAccount` when the liquidity of the
+		/// trading pair is added or removed. For the swap in the concentrated liquidity pool, it's
+		/// transferred to `TreasuryAccount` from the supply amount directly.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `protocol_fee_rate`: the (numerator, denominator) of the share of exchange fee that
+		///   goes to the protocol, `None` means turning off the protocol fee.
+		#[pallet::call_index(14)]
+		#[pallet::weight((<T as Config>::WeightInfo::update_protocol_fee_rate(), DispatchClass::Operational))]
+		pub fn update_protocol_fee_rate(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			protocol_fee_rate: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			if let Some((fee_numerator, fee_denominator)) = protocol_fee_rate {
+				ensure!(
+					!fee_numerator.is_zero() && fee_numerator <= fee_denominator,
+					Error::<T>::InvalidProtocolFeeRate
+				);
+			}
+
+			// settle the protocol fee accrued under the previous rate
+			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+			Self::mint_protocol_fee(&trading_pair, pool_0, pool_1)?;
+
+			ProtocolFeeRates::<T>::set(trading_pair, protocol_fee_rate);
+			Self::update_protocol_fee_k_last(&trading_pair, pool_0, pool_1);
+			Self::deposit_event(Event::UpdateProtocolFeeRate {
+				trading_pair,
+				protocol_fee_rate,
+			});
+			Ok(())
+		}
+
+		/// Create the concentrated liquidity pool of a trading pair, swaps of the trading pair go
+		/// through the pool which gives the better price.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `initial_price`: the price of currency A in currency B at tick 0, which is the initial
+		///   current tick.
+		/// - `tick_step`: the price increase rate between adjacent ticks.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_concentrated_pool())]
+		pub fn create_concentrated_pool(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			initial_price: Price,
+			tick_step: Ratio,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			ensure!(
+				!ConcentratedPools::<T>::contains_key(trading_pair),
+				Error::<T>::ConcentratedPoolAlreadyCreated
+			);
+
+			let base_price = if currency_id_a == trading_pair.first() {
+				Some(initial_price)
+			} else {
+				initial_price.reciprocal()
+			}
+			.filter(|price| !price.is_zero())
+			.ok_or(Error::<T>::InvalidConcentratedPoolParameters)?;
+			ensure!(!tick_step.is_zero(), Error::<T>::InvalidConcentratedPoolParameters);
+
+			ConcentratedPools::<T>::insert(
+				trading_pair,
+				ConcentratedPoolInfo {
+					base_price,
+					tick_step,
+					current_tick: 0,
+					lowest_tick: 0,
+					highest_tick: 0,
+				},
+			);
+			Self::deposit_event(Event::ConcentratedPoolCreated {
+				trading_pair,
+				base_price,
+				tick_step,
+			});
+			Ok(())
+		}
+
+		/// Add liquidity to the ticks in `[lower_tick, upper_tick]` of the concentrated liquidity
+		/// pool. Currency A and currency B are evenly distributed to the ticks which can hold them,
+		/// the amount can't be distributed is not transferred.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `max_amount_a`: maximum amount of currency_id_a is allowed to inject to the pool.
+		/// - `max_amount_b`: maximum amount of currency_id_b is allowed to inject to the pool.
+		/// - `lower_tick`: the lowest tick of the position.
+		/// - `upper_tick`: the highest tick of the position.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_concentrated_liquidity(
+			Pallet::<T>::concentrated_ticks_count(*lower_tick, *upper_tick)
+		))]
+		pub fn add_concentrated_liquidity(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			#[pallet::compact] max_amount_a: Balance,
+			#[pallet::compact] max_amount_b: Balance,
+			lower_tick: i32,
+			upper_tick: i32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_add_concentrated_liquidity(
+				&who,
+				currency_id_a,
+				currency_id_b,
+				max_amount_a,
+				max_amount_b,
+				lower_tick,
+				upper_tick,
+			)?;
+			Ok(())
+		}
+
+		/// Remove all liquidity of the caller from the ticks in `[lower_tick, upper_tick]` of the
+		/// concentrated liquidity pool.
+		///
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		/// - `lower_tick`: the lowest tick to remove liquidity from.
+		/// - `upper_tick`: the highest tick to remove liquidity from.
+		/// - `min_withdrawn_a`: minimum acceptable withrawn for currency_id_a.
+		/// - `min_withdrawn_b`: minimum acceptable withrawn for currency_id_b.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_concentrated_liquidity(
+			Pallet::<T>::concentrated_ticks_count(*lower_tick, *upper_tick)
+		))]
+		pub fn remove_concentrated_liquidity(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			lower_tick: i32,
+			upper_tick: i32,
+			#[pallet::compact] min_withdrawn_a: Balance,
+			#[pallet::compact] min_withdrawn_b: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_remove_concentrated_liquidity(
+				&who,
+				currency_id_a,
+				currency_id_b,
+				lower_tick,
+				upper_tick,
+				min_withdrawn_a,
+				min_withdrawn_b,
+			)?;
+			Ok(())
+		}
 	}
 }
 
@@ -826,6 +1207,59 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Mint the protocol fee accrued since the last settlement as dex share to
+	/// `TreasuryAccount`. Like Uniswap V2, the exchange fee is reflected by the growth of
+	/// `sqrt(k)`, and the protocol takes `protocol_fee_rate` of it.
+	fn mint_protocol_fee(trading_pair: &TradingPair, pool_0: Balance, pool_1: Balance) -> DispatchResult {
+		let Some((fee_numerator, fee_denominator)) = Self::protocol_fee_rates(trading_pair) else {
+			return Ok(());
+		};
+		let k_last = Self::protocol_fee_k_last(trading_pair);
+		if k_last.is_zero() {
+			return Ok(());
+		}
+
+		let root_k = U256::from(pool_0).saturating_mul(U256::from(pool_1)).integer_sqrt();
+		let root_k_last = k_last.integer_sqrt();
+		if root_k <= root_k_last {
+			return Ok(());
+		}
+
+		let dex_share_currency_id = trading_pair.dex_share_currency_id();
+		let total_shares = T::Currency::total_issuance(dex_share_currency_id);
+		// share_amount = total_shares * (root_k - root_k_last) * rate / ((1 / rate - 1) * root_k + root_k_last)
+		let numerator: U256 = U256::from(total_shares)
+			.saturating_mul(root_k.saturating_sub(root_k_last))
+			.saturating_mul(U256::from(fee_numerator));
+		let denominator: U256 = root_k
+			.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)))
+			.saturating_add(root_k_last.saturating_mul(U256::from(fee_numerator)));
+		let share_amount: Balance = numerator
+			.checked_div(denominator)
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero);
+
+		if !share_amount.is_zero() {
+			let receiver = T::TreasuryAccount::get();
+			T::Currency::deposit(dex_share_currency_id, &receiver, share_amount)?;
+			Self::deposit_event(Event::ProtocolFeeMinted {
+				trading_pair: *trading_pair,
+				receiver,
+				share_amount,
+			});
+		}
+		Ok(())
+	}
+
+	/// Record the product of the liquidity pool for the next protocol fee settlement.
+	fn update_protocol_fee_k_last(trading_pair: &TradingPair, pool_0: Balance, pool_1: Balance) {
+		if Self::protocol_fee_rates(trading_pair).is_some() {
+			ProtocolFeeKLast::<T>::insert(trading_pair, U256::from(pool_0).saturating_mul(U256::from(pool_1)));
+		} else {
+			ProtocolFeeKLast::<T>::remove(trading_pair);
+		}
+	}
+
 	fn do_claim_dex_share(
 		who: &T::AccountId,
 		currency_id_a: CurrencyId,
@@ -1022,6 +1456,8 @@ impl<T: Config> Pallet<T> {
 		Self::try_mutate_liquidity_pool(
 			&trading_pair,
 			|(pool_0, pool_1)| -> sp_std::result::Result<(Balance, Balance, Balance), DispatchError> {
+				Self::mint_protocol_fee(&trading_pair, *pool_0, *pool_1)?;
+
 				let dex_share_currency_id = trading_pair.dex_share_currency_id();
 				let total_shares = T::Currency::total_issuance(dex_share_currency_id);
 				let (max_amount_0, max_amount_1) = if currency_id_a == trading_pair.first() {
@@ -1094,6 +1530,7 @@ impl<T: Config> Pallet<T> {
 
 				*pool_0 = pool_0.checked_add(pool_0_increment).ok_or(ArithmeticError::Overflow)?;
 				*pool_1 = pool_1.checked_add(pool_1_increment).ok_or(ArithmeticError::Overflow)?;
+				Self::update_protocol_fee_k_last(&trading_pair, *pool_0, *pool_1);
 
 				if stake_increment_share {
 					T::DEXIncentives::do_deposit_dex_share(who, dex_share_currency_id, share_increment)?;
@@ -1142,6 +1579,8 @@ impl<T: Config> Pallet<T> {
 				} else {
 					(min_withdrawn_b, min_withdrawn_a)
 				};
+				Self::mint_protocol_fee(&trading_pair, *pool_0, *pool_1)?;
+
 				let total_shares = T::Currency::total_issuance(dex_share_currency_id);
 				let proportion =
 					Ratio::checked_from_rational(remove_share, total_shares).ok_or(ArithmeticError::Overflow)?;
@@ -1163,6 +1602,7 @@ impl<T: Config> Pallet<T> {
 
 				*pool_0 = pool_0.checked_sub(pool_0_decrement).ok_or(ArithmeticError::Underflow)?;
 				*pool_1 = pool_1.checked_sub(pool_1_decrement).ok_or(ArithmeticError::Underflow)?;
+				Self::update_protocol_fee_k_last(&trading_pair, *pool_0, *pool_1);
 
 				Self::deposit_event(Event::RemoveLiquidity {
 					who: who.clone(),
@@ -1182,6 +1622,337 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Get the weight of swap with exact supply by path, which goes through `concentrated_ticks`
+	/// ticks of concentrated liquidity pools.
+	pub fn swap_with_exact_supply_weight(path_len: u32, concentrated_ticks: u32) -> Weight {
+		let weight = <T as Config>::WeightInfo::swap_with_exact_supply(path_len);
+		if concentrated_ticks.is_zero() {
+			weight
+		} else {
+			weight.saturating_add(<T as Config>::WeightInfo::concentrated_swap(concentrated_ticks))
+		}
+	}
+
+	/// Get the weight of swap with exact target by path, which goes through `concentrated_ticks`
+	/// ticks of concentrated liquidity pools.
+	pub fn swap_with_exact_target_weight(path_len: u32, concentrated_ticks: u32) -> Weight {
+		let weight = <T as Config>::WeightInfo::swap_with_exact_target(path_len);
+		if concentrated_ticks.is_zero() {
+			weight
+		} else {
+			weight.saturating_add(<T as Config>::WeightInfo::concentrated_swap(concentrated_ticks))
+		}
+	}
+
+	/// Get the maximum count of ticks that a swap by `path` can go through, only the trading
+	/// pairs which have concentrated liquidity pool are counted.
+	pub fn concentrated_swap_ticks_of_path(path: &[CurrencyId]) -> u32 {
+		path.windows(2)
+			.filter(|pair| {
+				TradingPair::from_currency_ids(pair[0], pair[1])
+					.map_or(false, ConcentratedPools::<T>::contains_key)
+			})
+			.count()
+			.saturated_into::<u32>()
+			.saturating_mul(MAX_CONCENTRATED_SWAP_TICKS)
+	}
+
+	/// Get the count of ticks in `[lower_tick, upper_tick]`, it's capped by
+	/// `MAX_CONCENTRATED_POSITION_TICKS + 1` and used as weight parameter.
+	pub fn concentrated_ticks_count(lower_tick: i32, upper_tick: i32) -> u32 {
+		(upper_tick as i64)
+			.saturating_sub(lower_tick as i64)
+			.saturating_add(1)
+			.clamp(0, MAX_CONCENTRATED_POSITION_TICKS.saturating_add(1) as i64) as u32
+	}
+
+	fn ensure_valid_tick_range(lower_tick: i32, upper_tick: i32) -> DispatchResult {
+		ensure!(
+			lower_tick <= upper_tick
+				&& lower_tick >= -MAX_CONCENTRATED_TICK
+				&& upper_tick <= MAX_CONCENTRATED_TICK
+				&& Self::concentrated_ticks_count(lower_tick, upper_tick) <= MAX_CONCENTRATED_POSITION_TICKS,
+			Error::<T>::InvalidTickRange
+		);
+		Ok(())
+	}
+
+	/// Get the price of token 0 in token 1 at the tick of concentrated liquidity pool.
+	fn concentrated_tick_price(pool: &ConcentratedPoolInfo, tick: i32) -> Option<Price> {
+		let factor = Price::one()
+			.checked_add(&pool.tick_step)?
+			.saturating_pow(tick.unsigned_abs() as usize);
+		let price = if tick.is_negative() {
+			pool.base_price.checked_div(&factor)
+		} else {
+			pool.base_price.checked_mul(&factor)
+		};
+		price.filter(|price| !price.is_zero())
+	}
+
+	fn token_0_to_token_1(price: Price, amount: Balance, rounding: Rounding) -> Option<Balance> {
+		multiply_by_rational_with_rounding(amount, price.into_inner(), Price::accuracy(), rounding)
+	}
+
+	fn token_1_to_token_0(price: Price, amount: Balance, rounding: Rounding) -> Option<Balance> {
+		multiply_by_rational_with_rounding(amount, Price::accuracy(), price.into_inner(), rounding)
+	}
+
+	#[transactional]
+	fn do_add_concentrated_liquidity(
+		who: &T::AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+		lower_tick: i32,
+		upper_tick: i32,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+		ensure!(
+			matches!(
+				Self::trading_pair_statuses(trading_pair),
+				TradingPairStatus::<_, _>::Enabled
+			),
+			Error::<T>::MustBeEnabled,
+		);
+		Self::ensure_valid_tick_range(lower_tick, upper_tick)?;
+		let mut pool = Self::concentrated_pools(trading_pair).ok_or(Error::<T>::ConcentratedPoolNotCreated)?;
+
+		let (max_amount_0, max_amount_1) = if currency_id_a == trading_pair.first() {
+			(max_amount_a, max_amount_b)
+		} else {
+			(max_amount_b, max_amount_a)
+		};
+		// token 0 is distributed to the ticks not below the current tick,
+		// token 1 is distributed to the ticks not above the current tick.
+		let ticks_count_0 = Self::concentrated_ticks_count(lower_tick.max(pool.current_tick), upper_tick);
+		let ticks_count_1 = Self::concentrated_ticks_count(lower_tick, upper_tick.min(pool.current_tick));
+		let amount_0_per_tick = max_amount_0
+			.checked_div(ticks_count_0.into())
+			.unwrap_or_else(Zero::zero);
+		let amount_1_per_tick = max_amount_1
+			.checked_div(ticks_count_1.into())
+			.unwrap_or_else(Zero::zero);
+		ensure!(
+			!amount_0_per_tick.is_zero() || !amount_1_per_tick.is_zero(),
+			Error::<T>::InvalidLiquidityIncrement
+		);
+
+		let mut pool_0_increment: Balance = Zero::zero();
+		let mut pool_1_increment: Balance = Zero::zero();
+		for tick in lower_tick..=upper_tick {
+			let tick_amount_0 = if tick >= pool.current_tick {
+				amount_0_per_tick
+			} else {
+				Zero::zero()
+			};
+			let tick_amount_1 = if tick <= pool.current_tick {
+				amount_1_per_tick
+			} else {
+				Zero::zero()
+			};
+			if tick_amount_0.is_zero() && tick_amount_1.is_zero() {
+				continue;
+			}
+
+			let price = Self::concentrated_tick_price(&pool, tick).ok_or(ArithmeticError::Overflow)?;
+			let share_increment = ConcentratedTicks::<T>::try_mutate(
+				trading_pair,
+				tick,
+				|tick_info| -> Result<Balance, DispatchError> {
+					// the liquidity of the tick is valued in token 1 at the price of the tick
+					let value = Self::token_0_to_token_1(price, tick_amount_0, Rounding::Down)
+						.and_then(|n| n.checked_add(tick_amount_1))
+						.ok_or(ArithmeticError::Overflow)?;
+					let tick_value = Self::token_0_to_token_1(price, tick_info.reserve_0, Rounding::Up)
+						.and_then(|n| n.checked_add(tick_info.reserve_1))
+						.ok_or(ArithmeticError::Overflow)?;
+					let share_increment = if tick_info.total_shares.is_zero() || tick_value.is_zero() {
+						value
+					} else {
+						multiply_by_rational_with_rounding(value, tick_info.total_shares, tick_value, Rounding::Down)
+							.ok_or(ArithmeticError::Overflow)?
+					};
+					ensure!(!share_increment.is_zero(), Error::<T>::InvalidLiquidityIncrement);
+
+					tick_info.reserve_0 = tick_info
+						.reserve_0
+						.checked_add(tick_amount_0)
+						.ok_or(ArithmeticError::Overflow)?;
+					tick_info.reserve_1 = tick_info
+						.reserve_1
+						.checked_add(tick_amount_1)
+						.ok_or(ArithmeticError::Overflow)?;
+					tick_info.total_shares = tick_info
+						.total_shares
+						.checked_add(share_increment)
+						.ok_or(ArithmeticError::Overflow)?;
+					Ok(share_increment)
+				},
+			)?;
+			ConcentratedPositions::<T>::try_mutate(who, (trading_pair, tick), |share| -> DispatchResult {
+				*share = share.checked_add(share_increment).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			pool_0_increment = pool_0_increment
+				.checked_add(tick_amount_0)
+				.ok_or(ArithmeticError::Overflow)?;
+			pool_1_increment = pool_1_increment
+				.checked_add(tick_amount_1)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		let module_account_id = Self::account_id();
+		T::Currency::transfer(trading_pair.first(), who, &module_account_id, pool_0_increment)?;
+		T::Currency::transfer(trading_pair.second(), who, &module_account_id, pool_1_increment)?;
+
+		pool.lowest_tick = pool.lowest_tick.min(lower_tick);
+		pool.highest_tick = pool.highest_tick.max(upper_tick);
+		ConcentratedPools::<T>::insert(trading_pair, pool);
+
+		Self::deposit_event(Event::AddConcentratedLiquidity {
+			who: who.clone(),
+			currency_0: trading_pair.first(),
+			pool_0: pool_0_increment,
+			currency_1: trading_pair.second(),
+			pool_1: pool_1_increment,
+			lower_tick,
+			upper_tick,
+		});
+
+		if currency_id_a == trading_pair.first() {
+			Ok((pool_0_increment, pool_1_increment))
+		} else {
+			Ok((pool_1_increment, pool_0_increment))
+		}
+	}
+
+	#[transactional]
+	fn do_remove_concentrated_liquidity(
+		who: &T::AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		lower_tick: i32,
+		upper_tick: i32,
+		min_withdrawn_a: Balance,
+		min_withdrawn_b: Balance,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+		Self::ensure_valid_tick_range(lower_tick, upper_tick)?;
+		ensure!(
+			ConcentratedPools::<T>::contains_key(trading_pair),
+			Error::<T>::ConcentratedPoolNotCreated
+		);
+
+		let mut pool_0_decrement: Balance = Zero::zero();
+		let mut pool_1_decrement: Balance = Zero::zero();
+		for tick in lower_tick..=upper_tick {
+			let share = ConcentratedPositions::<T>::take(who, (trading_pair, tick));
+			if share.is_zero() {
+				continue;
+			}
+
+			let (amount_0, amount_1) = ConcentratedTicks::<T>::try_mutate_exists(
+				trading_pair,
+				tick,
+				|maybe_tick_info| -> Result<(Balance, Balance), DispatchError> {
+					let mut tick_info = maybe_tick_info.take().unwrap_or_default();
+					let amount_0 = multiply_by_rational_with_rounding(
+						tick_info.reserve_0,
+						share,
+						tick_info.total_shares,
+						Rounding::Down,
+					)
+					.ok_or(ArithmeticError::Overflow)?;
+					let amount_1 = multiply_by_rational_with_rounding(
+						tick_info.reserve_1,
+						share,
+						tick_info.total_shares,
+						Rounding::Down,
+					)
+					.ok_or(ArithmeticError::Overflow)?;
+
+					tick_info.reserve_0 = tick_info
+						.reserve_0
+						.checked_sub(amount_0)
+						.ok_or(ArithmeticError::Underflow)?;
+					tick_info.reserve_1 = tick_info
+						.reserve_1
+						.checked_sub(amount_1)
+						.ok_or(ArithmeticError::Underflow)?;
+					tick_info.total_shares = tick_info
+						.total_shares
+						.checked_sub(share)
+						.ok_or(ArithmeticError::Underflow)?;
+					if !tick_info.total_shares.is_zero() {
+						*maybe_tick_info = Some(tick_info);
+					}
+					Ok((amount_0, amount_1))
+				},
+			)?;
+
+			pool_0_decrement = pool_0_decrement
+				.checked_add(amount_0)
+				.ok_or(ArithmeticError::Overflow)?;
+			pool_1_decrement = pool_1_decrement
+				.checked_add(amount_1)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		let (min_withdrawn_0, min_withdrawn_1) = if currency_id_a == trading_pair.first() {
+			(min_withdrawn_a, min_withdrawn_b)
+		} else {
+			(min_withdrawn_b, min_withdrawn_a)
+		};
+		ensure!(
+			pool_0_decrement >= min_withdrawn_0 && pool_1_decrement >= min_withdrawn_1,
+			Error::<T>::UnacceptableLiquidityWithdrawn,
+		);
+
+		// shrink the tick range of the pool if the ticks at its edges have been emptied, only the
+		// ticks in `[lower_tick, upper_tick]` can be emptied by the removal.
+		ConcentratedPools::<T>::try_mutate(trading_pair, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::ConcentratedPoolNotCreated)?;
+			while pool.lowest_tick < pool.highest_tick
+				&& (lower_tick..=upper_tick).contains(&pool.lowest_tick)
+				&& !ConcentratedTicks::<T>::contains_key(trading_pair, pool.lowest_tick)
+			{
+				pool.lowest_tick = pool.lowest_tick.saturating_add(1);
+			}
+			while pool.highest_tick > pool.lowest_tick
+				&& (lower_tick..=upper_tick).contains(&pool.highest_tick)
+				&& !ConcentratedTicks::<T>::contains_key(trading_pair, pool.highest_tick)
+			{
+				pool.highest_tick = pool.highest_tick.saturating_sub(1);
+			}
+			Ok(())
+		})?;
+
+		let module_account_id = Self::account_id();
+		T::Currency::transfer(trading_pair.first(), &module_account_id, who, pool_0_decrement)?;
+		T::Currency::transfer(trading_pair.second(), &module_account_id, who, pool_1_decrement)?;
+
+		Self::deposit_event(Event::RemoveConcentratedLiquidity {
+			who: who.clone(),
+			currency_0: trading_pair.first(),
+			pool_0: pool_0_decrement,
+			currency_1: trading_pair.second(),
+			pool_1: pool_1_decrement,
+			lower_tick,
+			upper_tick,
+		});
+
+		if currency_id_a == trading_pair.first() {
+			Ok((pool_0_decrement, pool_1_decrement))
+		} else {
+			Ok((pool_1_decrement, pool_0_decrement))
+		}
+	}
+
 	fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
 			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
@@ -1195,12 +1966,22 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get the exchange fee of the trading pair.
+	pub fn exchange_fee(trading_pair: &TradingPair) -> (u32, u32) {
+		Self::exchange_fees(trading_pair).unwrap_or_else(T::GetExchangeFee::get)
+	}
+
 	/// Get how much target amount will be got for specific supply amount.
-	fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
+	fn get_target_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let supply_amount_with_fee: U256 =
 				U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
 			let numerator: U256 = supply_amount_with_fee.saturating_mul(U256::from(target_pool));
@@ -1216,11 +1997,16 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Get how much supply amount will be paid for specific target amount.
-	fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
+	fn get_supply_amount(
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
 		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
 			Zero::zero()
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
+			let (fee_numerator, fee_denominator) = exchange_fee;
 			let numerator: U256 = U256::from(supply_pool)
 				.saturating_mul(U256::from(target_amount))
 				.saturating_mul(U256::from(fee_denominator));
@@ -1236,14 +2022,225 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get how much target amount will be got for specific supply amount from the concentrated
+	/// liquidity pool, return `None` if the pool can't take all of the supply amount. Also return
+	/// the count of ticks that have been gone through.
+	fn get_concentrated_target_amount(
+		trading_pair: &TradingPair,
+		supply_currency_id: CurrencyId,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> (Option<Balance>, u32) {
+		let mut visited_ticks: u32 = 0;
+		let target_amount = Self::concentrated_pools(trading_pair).and_then(|pool| {
+			Self::walk_concentrated_target_amount(
+				trading_pair,
+				&pool,
+				supply_currency_id,
+				supply_amount,
+				exchange_fee,
+				&mut visited_ticks,
+			)
+		});
+		(target_amount, visited_ticks)
+	}
+
+	fn walk_concentrated_target_amount(
+		trading_pair: &TradingPair,
+		pool: &ConcentratedPoolInfo,
+		supply_currency_id: CurrencyId,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+		visited_ticks: &mut u32,
+	) -> Option<Balance> {
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		let zero_for_one = supply_currency_id == trading_pair.first();
+
+		let mut remaining_supply = supply_amount;
+		let mut target_amount: Balance = Zero::zero();
+		let mut tick = pool.current_tick;
+		for _ in 0..MAX_CONCENTRATED_SWAP_TICKS {
+			if remaining_supply.is_zero() || tick < pool.lowest_tick || tick > pool.highest_tick {
+				break;
+			}
+
+			*visited_ticks = visited_ticks.saturating_add(1);
+			let tick_info = Self::concentrated_ticks(trading_pair, tick);
+			let reserve_out = if zero_for_one {
+				tick_info.reserve_1
+			} else {
+				tick_info.reserve_0
+			};
+			if !reserve_out.is_zero() {
+				let price = Self::concentrated_tick_price(pool, tick)?;
+				let supply_without_fee = multiply_by_rational_with_rounding(
+					remaining_supply,
+					fee_denominator.saturating_sub(fee_numerator).into(),
+					fee_denominator.into(),
+					Rounding::Down,
+				)?;
+				let amount_out = if zero_for_one {
+					Self::token_0_to_token_1(price, supply_without_fee, Rounding::Down)?
+				} else {
+					Self::token_1_to_token_0(price, supply_without_fee, Rounding::Down)?
+				};
+
+				if amount_out <= reserve_out {
+					target_amount = target_amount.checked_add(amount_out)?;
+					remaining_supply = Zero::zero();
+				} else {
+					let (amount_in, _) = Self::concentrated_swap_step(price, zero_for_one, reserve_out, exchange_fee)?;
+					target_amount = target_amount.checked_add(reserve_out)?;
+					remaining_supply = remaining_supply.saturating_sub(amount_in);
+				}
+			}
+
+			tick = if zero_for_one {
+				tick.checked_sub(1)?
+			} else {
+				tick.checked_add(1)?
+			};
+		}
+
+		if remaining_supply.is_zero() {
+			Some(target_amount)
+		} else {
+			None
+		}
+	}
+
+	/// Get how much supply amount will be paid for specific target amount to the concentrated
+	/// liquidity pool, return `None` if the pool can't afford the target amount. Also return the
+	/// count of ticks that have been gone through.
+	fn get_concentrated_supply_amount(
+		trading_pair: &TradingPair,
+		supply_currency_id: CurrencyId,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> (Option<Balance>, u32) {
+		let (steps, visited_ticks) =
+			Self::get_concentrated_swap_steps(trading_pair, supply_currency_id, target_amount, exchange_fee);
+		(steps.map(|(supply_amount, _)| supply_amount), visited_ticks)
+	}
+
+	/// Get the supply amount with fee to take `amount_out` from a tick at `price`.
+	fn concentrated_swap_step(
+		price: Price,
+		zero_for_one: bool,
+		amount_out: Balance,
+		exchange_fee: (u32, u32),
+	) -> Option<(Balance, Balance)> {
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		let amount_in_without_fee = if zero_for_one {
+			Self::token_1_to_token_0(price, amount_out, Rounding::Up)?
+		} else {
+			Self::token_0_to_token_1(price, amount_out, Rounding::Up)?
+		};
+		let amount_in = multiply_by_rational_with_rounding(
+			amount_in_without_fee,
+			fee_denominator.into(),
+			fee_denominator.saturating_sub(fee_numerator).into(),
+			Rounding::Up,
+		)?;
+		Some((amount_in, amount_out))
+	}
+
+	/// Get the total supply amount and the (tick, amount_in, amount_out) of every tick the swap
+	/// goes through to take `target_amount` from the concentrated liquidity pool. Also return the
+	/// count of ticks that have been gone through.
+	#[allow(clippy::type_complexity)]
+	fn get_concentrated_swap_steps(
+		trading_pair: &TradingPair,
+		supply_currency_id: CurrencyId,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> (Option<(Balance, Vec<(i32, Balance, Balance)>)>, u32) {
+		let mut visited_ticks: u32 = 0;
+		let steps = Self::concentrated_pools(trading_pair).and_then(|pool| {
+			Self::walk_concentrated_swap_steps(
+				trading_pair,
+				&pool,
+				supply_currency_id,
+				target_amount,
+				exchange_fee,
+				&mut visited_ticks,
+			)
+		});
+		(steps, visited_ticks)
+	}
+
+	#[allow(clippy::type_complexity)]
+	fn walk_concentrated_swap_steps(
+		trading_pair: &TradingPair,
+		pool: &ConcentratedPoolInfo,
+		supply_currency_id: CurrencyId,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+		visited_ticks: &mut u32,
+	) -> Option<(Balance, Vec<(i32, Balance, Balance)>)> {
+		let zero_for_one = supply_currency_id == trading_pair.first();
+
+		let mut remaining_target = target_amount;
+		let mut supply_amount: Balance = Zero::zero();
+		let mut steps: Vec<(i32, Balance, Balance)> = vec![];
+		let mut tick = pool.current_tick;
+		for _ in 0..MAX_CONCENTRATED_SWAP_TICKS {
+			if remaining_target.is_zero() || tick < pool.lowest_tick || tick > pool.highest_tick {
+				break;
+			}
+
+			*visited_ticks = visited_ticks.saturating_add(1);
+			let tick_info = Self::concentrated_ticks(trading_pair, tick);
+			let reserve_out = if zero_for_one {
+				tick_info.reserve_1
+			} else {
+				tick_info.reserve_0
+			};
+			let amount_out = reserve_out.min(remaining_target);
+			if !amount_out.is_zero() {
+				let price = Self::concentrated_tick_price(pool, tick)?;
+				let (amount_in, amount_out) =
+					Self::concentrated_swap_step(price, zero_for_one, amount_out, exchange_fee)?;
+				supply_amount = supply_amount.checked_add(amount_in)?;
+				remaining_target = remaining_target.saturating_sub(amount_out);
+				steps.push((tick, amount_in, amount_out));
+			}
+
+			tick = if zero_for_one {
+				tick.checked_sub(1)?
+			} else {
+				tick.checked_add(1)?
+			};
+		}
+
+		if remaining_target.is_zero() && !supply_amount.is_zero() {
+			Some((supply_amount, steps))
+		} else {
+			None
+		}
+	}
+
 	fn get_target_amounts(
 		path: &[CurrencyId],
 		supply_amount: Balance,
 	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
+		Self::get_target_amounts_and_pools(path, supply_amount).map(|(target_amounts, _, _)| target_amounts)
+	}
+
+	/// Get the target amounts of the path for specific supply amount, the kind of pool that
+	/// gives the better price for each step of the path, and the count of ticks of concentrated
+	/// liquidity pools that have been gone through.
+	#[allow(clippy::type_complexity)]
+	fn get_target_amounts_and_pools(
+		path: &[CurrencyId],
+		supply_amount: Balance,
+	) -> sp_std::result::Result<(Vec<Balance>, Vec<PoolKind>, u32), DispatchError> {
 		Self::validate_path(path)?;
 
 		let path_length = path.len();
 		let mut target_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
+		let mut pool_kinds: Vec<PoolKind> = vec![PoolKind::ConstantProduct; path_length - 1];
+		let mut concentrated_ticks: u32 = 0;
 		target_amounts[0] = supply_amount;
 
 		let mut i: usize = 0;
@@ -1257,29 +2254,56 @@ impl<T: Config> Pallet<T> {
 				),
 				Error::<T>::MustBeEnabled
 			);
+			let exchange_fee = Self::exchange_fee(&trading_pair);
 			let (supply_pool, target_pool) = Self::get_liquidity(path[i], path[i + 1]);
-			ensure!(
-				!supply_pool.is_zero() && !target_pool.is_zero(),
-				Error::<T>::InsufficientLiquidity
-			);
-			let target_amount = Self::get_target_amount(supply_pool, target_pool, target_amounts[i]);
+			let constant_product_amount =
+				Self::get_target_amount(supply_pool, target_pool, target_amounts[i], exchange_fee);
+			let (concentrated_amount, visited_ticks) =
+				Self::get_concentrated_target_amount(&trading_pair, path[i], target_amounts[i], exchange_fee);
+			concentrated_ticks = concentrated_ticks.saturating_add(visited_ticks);
+			let (target_amount, pool_kind) = match concentrated_amount {
+				Some(concentrated_amount) if concentrated_amount > constant_product_amount => {
+					(concentrated_amount, PoolKind::Concentrated)
+				}
+				_ => {
+					ensure!(
+						!supply_pool.is_zero() && !target_pool.is_zero(),
+						Error::<T>::InsufficientLiquidity
+					);
+					(constant_product_amount, PoolKind::ConstantProduct)
+				}
+			};
 			ensure!(!target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
 
 			target_amounts[i + 1] = target_amount;
+			pool_kinds[i] = pool_kind;
 			i += 1;
 		}
 
-		Ok(target_amounts)
+		Ok((target_amounts, pool_kinds, concentrated_ticks))
 	}
 
 	fn get_supply_amounts(
 		path: &[CurrencyId],
 		target_amount: Balance,
 	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
+		Self::get_supply_amounts_and_pools(path, target_amount).map(|(supply_amounts, _, _)| supply_amounts)
+	}
+
+	/// Get the supply amounts of the path for specific target amount, the kind of pool that
+	/// gives the better price for each step of the path, and the count of ticks of concentrated
+	/// liquidity pools that have been gone through.
+	#[allow(clippy::type_complexity)]
+	fn get_supply_amounts_and_pools(
+		path: &[CurrencyId],
+		target_amount: Balance,
+	) -> sp_std::result::Result<(Vec<Balance>, Vec<PoolKind>, u32), DispatchError> {
 		Self::validate_path(path)?;
 
 		let path_length = path.len();
 		let mut supply_amounts: Vec<Balance> = vec![Zero::zero(); path_length];
+		let mut pool_kinds: Vec<PoolKind> = vec![PoolKind::ConstantProduct; path_length - 1];
+		let mut concentrated_ticks: u32 = 0;
 		supply_amounts[path_length - 1] = target_amount;
 
 		let mut i: usize = path_length - 1;
@@ -1293,19 +2317,35 @@ impl<T: Config> Pallet<T> {
 				),
 				Error::<T>::MustBeEnabled
 			);
+			let exchange_fee = Self::exchange_fee(&trading_pair);
 			let (supply_pool, target_pool) = Self::get_liquidity(path[i - 1], path[i]);
-			ensure!(
-				!supply_pool.is_zero() && !target_pool.is_zero(),
-				Error::<T>::InsufficientLiquidity
-			);
-			let supply_amount = Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i]);
+			let constant_product_amount =
+				Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i], exchange_fee);
+			let (concentrated_amount, visited_ticks) =
+				Self::get_concentrated_supply_amount(&trading_pair, path[i - 1], supply_amounts[i], exchange_fee);
+			concentrated_ticks = concentrated_ticks.saturating_add(visited_ticks);
+			let (supply_amount, pool_kind) = match concentrated_amount {
+				Some(concentrated_amount)
+					if constant_product_amount.is_zero() || concentrated_amount < constant_product_amount =>
+				{
+					(concentrated_amount, PoolKind::Concentrated)
+				}
+				_ => {
+					ensure!(
+						!supply_pool.is_zero() && !target_pool.is_zero(),
+						Error::<T>::InsufficientLiquidity
+					);
+					(constant_product_amount, PoolKind::ConstantProduct)
+				}
+			};
 			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
 
 			supply_amounts[i - 1] = supply_amount;
+			pool_kinds[i - 1] = pool_kind;
 			i -= 1;
 		}
 
-		Ok(supply_amounts)
+		Ok((supply_amounts, pool_kinds, concentrated_ticks))
 	}
 
 	fn validate_path(path: &[CurrencyId]) -> DispatchResult {
@@ -1349,30 +2389,127 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn _swap_by_path(path: &[CurrencyId], amounts: &[Balance]) -> DispatchResult {
+	fn _swap_concentrated(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_increment: Balance,
+		target_decrement: Balance,
+	) -> DispatchResult {
+		let trading_pair = TradingPair::from_currency_ids(supply_currency_id, target_currency_id)
+			.ok_or(Error::<T>::InvalidCurrencyId)?;
+		let exchange_fee = Self::exchange_fee(&trading_pair);
+		let (supply_amount, steps) =
+			Self::get_concentrated_swap_steps(&trading_pair, supply_currency_id, target_decrement, exchange_fee)
+				.0
+				.ok_or(Error::<T>::InsufficientLiquidity)?;
+		ensure!(supply_amount <= supply_increment, Error::<T>::InvariantCheckFailed);
+
+		let zero_for_one = supply_currency_id == trading_pair.first();
+		let protocol_fee_rate = Self::protocol_fee_rates(trading_pair);
+		let mut protocol_fee: Balance = Zero::zero();
+		let surplus = supply_increment.saturating_sub(supply_amount);
+		let steps_count = steps.len();
+		for (i, (tick, amount_in, amount_out)) in steps.into_iter().enumerate() {
+			// the protocol takes its share of the exchange fee in `amount_in` instead of the tick
+			let step_protocol_fee = Self::concentrated_protocol_fee(amount_in, exchange_fee, protocol_fee_rate);
+			protocol_fee = protocol_fee
+				.checked_add(step_protocol_fee)
+				.ok_or(ArithmeticError::Overflow)?;
+			let amount_in = amount_in.saturating_sub(step_protocol_fee);
+
+			// the surplus of supply goes to the last tick the swap goes through
+			let amount_in = if i + 1 == steps_count {
+				amount_in.checked_add(surplus).ok_or(ArithmeticError::Overflow)?
+			} else {
+				amount_in
+			};
+			ConcentratedTicks::<T>::try_mutate(trading_pair, tick, |tick_info| -> DispatchResult {
+				let (reserve_in, reserve_out) = if zero_for_one {
+					(&mut tick_info.reserve_0, &mut tick_info.reserve_1)
+				} else {
+					(&mut tick_info.reserve_1, &mut tick_info.reserve_0)
+				};
+				*reserve_in = reserve_in.checked_add(amount_in).ok_or(ArithmeticError::Overflow)?;
+				*reserve_out = reserve_out.checked_sub(amount_out).ok_or(ArithmeticError::Underflow)?;
+				Ok(())
+			})?;
+
+			if i + 1 == steps_count {
+				// the last tick the swap goes through becomes the current tick
+				ConcentratedPools::<T>::try_mutate(trading_pair, |maybe_pool| -> DispatchResult {
+					let pool = maybe_pool.as_mut().ok_or(Error::<T>::ConcentratedPoolNotCreated)?;
+					pool.current_tick = tick;
+					Ok(())
+				})?;
+			}
+		}
+
+		if !protocol_fee.is_zero() {
+			let receiver = T::TreasuryAccount::get();
+			T::Currency::transfer(supply_currency_id, &Self::account_id(), &receiver, protocol_fee)?;
+			Self::deposit_event(Event::ConcentratedProtocolFeeCharged {
+				trading_pair,
+				receiver,
+				currency_id: supply_currency_id,
+				amount: protocol_fee,
+			});
+		}
+		Ok(())
+	}
+
+	/// Get the protocol share of the exchange fee in `amount_in` which is swapped in concentrated
+	/// liquidity pool, the exchange fee is `amount_in * exchange_fee`.
+	fn concentrated_protocol_fee(
+		amount_in: Balance,
+		exchange_fee: (u32, u32),
+		protocol_fee_rate: Option<(u32, u32)>,
+	) -> Balance {
+		let Some((protocol_fee_numerator, protocol_fee_denominator)) = protocol_fee_rate else {
+			return Zero::zero();
+		};
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		U256::from(amount_in)
+			.saturating_mul(U256::from(fee_numerator))
+			.saturating_mul(U256::from(protocol_fee_numerator))
+			.checked_div(U256::from(fee_denominator).saturating_mul(U256::from(protocol_fee_denominator)))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	fn _swap_by_path(path: &[CurrencyId], amounts: &[Balance], pool_kinds: &[PoolKind]) -> DispatchResult {
 		let mut i: usize = 0;
 		while i + 1 < path.len() {
 			let (supply_currency_id, target_currency_id) = (path[i], path[i + 1]);
 			let (supply_increment, target_decrement) = (amounts[i], amounts[i + 1]);
-			Self::_swap(
-				supply_currency_id,
-				target_currency_id,
-				supply_increment,
-				target_decrement,
-			)?;
+			match pool_kinds[i] {
+				PoolKind::ConstantProduct => Self::_swap(
+					supply_currency_id,
+					target_currency_id,
+					supply_increment,
+					target_decrement,
+				)?,
+				PoolKind::Concentrated => Self::_swap_concentrated(
+					supply_currency_id,
+					target_currency_id,
+					supply_increment,
+					target_decrement,
+				)?,
+			}
 			i += 1;
 		}
 		Ok(())
 	}
 
+	/// Returns the target amount and the count of ticks of concentrated liquidity pools that the
+	/// swap has gone through.
 	#[transactional]
 	fn do_swap_with_exact_supply(
 		who: &T::AccountId,
 		path: &[CurrencyId],
 		supply_amount: Balance,
 		min_target_amount: Balance,
-	) -> sp_std::result::Result<Balance, DispatchError> {
-		let amounts = Self::get_target_amounts(path, supply_amount)?;
+	) -> sp_std::result::Result<(Balance, u32), DispatchError> {
+		let (amounts, pool_kinds, concentrated_ticks) = Self::get_target_amounts_and_pools(path, supply_amount)?;
 		ensure!(
 			amounts[amounts.len() - 1] >= min_target_amount,
 			Error::<T>::InsufficientTargetAmount
@@ -1381,7 +2518,7 @@ impl<T: Config> Pallet<T> {
 		let actual_target_amount = amounts[amounts.len() - 1];
 
 		T::Currency::transfer(path[0], who, &module_account_id, supply_amount)?;
-		Self::_swap_by_path(path, &amounts)?;
+		Self::_swap_by_path(path, &amounts, &pool_kinds)?;
 		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, actual_target_amount)?;
 
 		Self::deposit_event(Event::Swap {
@@ -1389,23 +2526,25 @@ impl<T: Config> Pallet<T> {
 			path: path.to_vec(),
 			liquidity_changes: amounts,
 		});
-		Ok(actual_target_amount)
+		Ok((actual_target_amount, concentrated_ticks))
 	}
 
+	/// Returns the supply amount and the count of ticks of concentrated liquidity pools that the
+	/// swap has gone through.
 	#[transactional]
 	fn do_swap_with_exact_target(
 		who: &T::AccountId,
 		path: &[CurrencyId],
 		target_amount: Balance,
 		max_supply_amount: Balance,
-	) -> sp_std::result::Result<Balance, DispatchError> {
-		let amounts = Self::get_supply_amounts(path, target_amount)?;
+	) -> sp_std::result::Result<(Balance, u32), DispatchError> {
+		let (amounts, pool_kinds, concentrated_ticks) = Self::get_supply_amounts_and_pools(path, target_amount)?;
 		ensure!(amounts[0] <= max_supply_amount, Error::<T>::ExcessiveSupplyAmount);
 		let module_account_id = Self::account_id();
 		let actual_supply_amount = amounts[0];
 
 		T::Currency::transfer(path[0], who, &module_account_id, actual_supply_amount)?;
-		Self::_swap_by_path(path, &amounts)?;
+		Self::_swap_by_path(path, &amounts, &pool_kinds)?;
 		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, target_amount)?;
 
 		Self::deposit_event(Event::Swap {
@@ -1413,7 +2552,94 @@ impl<T: Config> Pallet<T> {
 			path: path.to_vec(),
 			liquidity_changes: amounts,
 		});
-		Ok(actual_supply_amount)
+		Ok((actual_supply_amount, concentrated_ticks))
+	}
+
+	/// Swap without holding the supply amount upfront. The pool transfers `target_amount` to `who`
+	/// first and calls back the receiver contract of `context` through `T::FlashSwapEvmBridge`,
+	/// then takes the supply amount including the exchange fee from `who`. The whole swap is
+	/// reverted if the supply amount cannot be taken or the invariant with fee is broken. The
+	/// callback runs with at most `gas_limit` gas.
+	///
+	/// Returns the supply amount.
+	#[transactional]
+	pub fn do_flash_swap(
+		who: &T::AccountId,
+		context: InvokeContext,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		data: Vec<u8>,
+		gas_limit: u64,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let trading_pair = TradingPair::from_currency_ids(supply_currency_id, target_currency_id)
+			.ok_or(Error::<T>::InvalidCurrencyId)?;
+		ensure!(
+			matches!(
+				Self::trading_pair_statuses(trading_pair),
+				TradingPairStatus::<_, _>::Enabled
+			),
+			Error::<T>::MustBeEnabled
+		);
+		ensure!(!target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
+
+		let exchange_fee = Self::exchange_fee(&trading_pair);
+		let (supply_pool, target_pool) = Self::get_liquidity(supply_currency_id, target_currency_id);
+		ensure!(target_amount < target_pool, Error::<T>::InsufficientLiquidity);
+		let supply_amount = Self::get_supply_amount(supply_pool, target_pool, target_amount, exchange_fee);
+		ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
+		ensure!(supply_amount <= max_supply_amount, Error::<T>::ExcessiveSupplyAmount);
+
+		let supply_token =
+			T::Erc20InfoMapping::encode_evm_address(supply_currency_id).ok_or(Error::<T>::InvalidCurrencyId)?;
+		let target_token =
+			T::Erc20InfoMapping::encode_evm_address(target_currency_id).ok_or(Error::<T>::InvalidCurrencyId)?;
+		let module_account_id = Self::account_id();
+
+		// send the target amount first
+		T::Currency::transfer(target_currency_id, &module_account_id, who, target_amount)?;
+		T::FlashSwapEvmBridge::on_flash_swap(
+			context,
+			supply_token,
+			target_token,
+			supply_amount,
+			target_amount,
+			data,
+			gas_limit,
+		)?;
+		T::Currency::transfer(supply_currency_id, who, &module_account_id, supply_amount)?;
+
+		// the pool may be changed by the callback, check the invariant with fee against the
+		// current pool: (supply_pool + supply_amount * (1 - fee)) * (target_pool - target_amount)
+		// >= supply_pool * target_pool
+		let (supply_pool, target_pool) = Self::get_liquidity(supply_currency_id, target_currency_id);
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		let supply_pool_adjusted = U256::from(supply_pool)
+			.saturating_mul(U256::from(fee_denominator))
+			.saturating_add(
+				U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator))),
+			);
+		let target_pool_after = target_pool
+			.checked_sub(target_amount)
+			.ok_or(Error::<T>::InsufficientLiquidity)?;
+		ensure!(
+			supply_pool_adjusted.saturating_mul(U256::from(target_pool_after))
+				>= U256::from(supply_pool)
+					.saturating_mul(U256::from(target_pool))
+					.saturating_mul(U256::from(fee_denominator)),
+			Error::<T>::InvariantCheckFailed
+		);
+		Self::_swap(supply_currency_id, target_currency_id, supply_amount, target_amount)?;
+
+		Self::deposit_event(Event::FlashSwap {
+			trader: who.clone(),
+			supply_currency_id,
+			target_currency_id,
+			supply_amount,
+			target_amount,
+		});
+		Ok(supply_amount)
 	}
 }
 
@@ -1422,6 +2648,12 @@ impl<T: Config> DEXManager<T::AccountId, Balance, CurrencyId> for Pallet<T> {
 		Self::get_liquidity(currency_id_a, currency_id_b)
 	}
 
+	fn get_exchange_fee(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (u32, u32) {
+		TradingPair::from_currency_ids(currency_id_a, currency_id_b)
+			.map(|trading_pair| Self::exchange_fee(&trading_pair))
+			.unwrap_or_else(T::GetExchangeFee::get)
+	}
+
 	fn get_liquidity_token_address(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> Option<H160> {
 		let trading_pair = TradingPair::from_currency_ids(currency_id_a, currency_id_b)?;
 		match Self::trading_pair_statuses(trading_pair) {
@@ -1506,15 +2738,24 @@ impl<T: Config> DEXManager<T::AccountId, Balance, CurrencyId> for Pallet<T> {
 		match limit {
 			SwapLimit::ExactSupply(exact_supply_amount, minimum_target_amount) => {
 				Self::do_swap_with_exact_supply(who, path, exact_supply_amount, minimum_target_amount)
-					.map(|actual_target_amount| (exact_supply_amount, actual_target_amount))
+					.map(|(actual_target_amount, _)| (exact_supply_amount, actual_target_amount))
 			}
 			SwapLimit::ExactTarget(maximum_supply_amount, exact_target_amount) => {
 				Self::do_swap_with_exact_target(who, path, exact_target_amount, maximum_supply_amount)
-					.map(|actual_supply_amount| (actual_supply_amount, exact_target_amount))
+					.map(|(actual_supply_amount, _)| (actual_supply_amount, exact_target_amount))
 			}
 		}
 	}
 
+	fn get_concentrated_swap_weight(path: &[CurrencyId]) -> Weight {
+		let concentrated_ticks = Self::concentrated_swap_ticks_of_path(path);
+		if concentrated_ticks.is_zero() {
+			Weight::zero()
+		} else {
+			<T as Config>::WeightInfo::concentrated_swap(concentrated_ticks)
+		}
+	}
+
 	// `do_add_liquidity` is used in genesis_build,
 	// but transactions are not supported by BasicExternalities,
 	// put `transactional` here
@@ -28,7 +28,7 @@ use frame_support::{
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockErc20InfoMapping, SpecificJointsSwap};
 use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
-use primitives::{Amount, TokenSymbol};
+use primitives::{evm::EvmAddress, Amount, TokenSymbol};
 use sp_runtime::{traits::IdentityLookup, BuildStorage};
 
 pub type BlockNumber = u64;
@@ -99,6 +99,7 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+	pub const TreasuryAccount: AccountId = 10;
 	pub AlternativeSwapPathJointList: Vec<Vec<CurrencyId>> = vec![
 		vec![DOT],
 	];
@@ -118,6 +119,51 @@ impl Happened<(TradingPair, Balance, Balance)> for MockOnLiquidityPoolUpdated {
 	}
 }
 
+parameter_types! {
+	pub static FlashSwapCalls: Vec<(EvmAddress, EvmAddress, Balance, Balance, Vec<u8>, u64)> = vec![];
+	pub static FlashSwapCallbackDeposit: Balance = 0;
+	pub static FlashSwapCallbackSwap: Balance = 0;
+}
+
+/// Simulates the receiver contract of CAROL, which gets `FlashSwapCallbackDeposit` of the supply
+/// currency, and swaps `FlashSwapCallbackSwap` of the supply currency to the target currency in
+/// the same pool by BOB.
+pub struct MockFlashSwapEvmBridge;
+impl FlashSwapEvmBridge for MockFlashSwapEvmBridge {
+	fn on_flash_swap(
+		_context: InvokeContext,
+		supply_token: EvmAddress,
+		target_token: EvmAddress,
+		supply_amount: Balance,
+		target_amount: Balance,
+		data: Vec<u8>,
+		gas_limit: u64,
+	) -> DispatchResult {
+		FlashSwapCalls::mutate(|v| {
+			v.push((
+				supply_token,
+				target_token,
+				supply_amount,
+				target_amount,
+				data,
+				gas_limit,
+			))
+		});
+		let supply_currency_id = MockErc20InfoMapping::decode_evm_address(supply_token).unwrap();
+		let target_currency_id = MockErc20InfoMapping::decode_evm_address(target_token).unwrap();
+		Tokens::deposit(supply_currency_id, &CAROL, FlashSwapCallbackDeposit::get())?;
+		if !FlashSwapCallbackSwap::get().is_zero() {
+			DexModule::do_swap_with_exact_supply(
+				&BOB,
+				&[supply_currency_id, target_currency_id],
+				FlashSwapCallbackSwap::get(),
+				0,
+			)?;
+		}
+		Ok(())
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Tokens;
@@ -130,6 +176,8 @@ impl Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<2000>;
 	type OnLiquidityPoolUpdated = MockOnLiquidityPoolUpdated;
+	type TreasuryAccount = TreasuryAccount;
+	type FlashSwapEvmBridge = MockFlashSwapEvmBridge;
 }
 
 parameter_types! {
@@ -23,11 +23,13 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	ACAJointSwap, AUSDBTCPair, AUSDDOTPair, AUSDJointSwap, DOTBTCPair, DexModule, ExtBuilder, ListingOrigin, Runtime,
-	RuntimeEvent, RuntimeOrigin, System, Tokens, ACA, ALICE, AUSD, AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
+	ACAJointSwap, AUSDBTCPair, AUSDDOTPair, AUSDJointSwap, DOTBTCPair, DexModule, ExtBuilder, FlashSwapCallbackDeposit,
+	FlashSwapCallbackSwap, FlashSwapCalls, ListingOrigin, Runtime, RuntimeEvent, RuntimeOrigin, System, Tokens,
+	TreasuryAccount, ACA, ALICE, AUSD, AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
 };
 use module_support::{Swap, SwapError};
 use orml_traits::MultiReservableCurrency;
+use primitives::evm::EvmAddress;
 use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
 use std::str::FromStr;
@@ -973,26 +975,47 @@ fn get_liquidity_work() {
 #[test]
 fn get_target_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_target_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 1, 1000000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 10000), 9949);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_target_amount(10000, 0, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_target_amount(0, 20000, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_target_amount(10000, 20000, 0, GetExchangeFee::get()), 0);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 1, 1000000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 10000, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
 	});
 }
 
 #[test]
 fn get_supply_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 1, 1), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 9949), 9999);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 9999), 9949);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 1801), 1000);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0, GetExchangeFee::get()), 0);
+		assert_eq!(DexModule::get_supply_amount(10000, 1, 1, GetExchangeFee::get()), 0);
+		assert_eq!(
+			DexModule::get_supply_amount(10000, 20000, 9949, GetExchangeFee::get()),
+			9999
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 9999, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(10000, 20000, 1801, GetExchangeFee::get()),
+			1000
+		);
+		assert_eq!(
+			DexModule::get_target_amount(10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
 	});
 }
 
@@ -1043,6 +1066,239 @@ fn get_target_amounts_work() {
 		});
 }
 
+#[test]
+fn update_exchange_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
+			assert_eq!(DexModule::exchange_fees(AUSDDOTPair::get()), None);
+			assert_eq!(DexModule::exchange_fee(&AUSDDOTPair::get()), (1, 100));
+			assert_eq!(DexModule::get_exchange_fee(DOT, AUSD), (1, 100));
+
+			assert_noop!(
+				DexModule::update_exchange_fee(RuntimeOrigin::signed(ALICE), AUSD, DOT, Some((1, 1000))),
+				BadOrigin
+			);
+			assert_noop!(
+				DexModule::update_exchange_fee(
+					RuntimeOrigin::signed(ListingOrigin::get()),
+					AUSD,
+					AUSD,
+					Some((1, 1000))
+				),
+				Error::<Runtime>::InvalidCurrencyId
+			);
+			assert_noop!(
+				DexModule::update_exchange_fee(RuntimeOrigin::signed(ListingOrigin::get()), AUSD, DOT, Some((1, 0))),
+				Error::<Runtime>::InvalidExchangeFee
+			);
+			assert_noop!(
+				DexModule::update_exchange_fee(
+					RuntimeOrigin::signed(ListingOrigin::get()),
+					AUSD,
+					DOT,
+					Some((1000, 1000))
+				),
+				Error::<Runtime>::InvalidExchangeFee
+			);
+
+			assert_ok!(DexModule::update_exchange_fee(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((1, 1000))
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::UpdateExchangeFee {
+				trading_pair: AUSDDOTPair::get(),
+				exchange_fee: Some((1, 1000)),
+			}));
+			assert_eq!(DexModule::exchange_fees(AUSDDOTPair::get()), Some((1, 1000)));
+			assert_eq!(DexModule::exchange_fee(&AUSDDOTPair::get()), (1, 1000));
+			assert_eq!(DexModule::get_exchange_fee(DOT, AUSD), (1, 1000));
+			assert_eq!(DexModule::get_exchange_fee(DOT, BTC), (1, 100));
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 10000),
+				Ok(vec![10000, 24987])
+			);
+			assert_eq!(
+				DexModule::get_supply_amounts(&[DOT, AUSD], 24987),
+				Ok(vec![10000, 24987])
+			);
+
+			assert_ok!(DexModule::update_exchange_fee(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				None
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::UpdateExchangeFee {
+				trading_pair: AUSDDOTPair::get(),
+				exchange_fee: None,
+			}));
+			assert_eq!(DexModule::exchange_fees(AUSDDOTPair::get()), None);
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 10000),
+				Ok(vec![10000, 24874])
+			);
+		});
+}
+
+#[test]
+fn protocol_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let dex_share_currency_id = AUSDDOTPair::get().dex_share_currency_id();
+
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000_000_000,
+				1_000_000_000_000,
+				0,
+				false,
+			));
+			assert_eq!(Tokens::total_issuance(dex_share_currency_id), 2_000_000_000_000);
+			assert_eq!(DexModule::protocol_fee_k_last(AUSDDOTPair::get()), U256::zero());
+
+			assert_noop!(
+				DexModule::update_protocol_fee_rate(RuntimeOrigin::signed(ALICE), AUSD, DOT, Some((1, 5))),
+				BadOrigin
+			);
+			assert_noop!(
+				DexModule::update_protocol_fee_rate(
+					RuntimeOrigin::signed(ListingOrigin::get()),
+					AUSD,
+					DOT,
+					Some((0, 5))
+				),
+				Error::<Runtime>::InvalidProtocolFeeRate
+			);
+			assert_noop!(
+				DexModule::update_protocol_fee_rate(
+					RuntimeOrigin::signed(ListingOrigin::get()),
+					AUSD,
+					DOT,
+					Some((6, 5))
+				),
+				Error::<Runtime>::InvalidProtocolFeeRate
+			);
+
+			assert_ok!(DexModule::update_protocol_fee_rate(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((1, 5))
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::UpdateProtocolFeeRate {
+				trading_pair: AUSDDOTPair::get(),
+				protocol_fee_rate: Some((1, 5)),
+			}));
+			assert_eq!(DexModule::protocol_fee_rates(AUSDDOTPair::get()), Some((1, 5)));
+			assert_eq!(
+				DexModule::protocol_fee_k_last(AUSDDOTPair::get()),
+				U256::from(1_000_000_000_000_000_000_000_000u128)
+			);
+
+			// the swap accrues exchange fee, but protocol fee is not minted until liquidity changes
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(BOB),
+				vec![AUSD, DOT],
+				100_000_000_000,
+				0,
+			));
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(1_100_000_000_000, 909_918_107_371)
+			);
+			assert_eq!(Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()), 0);
+
+			// sqrt(k) grows from 1_000_000_000_000 to 1_000_454_855_607,
+			// share_amount = 2_000_000_000_000 * 454_855_607 / (4 * 1_000_454_855_607 + 1_000_000_000_000)
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				AUSD,
+				DOT,
+				1_000_000_000,
+				1_000_000_000,
+				0,
+				false,
+			));
+			System::assert_has_event(RuntimeEvent::DexModule(crate::Event::ProtocolFeeMinted {
+				trading_pair: AUSDDOTPair::get(),
+				receiver: TreasuryAccount::get(),
+				share_amount: 181_876_060,
+			}));
+			assert_eq!(
+				Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()),
+				181_876_060
+			);
+			let (pool_0, pool_1) = DexModule::liquidity_pool(AUSDDOTPair::get());
+			assert_eq!(
+				DexModule::protocol_fee_k_last(AUSDDOTPair::get()),
+				U256::from(pool_0) * U256::from(pool_1)
+			);
+
+			// no protocol fee is minted when no swap happened since the last liquidity change
+			System::reset_events();
+			assert_ok!(DexModule::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000_000,
+				0,
+				0,
+				false,
+			));
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::DexModule(crate::Event::ProtocolFeeMinted { .. })
+			)));
+			assert_eq!(
+				Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()),
+				181_876_060
+			);
+
+			// turn off the protocol fee will settle the accrued protocol fee
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(BOB),
+				vec![DOT, AUSD],
+				100_000_000_000,
+				0,
+			));
+
+			// sqrt(k) grows from 1_000_864_178_079 to 1_001_359_881_001,
+			// share_amount = 2_001_000_223_219 * 495_702_922 / (4 * 1_001_359_881_001 + 1_000_864_178_079)
+			assert_ok!(DexModule::update_protocol_fee_rate(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				None
+			));
+			System::assert_has_event(RuntimeEvent::DexModule(crate::Event::ProtocolFeeMinted {
+				trading_pair: AUSDDOTPair::get(),
+				receiver: TreasuryAccount::get(),
+				share_amount: 198_130_540,
+			}));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::UpdateProtocolFeeRate {
+				trading_pair: AUSDDOTPair::get(),
+				protocol_fee_rate: None,
+			}));
+			assert_eq!(
+				Tokens::free_balance(dex_share_currency_id, &TreasuryAccount::get()),
+				181_876_060 + 198_130_540
+			);
+			assert_eq!(DexModule::protocol_fee_rates(AUSDDOTPair::get()), None);
+			assert_eq!(DexModule::protocol_fee_k_last(AUSDDOTPair::get()), U256::zero());
+		});
+}
+
 #[test]
 fn calculate_amount_for_big_number_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1054,7 +1310,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_supply_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				1_000_000_000_000_000_000_000
+				1_000_000_000_000_000_000_000,
+				GetExchangeFee::get()
 			),
 			3_140_495_867_768_595_041_323
 		);
@@ -1062,7 +1319,8 @@ fn calculate_amount_for_big_number_work() {
 			DexModule::get_target_amount(
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				3_140_495_867_768_595_041_323
+				3_140_495_867_768_595_041_323,
+				GetExchangeFee::get()
 			),
 			1_000_000_000_000_000_000_000
 		);
@@ -1147,14 +1405,445 @@ fn _swap_by_path_work() {
 
 			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (50000, 10000));
 			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (100000, 10));
-			assert_ok!(DexModule::_swap_by_path(&[DOT, AUSD], &[10000, 25000]));
+			assert_ok!(DexModule::_swap_by_path(
+				&[DOT, AUSD],
+				&[10000, 25000],
+				&[PoolKind::ConstantProduct]
+			));
 			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (25000, 20000));
-			assert_ok!(DexModule::_swap_by_path(&[DOT, AUSD, BTC], &[100000, 20000, 1]));
+			assert_ok!(DexModule::_swap_by_path(
+				&[DOT, AUSD, BTC],
+				&[100000, 20000, 1],
+				&[PoolKind::ConstantProduct, PoolKind::ConstantProduct]
+			));
 			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (5000, 120000));
 			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (120000, 9));
 		});
 }
 
+#[test]
+fn concentrated_liquidity_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let tick_step = Ratio::saturating_from_rational(1, 1000);
+
+			assert_noop!(
+				DexModule::create_concentrated_pool(RuntimeOrigin::signed(ALICE), AUSD, DOT, Price::one(), tick_step),
+				BadOrigin
+			);
+			assert_noop!(
+				DexModule::create_concentrated_pool(
+					RuntimeOrigin::signed(ListingOrigin::get()),
+					AUSD,
+					DOT,
+					Price::one(),
+					Ratio::zero()
+				),
+				Error::<Runtime>::InvalidConcentratedPoolParameters
+			);
+			assert_noop!(
+				DexModule::add_concentrated_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					AUSD,
+					DOT,
+					1_000_000,
+					1_000_000,
+					-4,
+					4
+				),
+				Error::<Runtime>::ConcentratedPoolNotCreated
+			);
+
+			assert_ok!(DexModule::create_concentrated_pool(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Price::one(),
+				tick_step
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::ConcentratedPoolCreated {
+				trading_pair: AUSDDOTPair::get(),
+				base_price: Price::one(),
+				tick_step,
+			}));
+			assert_noop!(
+				DexModule::create_concentrated_pool(
+					RuntimeOrigin::signed(ListingOrigin::get()),
+					DOT,
+					AUSD,
+					Price::one(),
+					tick_step
+				),
+				Error::<Runtime>::ConcentratedPoolAlreadyCreated
+			);
+			assert_noop!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 300_000),
+				Error::<Runtime>::InsufficientLiquidity
+			);
+
+			assert_noop!(
+				DexModule::add_concentrated_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					AUSD,
+					DOT,
+					1_000_000,
+					1_000_000,
+					4,
+					-4
+				),
+				Error::<Runtime>::InvalidTickRange
+			);
+			assert_noop!(
+				DexModule::add_concentrated_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					AUSD,
+					DOT,
+					1_000_000,
+					1_000_000,
+					0,
+					MAX_CONCENTRATED_POSITION_TICKS as i32
+				),
+				Error::<Runtime>::InvalidTickRange
+			);
+
+			// AUSD is distributed to ticks [0, 4], DOT is distributed to ticks [-4, 0]
+			assert_ok!(DexModule::add_concentrated_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				-4,
+				4
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::AddConcentratedLiquidity {
+				who: ALICE,
+				currency_0: AUSD,
+				pool_0: 1_000_000,
+				currency_1: DOT,
+				pool_1: 1_000_000,
+				lower_tick: -4,
+				upper_tick: 4,
+			}));
+			assert_eq!(
+				DexModule::concentrated_ticks(AUSDDOTPair::get(), 0),
+				ConcentratedTick {
+					reserve_0: 200_000,
+					reserve_1: 200_000,
+					total_shares: 400_000,
+				}
+			);
+			assert_eq!(
+				DexModule::concentrated_ticks(AUSDDOTPair::get(), 1),
+				ConcentratedTick {
+					reserve_0: 200_000,
+					reserve_1: 0,
+					total_shares: 200_200,
+				}
+			);
+			assert_eq!(DexModule::concentrated_ticks(AUSDDOTPair::get(), -1).reserve_1, 200_000);
+			assert_eq!(
+				DexModule::concentrated_positions(ALICE, (AUSDDOTPair::get(), 1)),
+				200_200
+			);
+			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 1_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 1_000_000);
+
+			// the constant product pool has no liquidity, swap goes through the concentrated liquidity pool
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 300_000),
+				Ok(vec![300_000, 296_902])
+			);
+			assert_eq!(
+				DexModule::get_supply_amounts(&[DOT, AUSD], 296_902),
+				Ok(vec![300_000, 296_902])
+			);
+			assert_eq!(
+				DexModule::get_swap_amount(&[DOT, AUSD], SwapLimit::ExactSupply(300_000, 0)),
+				Some((300_000, 296_902))
+			);
+
+			// the concentrated liquidity pool is better than the constant product pool
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				AUSD,
+				DOT,
+				100_000,
+				100_000,
+				0,
+				false
+			));
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 300_000),
+				Ok(vec![300_000, 296_902])
+			);
+
+			assert_ok!(DexModule::swap_with_exact_supply(
+				RuntimeOrigin::signed(BOB),
+				vec![DOT, AUSD],
+				300_000,
+				296_902
+			));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (100_000, 100_000));
+			assert_eq!(
+				DexModule::concentrated_ticks(AUSDDOTPair::get(), 0),
+				ConcentratedTick {
+					reserve_0: 0,
+					reserve_1: 402_021,
+					total_shares: 400_000,
+				}
+			);
+			assert_eq!(
+				DexModule::concentrated_ticks(AUSDDOTPair::get(), 1),
+				ConcentratedTick {
+					reserve_0: 103_098,
+					reserve_1: 97_979,
+					total_shares: 200_200,
+				}
+			);
+			assert_eq!(
+				DexModule::concentrated_pools(AUSDDOTPair::get()).map(|pool| pool.current_tick),
+				Some(1)
+			);
+
+			// remove all liquidity from the concentrated liquidity pool
+			let alice_ausd_balance = Tokens::free_balance(AUSD, &ALICE);
+			let alice_dot_balance = Tokens::free_balance(DOT, &ALICE);
+			assert_noop!(
+				DexModule::remove_concentrated_liquidity(RuntimeOrigin::signed(ALICE), AUSD, DOT, -4, 4, 703_099, 0),
+				Error::<Runtime>::UnacceptableLiquidityWithdrawn
+			);
+			assert_ok!(DexModule::remove_concentrated_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				-4,
+				4,
+				703_098,
+				1_300_000
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::RemoveConcentratedLiquidity {
+				who: ALICE,
+				currency_0: AUSD,
+				pool_0: 703_098,
+				currency_1: DOT,
+				pool_1: 1_300_000,
+				lower_tick: -4,
+				upper_tick: 4,
+			}));
+			assert_eq!(Tokens::free_balance(AUSD, &ALICE), alice_ausd_balance + 703_098);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), alice_dot_balance + 1_300_000);
+			assert_eq!(DexModule::concentrated_ticks(AUSDDOTPair::get(), 1), Default::default());
+			assert_eq!(DexModule::concentrated_positions(ALICE, (AUSDDOTPair::get(), 1)), 0);
+			// the tick range of the pool is shrunk after the liquidity is removed
+			assert_eq!(
+				DexModule::concentrated_pools(AUSDDOTPair::get()).map(|pool| (pool.lowest_tick, pool.highest_tick)),
+				Some((4, 4))
+			);
+
+			// swap goes back to the constant product pool
+			assert_eq!(
+				DexModule::get_target_amounts(&[DOT, AUSD], 300_000),
+				Ok(vec![300_000, 74_811])
+			);
+		});
+}
+
+#[test]
+fn swap_with_exact_target_through_concentrated_pool_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(DexModule::create_concentrated_pool(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Price::one(),
+				Ratio::saturating_from_rational(1, 1000)
+			));
+			assert_ok!(DexModule::add_concentrated_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				-4,
+				4
+			));
+
+			assert_noop!(
+				DexModule::swap_with_exact_target(RuntimeOrigin::signed(BOB), vec![DOT, AUSD], 296_902, 299_999),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+
+			let bob_ausd_balance = Tokens::free_balance(AUSD, &BOB);
+			let bob_dot_balance = Tokens::free_balance(DOT, &BOB);
+			// the swap goes through tick 0 and tick 1, the actual weight is charged by the ticks
+			assert_eq!(
+				DexModule::swap_with_exact_target(RuntimeOrigin::signed(BOB), vec![DOT, AUSD], 296_902, 300_000)
+					.map(|post_info| post_info.actual_weight),
+				Ok(Some(DexModule::swap_with_exact_target_weight(2, 2)))
+			);
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::Swap {
+				trader: BOB,
+				path: vec![DOT, AUSD],
+				liquidity_changes: vec![300_000, 296_902],
+			}));
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), bob_ausd_balance + 296_902);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), bob_dot_balance - 300_000);
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
+			assert_eq!(DexModule::concentrated_ticks(AUSDDOTPair::get(), 0).reserve_0, 0);
+			assert_eq!(
+				DexModule::concentrated_pools(AUSDDOTPair::get()).map(|pool| pool.current_tick),
+				Some(1)
+			);
+		});
+}
+
+#[test]
+fn swap_through_concentrated_pool_charges_protocol_fee() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(DexModule::create_concentrated_pool(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Price::one(),
+				Ratio::saturating_from_rational(1, 1000)
+			));
+			assert_ok!(DexModule::add_concentrated_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				-4,
+				4
+			));
+			assert_ok!(DexModule::update_protocol_fee_rate(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((1, 5))
+			));
+
+			let treasury_dot_balance = Tokens::free_balance(DOT, &TreasuryAccount::get());
+			let bob_dot_balance = Tokens::free_balance(DOT, &BOB);
+			assert_ok!(DexModule::swap_with_exact_target(
+				RuntimeOrigin::signed(BOB),
+				vec![DOT, AUSD],
+				296_902,
+				300_000
+			));
+
+			// the swap pays 202_021 DOT to tick 0 and 97_979 DOT to tick 1, the protocol takes
+			// 1/5 of the 1/100 exchange fee of each: 202_021 / 500 + 97_979 / 500 = 404 + 195
+			System::assert_has_event(RuntimeEvent::DexModule(crate::Event::ConcentratedProtocolFeeCharged {
+				trading_pair: AUSDDOTPair::get(),
+				receiver: TreasuryAccount::get(),
+				currency_id: DOT,
+				amount: 599,
+			}));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::Swap {
+				trader: BOB,
+				path: vec![DOT, AUSD],
+				liquidity_changes: vec![300_000, 296_902],
+			}));
+			assert_eq!(Tokens::free_balance(DOT, &BOB), bob_dot_balance - 300_000);
+			assert_eq!(
+				Tokens::free_balance(DOT, &TreasuryAccount::get()),
+				treasury_dot_balance + 599
+			);
+			assert_eq!(
+				DexModule::concentrated_ticks(AUSDDOTPair::get(), 0).reserve_1,
+				200_000 + 202_021 - 404
+			);
+			assert_eq!(
+				DexModule::concentrated_ticks(AUSDDOTPair::get(), 1).reserve_1,
+				97_979 - 195
+			);
+			assert_eq!(
+				Tokens::free_balance(DOT, &DexModule::account_id()),
+				1_000_000 + 300_000 - 599
+			);
+		});
+}
+
+#[test]
+fn swap_with_exact_target_through_concentrated_and_constant_product_pools_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(DexModule::create_concentrated_pool(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Price::one(),
+				Ratio::saturating_from_rational(1, 1000)
+			));
+			assert_ok!(DexModule::add_concentrated_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				-4,
+				4
+			));
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false
+			));
+
+			// DOT to AUSD goes through the concentrated liquidity pool, AUSD to BTC goes through
+			// the constant product pool
+			let amounts = DexModule::get_supply_amounts(&[DOT, AUSD, BTC], 200_000).unwrap();
+			assert_eq!(amounts[2], 200_000);
+			let bob_btc_balance = Tokens::free_balance(BTC, &BOB);
+			let bob_dot_balance = Tokens::free_balance(DOT, &BOB);
+			assert_ok!(DexModule::swap_with_exact_target(
+				RuntimeOrigin::signed(BOB),
+				vec![DOT, AUSD, BTC],
+				200_000,
+				amounts[0]
+			));
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::Swap {
+				trader: BOB,
+				path: vec![DOT, AUSD, BTC],
+				liquidity_changes: amounts.clone(),
+			}));
+			assert_eq!(Tokens::free_balance(BTC, &BOB), bob_btc_balance + 200_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), bob_dot_balance - amounts[0]);
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
+			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (1_000_000 + amounts[1], 800_000));
+			assert_eq!(
+				(-4..=4)
+					.map(|tick| DexModule::concentrated_ticks(AUSDDOTPair::get(), tick).reserve_0)
+					.sum::<Balance>(),
+				1_000_000 - amounts[1]
+			);
+			assert_eq!(
+				Tokens::free_balance(DOT, &DexModule::account_id()),
+				1_000_000 + amounts[0]
+			);
+		});
+}
+
 #[test]
 fn add_liquidity_work() {
 	ExtBuilder::default()
@@ -1993,3 +2682,144 @@ fn specific_joint_swap_work() {
 			);
 		});
 }
+
+#[test]
+fn flash_swap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			let context = InvokeContext {
+				contract: H160::default(),
+				sender: H160::default(),
+				origin: H160::default(),
+			};
+
+			assert_noop!(
+				DexModule::do_flash_swap(
+					&CAROL,
+					context,
+					AUSD,
+					ACA,
+					1_000_000_000_000,
+					10_000_000_000_000,
+					vec![],
+					1_000_000
+				),
+				Error::<Runtime>::MustBeEnabled
+			);
+			assert_noop!(
+				DexModule::do_flash_swap(&CAROL, context, AUSD, DOT, 0, 10_000_000_000_000, vec![], 1_000_000),
+				Error::<Runtime>::ZeroTargetAmount
+			);
+			assert_noop!(
+				DexModule::do_flash_swap(
+					&CAROL,
+					context,
+					AUSD,
+					DOT,
+					100_000_000_000_000,
+					u128::MAX,
+					vec![],
+					1_000_000
+				),
+				Error::<Runtime>::InsufficientLiquidity
+			);
+			assert_noop!(
+				DexModule::do_flash_swap(
+					&CAROL,
+					context,
+					AUSD,
+					DOT,
+					1_000_000_000_000,
+					5_000_000_000_000,
+					vec![],
+					1_000_000
+				),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+
+			// the receiver does not repay
+			assert_noop!(
+				DexModule::do_flash_swap(
+					&CAROL,
+					context,
+					AUSD,
+					DOT,
+					1_000_000_000_000,
+					10_000_000_000_000,
+					vec![],
+					1_000_000
+				),
+				orml_tokens::Error::<Runtime>::BalanceTooLow
+			);
+
+			// the pool is changed in the callback and the invariant with fee is broken
+			FlashSwapCallbackDeposit::set(5_101_520_253_036);
+			FlashSwapCallbackSwap::set(10_000_000_000_000);
+			assert_noop!(
+				DexModule::do_flash_swap(
+					&CAROL,
+					context,
+					AUSD,
+					DOT,
+					1_000_000_000_000,
+					10_000_000_000_000,
+					vec![],
+					1_000_000
+				),
+				Error::<Runtime>::InvariantCheckFailed
+			);
+
+			FlashSwapCalls::set(vec![]);
+			FlashSwapCallbackSwap::set(0);
+			assert_eq!(
+				DexModule::do_flash_swap(
+					&CAROL,
+					context,
+					AUSD,
+					DOT,
+					1_000_000_000_000,
+					10_000_000_000_000,
+					vec![1, 2, 3],
+					500_000
+				),
+				Ok(5_101_520_253_036)
+			);
+			System::assert_last_event(RuntimeEvent::DexModule(crate::Event::FlashSwap {
+				trader: CAROL,
+				supply_currency_id: AUSD,
+				target_currency_id: DOT,
+				supply_amount: 5_101_520_253_036,
+				target_amount: 1_000_000_000_000,
+			}));
+			assert_eq!(
+				FlashSwapCalls::get(),
+				vec![(
+					EvmAddress::try_from(AUSD).unwrap(),
+					EvmAddress::try_from(DOT).unwrap(),
+					5_101_520_253_036,
+					1_000_000_000_000,
+					vec![1, 2, 3],
+					500_000
+				)]
+			);
+			assert_eq!(
+				DexModule::get_liquidity(AUSD, DOT),
+				(505_101_520_253_036, 99_000_000_000_000)
+			);
+			assert_eq!(Tokens::free_balance(AUSD, &CAROL), 0);
+			assert_eq!(Tokens::free_balance(DOT, &CAROL), 1_000_000_000_000);
+		});
+}
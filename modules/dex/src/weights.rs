@@ -61,6 +61,12 @@ pub trait WeightInfo {
 	fn swap_with_exact_target(u: u32, ) -> Weight;
 	fn refund_provision() -> Weight;
 	fn abort_provisioning() -> Weight;
+	fn update_exchange_fee() -> Weight;
+	fn update_protocol_fee_rate() -> Weight;
+	fn create_concentrated_pool() -> Weight;
+	fn add_concentrated_liquidity(t: u32, ) -> Weight;
+	fn remove_concentrated_liquidity(t: u32, ) -> Weight;
+	fn concentrated_swap(t: u32, ) -> Weight;
 }
 
 /// Weights for module_dex using the Acala node and recommended hardware.
@@ -149,6 +155,50 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
+	fn update_exchange_fee() -> Weight {
+		// Estimated from the single `ExchangeFees` write, not benchmarked yet.
+		Weight::from_parts(15_212_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn update_protocol_fee_rate() -> Weight {
+		// Estimated from `add_liquidity` for minting the protocol fee share, not benchmarked yet.
+		Weight::from_parts(44_516_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	fn create_concentrated_pool() -> Weight {
+		// Estimated from `update_exchange_fee` plus the `ConcentratedPools` read, not benchmarked yet.
+		Weight::from_parts(17_834_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn add_concentrated_liquidity(t: u32, ) -> Weight {
+		// Estimated from `add_liquidity` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(71_526_000, 0)
+			.saturating_add(Weight::from_parts(13_452_000, 0).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(t as u64)))
+	}
+	fn remove_concentrated_liquidity(t: u32, ) -> Weight {
+		// Estimated from `remove_liquidity` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(65_341_000, 0)
+			.saturating_add(Weight::from_parts(12_118_000, 0).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(t as u64)))
+	}
+	fn concentrated_swap(t: u32, ) -> Weight {
+		// Estimated from `swap_with_exact_target` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(93_966_000, 0)
+			.saturating_add(Weight::from_parts(12_118_000, 0).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(t as u64)))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -236,4 +286,48 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
+	fn update_exchange_fee() -> Weight {
+		// Estimated from the single `ExchangeFees` write, not benchmarked yet.
+		Weight::from_parts(15_212_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn update_protocol_fee_rate() -> Weight {
+		// Estimated from `add_liquidity` for minting the protocol fee share, not benchmarked yet.
+		Weight::from_parts(44_516_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	fn create_concentrated_pool() -> Weight {
+		// Estimated from `update_exchange_fee` plus the `ConcentratedPools` read, not benchmarked yet.
+		Weight::from_parts(17_834_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn add_concentrated_liquidity(t: u32, ) -> Weight {
+		// Estimated from `add_liquidity` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(71_526_000, 0)
+			.saturating_add(Weight::from_parts(13_452_000, 0).saturating_mul(t as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(t as u64)))
+	}
+	fn remove_concentrated_liquidity(t: u32, ) -> Weight {
+		// Estimated from `remove_liquidity` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(65_341_000, 0)
+			.saturating_add(Weight::from_parts(12_118_000, 0).saturating_mul(t as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(t as u64)))
+	}
+	fn concentrated_swap(t: u32, ) -> Weight {
+		// Estimated from `swap_with_exact_target` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(93_966_000, 0)
+			.saturating_add(Weight::from_parts(12_118_000, 0).saturating_mul(t as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(t as u64)))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+	}
 }
@@ -24,8 +24,9 @@ use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 use module_evm::{ExitReason, ExitSucceed};
 use module_support::{
-	evm::limits::{erc20, liquidation},
-	EVMBridge as EVMBridgeTrait, ExecutionMode, InvokeContext, LiquidationEvmBridge as LiquidationEvmBridgeT, EVM,
+	evm::limits::{erc20, flash_swap, liquidation},
+	EVMBridge as EVMBridgeTrait, ExecutionMode, FlashSwapEvmBridge as FlashSwapEvmBridgeT, InvokeContext,
+	LiquidationEvmBridge as LiquidationEvmBridgeT, EVM,
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::{evm::EvmAddress, Balance};
@@ -49,6 +50,7 @@ pub enum Action {
 	Liquidate = "liquidate(address,address,uint256,uint256)",
 	OnCollateralTransfer = "onCollateralTransfer(address,uint256)",
 	OnRepaymentRefund = "onRepaymentRefund(address,uint256)",
+	OnFlashSwap = "onFlashSwap(address,address,uint256,uint256,bytes)",
 }
 
 mod mock;
@@ -345,7 +347,63 @@ impl<T: Config> LiquidationEvmBridgeT for LiquidationEvmBridge<T> {
 	}
 }
 
+pub struct FlashSwapEvmBridge<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> FlashSwapEvmBridgeT for FlashSwapEvmBridge<T> {
+	fn on_flash_swap(
+		context: InvokeContext,
+		supply_token: EvmAddress,
+		target_token: EvmAddress,
+		supply_amount: Balance,
+		target_amount: Balance,
+		data: Vec<u8>,
+		gas_limit: u64,
+	) -> DispatchResult {
+		let input = Pallet::<T>::encode_on_flash_swap(supply_token, target_token, supply_amount, target_amount, &data);
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			gas_limit,
+			flash_swap::ON_FLASH_SWAP_STORAGE_LIMIT,
+			ExecutionMode::Execute,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)
+	}
+}
+
 impl<T: Config> Pallet<T> {
+	fn encode_on_flash_swap(
+		supply_token: EvmAddress,
+		target_token: EvmAddress,
+		supply_amount: Balance,
+		target_amount: Balance,
+		data: &[u8],
+	) -> Vec<u8> {
+		// flash swap receiver method hash
+		let mut input = Into::<u32>::into(Action::OnFlashSwap).to_be_bytes().to_vec();
+
+		// append supply ERC20 address
+		input.extend_from_slice(H256::from(supply_token).as_bytes());
+		// append target ERC20 address
+		input.extend_from_slice(H256::from(target_token).as_bytes());
+		// append supply amount to be repaid
+		input.extend_from_slice(H256::from_uint(&U256::from(supply_amount)).as_bytes());
+		// append target amount received
+		input.extend_from_slice(H256::from_uint(&U256::from(target_amount)).as_bytes());
+		// append offset of data, which is after the 5 parameters
+		input.extend_from_slice(H256::from_uint(&U256::from(5 * 32)).as_bytes());
+		// append data length
+		input.extend_from_slice(H256::from_uint(&U256::from(data.len())).as_bytes());
+		// append data, right padded to 32 bytes
+		input.extend_from_slice(data);
+		input.resize(input.len() + (32 - data.len() % 32) % 32, 0);
+
+		input
+	}
+
 	fn handle_exit_reason(exit_reason: ExitReason) -> Result<(), DispatchError> {
 		match exit_reason {
 			ExitReason::Succeed(ExitSucceed::Returned) => Ok(()),
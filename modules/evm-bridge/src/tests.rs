@@ -355,6 +355,42 @@ fn liquidation_err_fails_as_expected() {
 		});
 }
 
+#[test]
+fn encode_on_flash_swap_works() {
+	let supply_token = EvmAddress::from_str("1000000000000000000000000000000000000111").unwrap();
+	let target_token = EvmAddress::from_str("1000000000000000000000000000000000000222").unwrap();
+
+	// 33 bytes of data is right padded to 2 words
+	let data = [[0x11u8; 32].to_vec(), vec![0x22]].concat();
+	let input = EVMBridgeModule::encode_on_flash_swap(supply_token, target_token, 300, 200, &data);
+	assert_eq!(
+		hex::encode(input),
+		[
+			// selector
+			"bcbc5e55",
+			"0000000000000000000000001000000000000000000000000000000000000111",
+			"0000000000000000000000001000000000000000000000000000000000000222",
+			"000000000000000000000000000000000000000000000000000000000000012c",
+			"00000000000000000000000000000000000000000000000000000000000000c8",
+			// offset of data
+			"00000000000000000000000000000000000000000000000000000000000000a0",
+			// length of data
+			"0000000000000000000000000000000000000000000000000000000000000021",
+			"1111111111111111111111111111111111111111111111111111111111111111",
+			"2200000000000000000000000000000000000000000000000000000000000000",
+		]
+		.concat()
+	);
+
+	// empty data has no padding
+	let input = EVMBridgeModule::encode_on_flash_swap(supply_token, target_token, 300, 200, &[]);
+	assert_eq!(input.len(), 4 + 6 * 32);
+	assert_eq!(
+		hex::encode(&input[4 + 5 * 32..]),
+		"0000000000000000000000000000000000000000000000000000000000000000"
+	);
+}
+
 #[cfg(feature = "tracing")]
 #[test]
 fn tracing_should_work() {
@@ -19,7 +19,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
-use primitives::evm::{AccessListItem, BlockLimits, CallInfo, CreateInfo, EstimateResourcesRequest};
+use primitives::evm::{
+	AccessListItem, BlockLimits, CallInfo, ContractStorageInfo, CreateInfo, EstimateResourcesRequest,
+};
 use sp_core::H160;
 use sp_runtime::{
 	codec::Codec,
@@ -28,7 +30,7 @@ use sp_runtime::{
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait EVMRuntimeRPCApi<Balance, AccountId> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 		AccountId: Codec + MaybeDisplay + MaybeFromStr,
@@ -78,15 +80,38 @@ sp_api::decl_runtime_apis! {
 			access_list: Option<Vec<AccessListItem>>,
 			estimate: bool,
 		) -> Result<CreateInfo, sp_runtime::DispatchError>;
+
+		/// Storage usage and reserved storage deposit of the contract.
+		fn contract_storage_info(address: H160) -> Option<ContractStorageInfo<Balance>>;
 	}
 }
 
 #[cfg(feature = "tracing")]
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait EVMTraceApi {
 		fn trace_extrinsic(
 			extrinsic: Block::Extrinsic,
 			tracer_config: primitives::evm::tracing::TracerConfig,
 		) -> Result<primitives::evm::tracing::TraceOutcome, sp_runtime::transaction_validity::TransactionValidityError>;
+
+		/// Trace a call without submitting a transaction, any state changes are discarded.
+		fn trace_call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: primitives::Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<primitives::evm::tracing::TraceOutcome, sp_runtime::DispatchError>;
+
+		/// Replay the whole block and trace every extrinsic which executed the EVM, including the
+		/// EVM calls made by other pallets. Returns the index of the extrinsic with its trace.
+		fn trace_block(
+			block: Block,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<Vec<(u32, primitives::evm::tracing::TraceOutcome)>, sp_runtime::transaction_validity::TransactionValidityError>;
 	}
 }
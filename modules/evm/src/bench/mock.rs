@@ -285,6 +285,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId32>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type TreasuryAccount = TreasuryAccount;
+	type FlashSwapEvmBridge = ();
 }
 
 pub type SignedExtra = (frame_system::CheckWeight<Runtime>,);
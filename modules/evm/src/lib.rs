@@ -61,8 +61,9 @@ pub use orml_traits::{currency::TransferAll, MultiCurrency};
 use parity_scale_codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 pub use primitives::{
 	evm::{
-		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, is_system_contract, CallInfo, CreateInfo,
-		EvmAddress, ExecutionInfo, Vicinity, MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
+		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, is_system_contract, BatchCallInfo,
+		CallInfo, ContractStorageInfo, CreateInfo, EvmAddress, ExecutionInfo, Vicinity, MIRRORED_NFT_ADDRESS_START,
+		MIRRORED_TOKENS_ADDRESS_START,
 	},
 	task::TaskResult,
 	Balance, CurrencyId, Nonce, ReserveIdentifier,
@@ -113,6 +114,34 @@ static ACALA_CONFIG: EvmConfig = EvmConfig {
 	..module_evm_utility::evm::Config::shanghai()
 };
 
+/// EVM features which are not covered by `EvmConfig`.
+#[derive(Clone, Copy, Default, Eq, PartialEq, RuntimeDebug)]
+pub struct EvmFeatures {
+	/// EIP-1153, enables `TLOAD` and `TSTORE`.
+	pub has_transient_storage: bool,
+	/// EIP-5656, enables `MCOPY`.
+	pub has_mcopy: bool,
+	/// EIP-7516, enables `BLOBBASEFEE`.
+	pub has_blob_base_fee: bool,
+}
+
+impl EvmFeatures {
+	/// Opcodes of the Cancun hard fork, used together with the shanghai based `EvmConfig`.
+	pub const fn cancun() -> Self {
+		Self {
+			has_transient_storage: true,
+			has_mcopy: true,
+			has_blob_base_fee: true,
+		}
+	}
+}
+
+static ACALA_FEATURES: EvmFeatures = EvmFeatures {
+	has_transient_storage: false,
+	has_mcopy: false,
+	has_blob_base_fee: false,
+};
+
 /// Create an empty contract `contract Empty { }`.
 pub const BASE_CREATE_GAS: u64 = 67_072;
 /// Call function that just set a storage `function store(uint256 num) public { number = num; }`.
@@ -132,6 +161,8 @@ fn create2_weight<T: Config>(gas: u64) -> Weight {
 		// during `create2` benchmark an additional of `BASE_CREATE_GAS` was used
 		// so user will be extra charged only for extra gas usage
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CREATE_GAS)))
+		// record the deployment in `DeterministicDeployments`
+		.saturating_add(T::DbWeight::get().writes(1))
 }
 
 /// Helper method to calculate `create_predeploy_contract` weight.
@@ -142,6 +173,14 @@ fn create_predeploy_contract<T: Config>(gas: u64) -> Weight {
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CREATE_GAS)))
 }
 
+/// Helper method to calculate `create_deterministic_predeploy_contract` weight.
+fn create_deterministic_predeploy_contract<T: Config>(gas: u64) -> Weight {
+	<T as Config>::WeightInfo::create_deterministic_predeploy_contract()
+		// during `create_deterministic_predeploy_contract` benchmark an additional of `BASE_CREATE_GAS`
+		// was used so user will be extra charged only for extra gas usage
+		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CREATE_GAS)))
+}
+
 /// Helper method to calculate `create_nft_contract` weight.
 fn create_nft_contract<T: Config>(gas: u64) -> Weight {
 	<T as Config>::WeightInfo::create_nft_contract()
@@ -158,6 +197,23 @@ fn call_weight<T: Config>(gas: u64) -> Weight {
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CALL_GAS)))
 }
 
+/// Helper method to calculate `batch_call` weight.
+fn batch_call_weight<T: Config>(calls: u32, input_len: u32, gas: u64) -> Weight {
+	<T as Config>::WeightInfo::batch_call(calls, input_len)
+		// during `batch_call` benchmark an additional of `BASE_CALL_GAS` was used by each call
+		// so user will be extra charged only for extra gas usage
+		.saturating_add(T::GasToWeight::convert(
+			gas.saturating_sub(BASE_CALL_GAS.saturating_mul(calls.into())),
+		))
+}
+
+/// Total input length of the calls in `batch_call`.
+fn batch_input_len<T: Config>(calls: &[(EvmAddress, Vec<u8>, BalanceOf<T>)]) -> u32 {
+	calls.iter().fold(0u32, |len, (_, input, _)| {
+		len.saturating_add(input.len().saturated_into())
+	})
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -165,6 +221,8 @@ pub mod module {
 	parameter_types! {
 		// Contract max code size.
 		pub const MaxCodeSize: u32 = 60 * 1024;
+		// Max number of calls in `batch_call`.
+		pub const MaxBatchCalls: u32 = 32;
 	}
 
 	/// EVM module trait
@@ -215,6 +273,11 @@ pub mod module {
 			&ACALA_CONFIG
 		}
 
+		/// EVM features enabled on top of `config`.
+		fn features() -> &'static EvmFeatures {
+			&ACALA_FEATURES
+		}
+
 		/// Required origin for creating system contract.
 		type NetworkContractOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -279,6 +342,22 @@ pub mod module {
 		pub ref_count: u32,
 	}
 
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub enum BatchCallMode {
+		/// Stop at the first failed call and revert all the calls.
+		Atomic,
+		/// Continue with the rest calls if a call failed.
+		BestEffort,
+	}
+
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub struct DeploymentInfo {
+		pub deployer: EvmAddress,
+		pub salt: H256,
+		/// Keccak256 hash of the init code.
+		pub code_hash: H256,
+	}
+
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, Default, Serialize, Deserialize)]
 	/// Account definition used for genesis block construction.
 	pub struct GenesisAccount<Balance, Nonce> {
@@ -347,6 +426,14 @@ pub mod module {
 	#[pallet::getter(fn network_contract_index)]
 	pub type NetworkContractIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// The provenance of contracts deployed at CREATE2 addresses by `create2` and
+	/// `create_deterministic_predeploy_contract`.
+	///
+	/// DeterministicDeployments: map EvmAddress => Option<DeploymentInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn deterministic_deployments)]
+	pub type DeterministicDeployments<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, DeploymentInfo, OptionQuery>;
+
 	/// Extrinsics origin for the current transaction.
 	///
 	/// ExtrinsicOrigin: Option<AccountId>
@@ -500,6 +587,22 @@ pub mod module {
 		ContractSetCode { contract: EvmAddress },
 		/// Selfdestructed contract code.
 		ContractSelfdestructed { contract: EvmAddress },
+		/// A batch of calls has been executed. `results` has the exit reason and output of each
+		/// executed call.
+		BatchExecuted {
+			from: EvmAddress,
+			mode: BatchCallMode,
+			results: Vec<(EvmAddress, ExitReason, Vec<u8>)>,
+			logs: Vec<Log>,
+			used_gas: u64,
+			used_storage: i32,
+		},
+		/// The storage usage and reserved storage deposit of a contract changed.
+		ContractStorageChanged {
+			contract: EvmAddress,
+			used_storage: u32,
+			reserved_deposit: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -694,6 +797,86 @@ pub mod module {
 			}
 		}
 
+		/// Issue a batch of EVM calls in one transaction, sharing the gas limit, the storage limit
+		/// and the transaction fee.
+		///
+		/// - `calls`: the contract address, input data and value of each call
+		/// - `mode`: revert all the calls on the first failure, or continue with the rest calls
+		/// - `gas_limit`: the maximum gas all the calls can use
+		/// - `storage_limit`: the total bytes the contracts' storage can increase by
+		#[pallet::call_index(17)]
+		#[pallet::weight(batch_call_weight::<T>(calls.len() as u32, batch_input_len::<T>(calls), *gas_limit))]
+		pub fn batch_call(
+			origin: OriginFor<T>,
+			calls: BoundedVec<(EvmAddress, Vec<u8>, BalanceOf<T>), MaxBatchCalls>,
+			mode: BatchCallMode,
+			#[pallet::compact] gas_limit: u64,
+			#[pallet::compact] storage_limit: u32,
+			access_list: Vec<AccessListItem>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let source = T::AddressMapping::get_or_create_evm_address(&who);
+
+			Self::ensure_eoa(&source)?;
+
+			let targets: Vec<EvmAddress> = calls.iter().map(|(target, _, _)| *target).collect();
+			let targets_len = targets.len() as u32;
+			let input_len = batch_input_len::<T>(&calls);
+			let outcome = T::Runner::batch_call(
+				source,
+				source,
+				calls.into_inner(),
+				mode == BatchCallMode::Atomic,
+				gas_limit,
+				storage_limit,
+				access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+				T::config(),
+			);
+
+			match outcome {
+				Err(e) => {
+					// EVM state changes reverted, increase nonce by ourselves
+					Self::inc_nonce(&source);
+
+					let exit_reason = ExitReason::Error(ExitError::Other(Into::<&str>::into(e).into()));
+					Pallet::<T>::deposit_event(Event::<T>::BatchExecuted {
+						from: source,
+						mode,
+						results: targets
+							.into_iter()
+							.map(|target| (target, exit_reason.clone(), vec![]))
+							.collect(),
+						logs: vec![],
+						used_gas: gas_limit,
+						used_storage: Default::default(),
+					});
+
+					Ok(().into())
+				}
+				Ok(info) => {
+					let used_gas: u64 = info.used_gas.unique_saturated_into();
+
+					Pallet::<T>::deposit_event(Event::<T>::BatchExecuted {
+						from: source,
+						mode,
+						results: targets
+							.into_iter()
+							.zip(info.value)
+							.map(|(target, (exit_reason, output))| (target, exit_reason, output))
+							.collect(),
+						logs: info.logs,
+						used_gas,
+						used_storage: info.used_storage,
+					});
+
+					Ok(PostDispatchInfo {
+						actual_weight: Some(batch_call_weight::<T>(targets_len, input_len, used_gas)),
+						pays_fee: Pays::Yes,
+					})
+				}
+			}
+		}
+
 		/// Issue an EVM call operation on a scheduled contract call, and
 		/// refund the unused gas reserved when the call was scheduled.
 		///
@@ -878,7 +1061,8 @@ pub mod module {
 			}
 		}
 
-		/// Issue an EVM create2 operation.
+		/// Issue an EVM create2 operation. The provenance of the created contract is recorded in
+		/// `DeterministicDeployments`.
 		///
 		/// - `target`: the contract address to call
 		/// - `input`: the data supplied for the contract's constructor
@@ -902,6 +1086,7 @@ pub mod module {
 
 			Self::ensure_eoa(&source)?;
 
+			let code_hash = code_hash(&input);
 			let outcome = T::Runner::create2(
 				source,
 				input,
@@ -933,6 +1118,15 @@ pub mod module {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
 
 					if info.exit_reason.is_succeed() {
+						DeterministicDeployments::<T>::insert(
+							info.value,
+							DeploymentInfo {
+								deployer: source,
+								salt,
+								code_hash,
+							},
+						);
+
 						Pallet::<T>::deposit_event(Event::<T>::Created {
 							from: source,
 							contract: info.value,
@@ -1068,72 +1262,66 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			T::NetworkContractOrigin::ensure_origin(origin)?;
 
-			ensure!(Self::accounts(target).is_none(), Error::<T>::ContractAlreadyExisted);
-
-			let source = T::NetworkContractSource::get();
-			let source_account = T::AddressMapping::get_account_id(&source);
-			// ensure source have more than 10 KAR/ACA to deploy the contract.
-			let amount = T::Currency::minimum_balance().saturating_mul(100u32.into());
-			if T::Currency::free_balance(&source_account) < amount {
-				T::Currency::transfer(
-					&T::TreasuryAccount::get(),
-					&source_account,
-					amount,
-					ExistenceRequirement::AllowDeath,
-				)?;
-			}
-
-			match T::Runner::create_at_address(
-				source,
+			Self::do_create_predeploy_contract(
 				target,
 				input,
 				value,
 				gas_limit,
 				storage_limit,
-				access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect(),
-				T::config(),
-			) {
-				Err(e) => {
-					Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
-						from: source,
-						contract: H160::default(),
-						exit_reason: ExitReason::Error(ExitError::Other(Into::<&str>::into(e).into())),
-						logs: vec![],
-						used_gas: gas_limit,
-						used_storage: Default::default(),
-					});
+				access_list,
+				create_predeploy_contract::<T>,
+			)
+		}
 
-					Ok(().into())
-				}
-				Ok(info) => {
-					let used_gas: u64 = info.used_gas.unique_saturated_into();
-					let contract = info.value;
+		/// Create a predeploy contract at a deterministic address, like the CREATE2 opcode.
+		///
+		/// The address is derived from `NetworkContractSource`, `salt` and the hash of `input`, and
+		/// the provenance is recorded in `DeterministicDeployments`.
+		///
+		/// - `salt`: the salt used to derive the contract address
+		/// - `input`: the data supplied for the contract's constructor
+		/// - `value`: the amount sent for payable calls
+		/// - `gas_limit`: the maximum gas the call can use
+		/// - `storage_limit`: the total bytes the contract's storage can increase by
+		#[pallet::call_index(16)]
+		#[pallet::weight(create_deterministic_predeploy_contract::<T>(*gas_limit))]
+		pub fn create_deterministic_predeploy_contract(
+			origin: OriginFor<T>,
+			salt: H256,
+			input: Vec<u8>,
+			#[pallet::compact] value: BalanceOf<T>,
+			#[pallet::compact] gas_limit: u64,
+			#[pallet::compact] storage_limit: u32,
+			access_list: Vec<AccessListItem>,
+		) -> DispatchResultWithPostInfo {
+			T::NetworkContractOrigin::ensure_origin(origin)?;
 
-					if info.exit_reason.is_succeed() {
-						Pallet::<T>::deposit_event(Event::<T>::Created {
-							from: source,
-							contract,
-							logs: info.logs,
-							used_gas,
-							used_storage: info.used_storage,
-						});
-					} else {
-						Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
-							from: source,
-							contract,
-							exit_reason: info.exit_reason.clone(),
-							logs: info.logs,
-							used_gas,
-							used_storage: Default::default(),
-						});
-					}
+			let deployer = T::NetworkContractSource::get();
+			let code_hash = code_hash(&input);
+			let target = create2_address(deployer, salt, code_hash);
 
-					Ok(PostDispatchInfo {
-						actual_weight: Some(create_predeploy_contract::<T>(used_gas)),
-						pays_fee: Pays::No,
-					})
-				}
+			let post_info = Self::do_create_predeploy_contract(
+				target,
+				input,
+				value,
+				gas_limit,
+				storage_limit,
+				access_list,
+				create_deterministic_predeploy_contract::<T>,
+			)?;
+
+			if Self::is_contract(&target) {
+				DeterministicDeployments::<T>::insert(
+					target,
+					DeploymentInfo {
+						deployer,
+						salt,
+						code_hash,
+					},
+				);
 			}
+
+			Ok(post_info)
 		}
 
 		/// Transfers Contract maintainership to a new EVM Address.
@@ -1360,6 +1548,83 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	fn do_create_predeploy_contract(
+		target: EvmAddress,
+		input: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<AccessListItem>,
+		weight: fn(u64) -> Weight,
+	) -> DispatchResultWithPostInfo {
+		ensure!(Self::accounts(target).is_none(), Error::<T>::ContractAlreadyExisted);
+
+		let source = T::NetworkContractSource::get();
+		let source_account = T::AddressMapping::get_account_id(&source);
+		// ensure source have more than 10 KAR/ACA to deploy the contract.
+		let amount = T::Currency::minimum_balance().saturating_mul(100u32.into());
+		if T::Currency::free_balance(&source_account) < amount {
+			T::Currency::transfer(
+				&T::TreasuryAccount::get(),
+				&source_account,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		match T::Runner::create_at_address(
+			source,
+			target,
+			input,
+			value,
+			gas_limit,
+			storage_limit,
+			access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+			T::config(),
+		) {
+			Err(e) => {
+				Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
+					from: source,
+					contract: H160::default(),
+					exit_reason: ExitReason::Error(ExitError::Other(Into::<&str>::into(e).into())),
+					logs: vec![],
+					used_gas: gas_limit,
+					used_storage: Default::default(),
+				});
+
+				Ok(().into())
+			}
+			Ok(info) => {
+				let used_gas: u64 = info.used_gas.unique_saturated_into();
+				let contract = info.value;
+
+				if info.exit_reason.is_succeed() {
+					Pallet::<T>::deposit_event(Event::<T>::Created {
+						from: source,
+						contract,
+						logs: info.logs,
+						used_gas,
+						used_storage: info.used_storage,
+					});
+				} else {
+					Pallet::<T>::deposit_event(Event::<T>::CreatedFailed {
+						from: source,
+						contract,
+						exit_reason: info.exit_reason.clone(),
+						logs: info.logs,
+						used_gas,
+						used_storage: Default::default(),
+					});
+				}
+
+				Ok(PostDispatchInfo {
+					actual_weight: Some(weight(used_gas)),
+					pays_fee: Pays::No,
+				})
+			}
+		}
+	}
+
 	#[transactional]
 	pub fn remove_contract(caller: &EvmAddress, contract: &EvmAddress) -> DispatchResult {
 		let contract_account = T::AddressMapping::get_account_id(contract);
@@ -1386,6 +1651,7 @@ impl<T: Config> Pallet<T> {
 				});
 
 				let _total_size = ContractStorageSizes::<T>::take(contract);
+				DeterministicDeployments::<T>::remove(contract);
 
 				// schedule to remove
 				T::IdleScheduler::schedule(
@@ -1573,6 +1839,15 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Storage usage and reserved storage deposit of the contract.
+	pub fn contract_storage_info(address: &EvmAddress) -> ContractStorageInfo<BalanceOf<T>> {
+		let contract_acc = T::AddressMapping::get_account_id(address);
+		ContractStorageInfo {
+			used_storage: Self::contract_storage_sizes(address),
+			reserved_deposit: T::Currency::reserved_balance_named(&RESERVE_ID_STORAGE_DEPOSIT, &contract_acc),
+		}
+	}
+
 	pub fn update_contract_storage_size(address: &EvmAddress, change: i32) {
 		if change == 0 {
 			return;
@@ -1703,8 +1978,8 @@ impl<T: Config> Pallet<T> {
 			if storage_size_changed.is_positive() {
 				Self::reserve_storage(&source, storage_size_changed as u32)?;
 			}
-			Self::charge_storage(&source, &contract, storage_size_changed)?;
 			Self::update_contract_storage_size(&contract, storage_size_changed);
+			Self::charge_storage(&source, &contract, storage_size_changed)?;
 
 			// try remove old codes
 			CodeInfos::<T>::mutate_exists(contract_info.code_hash, |maybe_code_info| -> DispatchResult {
@@ -1858,6 +2133,13 @@ impl<T: Config> Pallet<T> {
 			debug_assert!(val.is_zero());
 		};
 
+		let info = Self::contract_storage_info(contract);
+		Self::deposit_event(Event::<T>::ContractStorageChanged {
+			contract: *contract,
+			used_storage: info.used_storage,
+			reserved_deposit: info.reserved_deposit,
+		});
+
 		Ok(())
 	}
 
@@ -1883,6 +2165,13 @@ impl<T: Config> Pallet<T> {
 		)?;
 		debug_assert!(val.is_zero());
 
+		let info = Self::contract_storage_info(contract);
+		Self::deposit_event(Event::<T>::ContractStorageChanged {
+			contract: *contract,
+			used_storage: info.used_storage,
+			reserved_deposit: info.reserved_deposit,
+		});
+
 		// transfer to treasury if maintainer is contract itself
 		let dest = if contract_acc == maintainer_acc {
 			T::TreasuryAccount::get()
@@ -2090,6 +2379,16 @@ pub fn code_hash(code: &[u8]) -> H256 {
 	H256::from_slice(Keccak256::digest(code).as_slice())
 }
 
+/// Contract address derived by the CREATE2 scheme.
+pub fn create2_address(caller: EvmAddress, salt: H256, code_hash: H256) -> EvmAddress {
+	let mut hasher = Keccak256::new();
+	hasher.update([0xff]);
+	hasher.update(&caller[..]);
+	hasher.update(&salt[..]);
+	hasher.update(&code_hash[..]);
+	H256::from_slice(hasher.finalize().as_slice()).into()
+}
+
 #[allow(dead_code)]
 fn encode_revert_message(msg: &[u8]) -> Vec<u8> {
 	// A minimum size of error function selector (4) + offset (32) + string length
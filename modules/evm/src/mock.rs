@@ -177,6 +177,8 @@ ord_parameter_types! {
 pub const NEW_CONTRACT_EXTRA_BYTES: u32 = 100;
 pub const DEVELOPER_DEPOSIT: u128 = 1000;
 pub const PUBLICATION_FEE: u128 = 200;
+
+static MOCK_FEATURES: EvmFeatures = EvmFeatures::cancun();
 impl Config for Runtime {
 	type AddressMapping = MockAddressMapping;
 	type Currency = Balances;
@@ -204,6 +206,10 @@ impl Config for Runtime {
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
+
+	fn features() -> &'static EvmFeatures {
+		&MOCK_FEATURES
+	}
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -125,6 +125,7 @@ pub mod tests {
 		pub gas_used: u64,
 		pub context: &'inner Context,
 		pub is_static: bool,
+		pub logs: Vec<(H160, Vec<H256>, Vec<u8>)>,
 	}
 
 	impl<'inner> MockPrecompileHandle<'inner> {
@@ -136,6 +137,7 @@ pub mod tests {
 				gas_used: 0,
 				context,
 				is_static,
+				logs: Vec::new(),
 			}
 		}
 	}
@@ -184,8 +186,9 @@ pub mod tests {
 			unimplemented!()
 		}
 
-		fn log(&mut self, _address: H160, _topics: Vec<H256>, _data: Vec<u8>) -> Result<(), ExitError> {
-			unimplemented!()
+		fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
+			self.logs.push((address, topics, data));
+			Ok(())
 		}
 
 		fn code_address(&self) -> H160 {
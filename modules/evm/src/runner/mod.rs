@@ -24,7 +24,7 @@ pub mod tagged_runtime;
 #[cfg(feature = "tracing")]
 pub mod tracing;
 
-use crate::{BalanceOf, CallInfo, Config, CreateInfo};
+use crate::{BalanceOf, BatchCallInfo, CallInfo, Config, CreateInfo};
 use module_evm_utility::evm;
 pub use primitives::evm::{EvmAddress, Vicinity};
 use sp_core::{H160, H256};
@@ -75,6 +75,17 @@ pub trait Runner<T: Config> {
 		access_list: Vec<(H160, Vec<H256>)>,
 		config: &evm::Config,
 	) -> Result<CreateInfo, DispatchError>;
+
+	fn batch_call(
+		source: H160,
+		origin: H160,
+		calls: Vec<(H160, Vec<u8>, BalanceOf<T>)>,
+		atomic: bool,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		config: &evm::Config,
+	) -> Result<BatchCallInfo, DispatchError>;
 }
 
 pub trait RunnerExtended<T: Config>: Runner<T> {
@@ -24,7 +24,8 @@ use crate::{
 		state::{Accessed, StackExecutor, StackState as StackStateT, StackSubstateMetadata},
 		Runner as RunnerT, RunnerExtended,
 	},
-	AccountStorages, BalanceOf, CallInfo, Config, CreateInfo, Error, ExecutionInfo, Pallet, STORAGE_SIZE,
+	AccountStorages, BalanceOf, BatchCallInfo, CallInfo, Config, CreateInfo, Error, EvmFeatures, ExecutionInfo, Pallet,
+	STORAGE_SIZE,
 };
 use frame_support::{
 	ensure,
@@ -54,6 +55,17 @@ use sp_std::{
 	vec::Vec,
 };
 
+macro_rules! record_prestate {
+	($address:expr) => {{
+		#[cfg(feature = "tracing")]
+		crate::runner::tracing::with(|tracer| tracer.record_account::<T>($address));
+	}};
+	($address:expr, $index:expr) => {{
+		#[cfg(feature = "tracing")]
+		crate::runner::tracing::with(|tracer| tracer.record_storage::<T>($address, $index));
+	}};
+}
+
 #[derive(Default)]
 pub struct Runner<T: Config> {
 	_marker: PhantomData<T>,
@@ -96,6 +108,10 @@ impl<T: Config> Runner<T> {
 			Error::<T>::InvalidDecimals
 		);
 
+		// reserve storage will modify the balance of origin
+		record_prestate!(source);
+		record_prestate!(origin);
+
 		if !skip_storage_rent {
 			Pallet::<T>::reserve_storage(&origin, storage_limit).map_err(|e| {
 				log::debug!(
@@ -214,6 +230,9 @@ impl<T: Config> Runner<T> {
 			state.substate.logs
 		);
 
+		#[cfg(feature = "tracing")]
+		crate::runner::tracing::with(|tracer| tracer.record_poststate::<T>());
+
 		Ok(ExecutionInfo {
 			value: retv,
 			exit_reason: reason,
@@ -360,6 +379,53 @@ impl<T: Config> RunnerT<T> for Runner<T> {
 			},
 		)
 	}
+
+	/// Require transactional here. Always need to send events.
+	#[transactional]
+	fn batch_call(
+		source: H160,
+		origin: H160,
+		calls: Vec<(H160, Vec<u8>, BalanceOf<T>)>,
+		atomic: bool,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+		config: &evm::Config,
+	) -> Result<BatchCallInfo, DispatchError> {
+		let mut total_value = U256::zero();
+		let mut transact_calls = Vec::with_capacity(calls.len());
+		for (target, input, value) in calls {
+			ensure!(
+				Pallet::<T>::can_call_contract(&target, &source),
+				Error::<T>::NoPermission
+			);
+
+			let value = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(value));
+			// `execute` only checks the total value
+			ensure!(
+				convert_decimals_from_evm(
+					TryInto::<BalanceOf<T>>::try_into(value).map_err(|_| Error::<T>::InvalidDecimals)?
+				)
+				.is_some(),
+				Error::<T>::InvalidDecimals
+			);
+			total_value = total_value.saturating_add(value);
+			transact_calls.push((target, value, input));
+		}
+
+		let precompiles = T::PrecompilesValue::get();
+		Self::execute(
+			source,
+			origin,
+			total_value,
+			gas_limit,
+			storage_limit,
+			config,
+			false,
+			&precompiles,
+			|executor| executor.transact_batch_call(source, transact_calls, access_list, atomic),
+		)
+	}
 }
 
 impl<T: Config> RunnerExtended<T> for Runner<T> {
@@ -435,6 +501,7 @@ struct SubstrateStackSubstate<'config> {
 	storage_logs: Vec<(H160, i32)>,
 	parent: Option<Box<SubstrateStackSubstate<'config>>>,
 	known_original_storage: BTreeMap<(H160, H256), H256>,
+	transient_storage: BTreeMap<(H160, H256), H256>,
 }
 
 impl<'config> SubstrateStackSubstate<'config> {
@@ -454,6 +521,7 @@ impl<'config> SubstrateStackSubstate<'config> {
 			logs: Vec::new(),
 			storage_logs: Vec::new(),
 			known_original_storage: BTreeMap::new(),
+			transient_storage: BTreeMap::new(),
 		};
 		mem::swap(&mut entering, self);
 
@@ -474,6 +542,7 @@ impl<'config> SubstrateStackSubstate<'config> {
 		})?;
 		self.logs.append(&mut exited.logs);
 		self.deletes.append(&mut exited.deletes);
+		self.transient_storage.append(&mut exited.transient_storage);
 
 		exited.storage_logs.push((target, storage));
 		self.storage_logs.append(&mut exited.storage_logs);
@@ -546,6 +615,21 @@ impl<'config> SubstrateStackSubstate<'config> {
 		}
 		self.known_original_storage.insert((address, index), value);
 	}
+
+	pub fn transient_storage(&self, address: H160, index: H256) -> H256 {
+		if let Some(value) = self.transient_storage.get(&(address, index)) {
+			return *value;
+		}
+		if let Some(parent) = self.parent.as_ref() {
+			return parent.transient_storage(address, index);
+		}
+		H256::default()
+	}
+
+	pub fn set_transient_storage(&mut self, address: H160, index: H256, value: H256) {
+		// keep the value even if it is zero, it overrides the value of parent
+		self.transient_storage.insert((address, index), value);
+	}
 }
 
 #[cfg(feature = "evm-tests")]
@@ -591,6 +675,7 @@ impl<'vicinity, 'config, T: Config> SubstrateStackState<'vicinity, 'config, T> {
 				storage_logs: Vec::new(),
 				parent: None,
 				known_original_storage: BTreeMap::new(),
+				transient_storage: BTreeMap::new(),
 			},
 			_marker: PhantomData,
 		}
@@ -678,6 +763,7 @@ impl<'vicinity, 'config, T: Config> BackendT for SubstrateStackState<'vicinity,
 	}
 
 	fn basic(&self, address: H160) -> evm::backend::Basic {
+		record_prestate!(address);
 		let account = Pallet::<T>::account_basic(&address);
 
 		evm::backend::Basic {
@@ -687,10 +773,12 @@ impl<'vicinity, 'config, T: Config> BackendT for SubstrateStackState<'vicinity,
 	}
 
 	fn code(&self, address: H160) -> Vec<u8> {
+		record_prestate!(address);
 		Pallet::<T>::code_at_address(&address).into_inner()
 	}
 
 	fn storage(&self, address: H160, index: H256) -> H256 {
+		record_prestate!(address, index);
 		AccountStorages::<T>::get(address, index)
 	}
 
@@ -740,12 +828,18 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 		self.substate.deleted(address)
 	}
 
+	fn features(&self) -> EvmFeatures {
+		*T::features()
+	}
+
 	fn inc_nonce(&mut self, address: H160) -> Result<(), ExitError> {
+		record_prestate!(address);
 		Pallet::<T>::inc_nonce(&address);
 		Ok(())
 	}
 
 	fn set_storage(&mut self, address: H160, index: H256, value: H256) {
+		record_prestate!(address, index);
 		let current = <AccountStorages<T>>::get(address, index);
 
 		// keep track of original storage
@@ -785,6 +879,14 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 		}
 	}
 
+	fn transient_storage(&self, address: H160, index: H256) -> H256 {
+		self.substate.transient_storage(address, index)
+	}
+
+	fn set_transient_storage(&mut self, address: H160, index: H256, value: H256) {
+		self.substate.set_transient_storage(address, index, value)
+	}
+
 	fn reset_storage(&mut self, address: H160) {
 		// use drain_prefix to avoid wasm-bencher counting limit as write operation
 		<AccountStorages<T>>::drain_prefix(address).for_each(drop);
@@ -854,6 +956,7 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 			is_published
 		);
 
+		record_prestate!(address);
 		let code_size = code.len() as u32;
 		Pallet::<T>::create_contract(*caller, address, is_published, code);
 
@@ -863,6 +966,8 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 	}
 
 	fn transfer(&mut self, transfer: Transfer) -> Result<(), ExitError> {
+		record_prestate!(transfer.source);
+		record_prestate!(transfer.target);
 		self.touch(transfer.target);
 		if transfer.value.is_zero() {
 			return Ok(());
@@ -893,6 +998,7 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 	fn reset_balance(&mut self, address: H160) {
 		// Address and target can be the same during SELFDESTRUCT. In that case we transfer the
 		// remaining balance to treasury
+		record_prestate!(address);
 		let source = T::AddressMapping::get_account_id(&address);
 		let balance = T::Currency::free_balance(&source);
 		if !balance.is_zero() {
@@ -934,10 +1040,12 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 	}
 
 	fn code_size(&self, address: H160) -> U256 {
+		record_prestate!(address);
 		Pallet::<T>::code_size_at_address(&address)
 	}
 
 	fn code_hash(&self, address: H160) -> H256 {
+		record_prestate!(address);
 		Pallet::<T>::code_hash_at_address(&address)
 	}
 }
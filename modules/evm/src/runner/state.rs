@@ -19,15 +19,16 @@
 // Synchronize with https://github.com/rust-blockchain/evm/blob/d543f10/src/executor/stack/executor.rs
 
 use crate::{
-	encode_revert_message,
+	create2_address, encode_revert_message,
 	runner::tagged_runtime::{RuntimeKind, TaggedRuntime},
-	IsPrecompileResult, PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileSet, StorageMeter,
+	EvmFeatures, IsPrecompileResult, PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileSet,
+	StorageMeter,
 };
 use core::{cmp::min, convert::Infallible};
 use module_evm_utility::{
 	evm::{
 		backend::Backend, maybe_borrowed::MaybeBorrowed, Capture, Config, Context, CreateScheme, ExitError, ExitFatal,
-		ExitReason, Opcode, Resolve, Runtime, Stack, Transfer,
+		ExitReason, ExitSucceed, Machine, Opcode, Resolve, Runtime, Stack, Transfer,
 	},
 	evm_gasometer::{self as gasometer, Gasometer, StorageTarget},
 	evm_runtime::Handler,
@@ -77,6 +78,29 @@ macro_rules! emit_exit {
 	}};
 }
 
+// EIP-1153 opcodes, not supported by evm.
+const TLOAD: Opcode = Opcode(0x5c);
+const TSTORE: Opcode = Opcode(0x5d);
+// Same as `WARM_STORAGE_READ_COST` of Cancun.
+const GAS_TRANSIENT_STORAGE: u64 = 100;
+// EIP-5656 opcode, not supported by evm.
+const MCOPY: Opcode = Opcode(0x5e);
+// EIP-7516 opcode, not supported by evm.
+const BLOBBASEFEE: Opcode = Opcode(0x4a);
+const GAS_BASE: u64 = 2;
+// There are no blobs, always return `MIN_BASE_FEE_PER_BLOB_GAS` of EIP-4844.
+const BLOB_BASE_FEE: U256 = U256([1, 0, 0, 0]);
+
+// Opcodes which are not supported by evm and enabled by `EvmFeatures`.
+fn has_feature_opcode(features: &EvmFeatures, opcode: Opcode) -> bool {
+	match opcode {
+		TLOAD | TSTORE => features.has_transient_storage,
+		MCOPY => features.has_mcopy,
+		BLOBBASEFEE => features.has_blob_base_fee,
+		_ => false,
+	}
+}
+
 // Default call stack capacity that can be used to
 // execute the stack without reallocating.
 const DEFAULT_CALL_STACK_CAPACITY: usize = 4;
@@ -311,8 +335,12 @@ pub trait StackState<'config>: Backend {
 	fn is_cold(&self, address: H160) -> bool;
 	fn is_storage_cold(&self, address: H160, key: H256) -> bool;
 
+	fn features(&self) -> EvmFeatures;
+
 	fn inc_nonce(&mut self, address: H160) -> Result<(), ExitError>;
 	fn set_storage(&mut self, address: H160, key: H256, value: H256);
+	fn transient_storage(&self, address: H160, key: H256) -> H256;
+	fn set_transient_storage(&mut self, address: H160, key: H256, value: H256);
 	fn reset_storage(&mut self, address: H160);
 	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>);
 	fn set_deleted(&mut self, address: H160);
@@ -370,6 +398,8 @@ pub struct StackExecutor<'config, 'precompiles, S, P> {
 	config: &'config Config,
 	state: S,
 	precompile_set: &'precompiles P,
+	// address of the transient storage accessed by the current opcode
+	transient_address: Option<H160>,
 }
 
 impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecutor<'config, 'precompiles, S, P> {
@@ -389,6 +419,7 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 			config,
 			state,
 			precompile_set,
+			transient_address: None,
 		}
 	}
 
@@ -771,6 +802,124 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 		}
 	}
 
+	/// Execute a batch of calls as one transaction, sharing the gas limit and the storage meter.
+	/// If `atomic`, stop at the first failed call and revert all the calls, otherwise continue
+	/// with the rest. Returns the first failure reason, with the result of every executed call.
+	pub fn transact_batch_call(
+		&mut self,
+		caller: H160,
+		calls: Vec<(H160, U256, Vec<u8>)>,
+		access_list: Vec<(H160, Vec<H256>)>,
+		atomic: bool,
+	) -> (ExitReason, Vec<(ExitReason, Vec<u8>)>) {
+		if let Err(e) = self.state.inc_nonce(caller) {
+			return (e.into(), Vec::new());
+		}
+
+		// charge the transaction cost once, as if all the inputs are in one transaction
+		let data: Vec<u8> = calls.iter().flat_map(|(_, _, input)| input.iter().copied()).collect();
+		let transaction_cost = gasometer::call_transaction_cost(&data, &access_list);
+		let gasometer = &mut self.state.metadata_mut().gasometer;
+		if let Err(e) = gasometer.record_transaction(transaction_cost) {
+			return (e.into(), Vec::new());
+		}
+
+		// Initialize initial addresses for EIP-2929
+		if self.config.increase_state_access_gas {
+			let addresses = core::iter::once(caller).chain(calls.iter().map(|(address, _, _)| *address));
+			if self.config.warm_coinbase_address {
+				// Warm coinbase address for EIP-3651
+				let addresses = addresses.chain(core::iter::once(self.block_coinbase()));
+				self.state.metadata_mut().access_addresses(addresses);
+			} else {
+				self.state.metadata_mut().access_addresses(addresses);
+			}
+
+			self.initialize_with_access_list(access_list);
+		}
+
+		if atomic {
+			// a substate holding all the calls, so they can be reverted together
+			let gas_limit = self.state.metadata().gasometer.gas();
+			if let Err(e) = self.state.metadata_mut().gasometer.record_cost(gas_limit) {
+				return (e.into(), Vec::new());
+			}
+			self.enter_substate(gas_limit, false);
+		}
+
+		let mut reason = ExitReason::Succeed(ExitSucceed::Stopped);
+		let mut results = Vec::with_capacity(calls.len());
+		for (address, value, input) in calls {
+			event!(TransactCall {
+				caller,
+				address,
+				value,
+				data: &input,
+				gas_limit: self.state.metadata().gasometer.gas(),
+			});
+
+			// set origin_code_address publish status will sync from it.
+			*self.state.metadata_mut().origin_code_address_mut() = Some(address);
+
+			let (exit_reason, output) = match self.record_external_operation(crate::ExternalOperation::AccountBasicRead)
+			{
+				Err(e) => emit_exit!(e.into(), Vec::new()),
+				Ok(()) => {
+					let context = Context {
+						caller,
+						address,
+						apparent_value: value,
+					};
+
+					match self.call_inner(
+						address,
+						Some(Transfer {
+							source: caller,
+							target: address,
+							value,
+						}),
+						input,
+						None,
+						false,
+						false,
+						false,
+						context,
+					) {
+						Capture::Exit((s, v)) => emit_exit!(s, v),
+						Capture::Trap(rt) => {
+							let mut cs = Vec::with_capacity(DEFAULT_CALL_STACK_CAPACITY);
+							cs.push(rt.0);
+							let (s, _, v) = self.execute_with_call_stack(&mut cs);
+							emit_exit!(s, v)
+						}
+					}
+				}
+			};
+
+			let failed = !exit_reason.is_succeed();
+			if failed && reason.is_succeed() {
+				reason = exit_reason.clone();
+			}
+			results.push((exit_reason, output));
+			if failed && atomic {
+				break;
+			}
+		}
+
+		if atomic {
+			let kind = if reason.is_succeed() {
+				StackExitKind::Succeeded
+			} else {
+				StackExitKind::Reverted
+			};
+			if let Err(e) = self.exit_substate(kind) {
+				return (e.into(), results);
+			}
+		}
+
+		(reason, results)
+	}
+
 	/// Get used gas for the current executor, given the price.
 	pub fn used_gas(&self) -> u64 {
 		self.state.metadata().gasometer.total_used_gas()
@@ -798,14 +947,7 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 				caller,
 				code_hash,
 				salt,
-			} => {
-				let mut hasher = Keccak256::new();
-				hasher.update([0xff]);
-				hasher.update(&caller[..]);
-				hasher.update(&salt[..]);
-				hasher.update(&code_hash[..]);
-				H256::from_slice(hasher.finalize().as_slice()).into()
-			}
+			} => create2_address(caller, salt, code_hash),
 			CreateScheme::Legacy { caller } => {
 				let nonce = self.nonce(caller);
 				let mut stream = rlp::RlpStream::new_list(2);
@@ -1209,6 +1351,39 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 			}
 		}
 	}
+
+	fn record_feature_opcode_cost(
+		&mut self,
+		context: &Context,
+		opcode: Opcode,
+		stack: &Stack,
+	) -> Result<(), ExitError> {
+		match opcode {
+			TLOAD | TSTORE => {
+				if opcode == TSTORE && self.state.metadata().is_static() {
+					return Err(ExitError::InvalidCode(opcode));
+				}
+				// `other` has no access to the context, save the address for it
+				self.transient_address = Some(context.address);
+				self.state.metadata_mut().gasometer.record_cost(GAS_TRANSIENT_STORAGE)
+			}
+			MCOPY => {
+				let dst = U256::from_big_endian(&stack.peek(0)?[..]);
+				let src = U256::from_big_endian(&stack.peek(1)?[..]);
+				let len = U256::from_big_endian(&stack.peek(2)?[..]);
+				// same as `CALLDATACOPY`, charge for the copied words and the expanded memory
+				self.state.metadata_mut().gasometer.record_dynamic_cost(
+					gasometer::GasCost::VeryLowCopy { len },
+					Some(gasometer::MemoryCost {
+						offset: dst.max(src),
+						len,
+					}),
+				)
+			}
+			BLOBBASEFEE => self.state.metadata_mut().gasometer.record_cost(GAS_BASE),
+			_ => Err(ExitError::InvalidCode(opcode)),
+		}
+	}
 }
 
 pub struct StackExecutorCallInterrupt<'borrow>(TaggedRuntime<'borrow>);
@@ -1483,6 +1658,10 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> Handler
 	fn pre_validate(&mut self, context: &Context, opcode: Opcode, stack: &Stack) -> Result<(), ExitError> {
 		// log::trace!(target: "evm", "Running opcode: {:?}, Pre gas-left: {:?}", opcode, gasometer.gas());
 
+		if has_feature_opcode(&self.state.features(), opcode) {
+			return self.record_feature_opcode_cost(context, opcode, stack);
+		}
+
 		if let Some(cost) = gasometer::static_opcode_cost(opcode) {
 			self.state.metadata_mut().gasometer.record_cost(cost)?;
 		} else {
@@ -1510,6 +1689,51 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> Handler
 	fn record_external_operation(&mut self, op: crate::ExternalOperation) -> Result<(), ExitError> {
 		self.state.record_external_operation(op)
 	}
+
+	fn other(&mut self, opcode: Opcode, machine: &mut Machine) -> Result<(), ExitError> {
+		if !has_feature_opcode(&self.state.features(), opcode) {
+			return Err(ExitError::InvalidCode(opcode));
+		}
+
+		match opcode {
+			TLOAD => {
+				let address = self.transient_address.take().ok_or(ExitError::InvalidCode(opcode))?;
+				let index = machine.stack_mut().pop()?;
+				let value = self.state.transient_storage(address, index);
+				machine.stack_mut().push(value)
+			}
+			TSTORE => {
+				let address = self.transient_address.take().ok_or(ExitError::InvalidCode(opcode))?;
+				let index = machine.stack_mut().pop()?;
+				let value = machine.stack_mut().pop()?;
+				self.state.set_transient_storage(address, index, value);
+				Ok(())
+			}
+			MCOPY => {
+				let dst = U256::from_big_endian(&machine.stack_mut().pop()?[..]);
+				let src = U256::from_big_endian(&machine.stack_mut().pop()?[..]);
+				let len = U256::from_big_endian(&machine.stack_mut().pop()?[..]);
+				if len.is_zero() {
+					return Ok(());
+				}
+
+				// the memory cost has been recorded in `pre_validate`, so the range fits in usize
+				machine.memory_mut().resize_offset(dst.max(src), len)?;
+				let len = len.as_usize();
+				let data = machine.memory().get(src.as_usize(), len);
+				machine
+					.memory_mut()
+					.set(dst.as_usize(), &data, Some(len))
+					.map_err(|_| ExitError::InvalidRange)
+			}
+			BLOBBASEFEE => {
+				let mut value = H256::default();
+				BLOB_BASE_FEE.to_big_endian(&mut value[..]);
+				machine.stack_mut().push(value)
+			}
+			_ => Err(ExitError::InvalidCode(opcode)),
+		}
+	}
 }
 
 pub struct StackExecutorHandle<'inner, 'config, 'precompiles, S, P> {
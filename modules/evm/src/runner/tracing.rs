@@ -16,14 +16,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{AccountStorages, Config, Pallet};
 use module_evm_utility::{
 	evm::{Context, ExitError, ExitFatal, ExitReason, ExitSucceed, Opcode, Transfer},
 	evm_gasometer, evm_runtime,
 };
+use parity_scale_codec::Encode;
 use sp_core::{H160, H256, U256};
-use sp_std::prelude::*;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
-pub use primitives::evm::tracing::{CallTrace, CallType, LogTrace, OpcodeConfig, Step, TraceOutcome, TracerConfig};
+pub use primitives::evm::tracing::{
+	CallTrace, CallType, FourByteCall, FourByteGas, FourByteTrace, LogTrace, OpcodeConfig, PrestateAccount,
+	PrestateConfig, PrestateTrace, Step, TraceOutcome, TracerConfig,
+};
 
 #[derive(Debug, Copy, Clone)]
 pub enum Event<'a> {
@@ -100,6 +105,13 @@ pub struct Tracer {
 	step_counter: u32,
 	gas: u64,
 	current_opcode: Option<Opcode>,
+	prestate: BTreeMap<H160, PrestateAccount>,
+	poststate: BTreeMap<H160, PrestateAccount>,
+	// (selector, calldata size) -> count
+	four_byte_calls: BTreeMap<([u8; 4], u32), u32>,
+	four_byte_gas: BTreeMap<[u8; 4], u64>,
+	// selector and gas limit of the calls in progress
+	four_byte_stack: Vec<(Option<[u8; 4]>, u64)>,
 }
 
 impl Tracer {
@@ -113,6 +125,11 @@ impl Tracer {
 			step_counter: 0,
 			gas: 0,
 			current_opcode: None,
+			prestate: BTreeMap::new(),
+			poststate: BTreeMap::new(),
+			four_byte_calls: BTreeMap::new(),
+			four_byte_gas: BTreeMap::new(),
+			four_byte_stack: Vec::new(),
 		}
 	}
 
@@ -143,6 +160,107 @@ impl Tracer {
 		matches!(self.config, TracerConfig::CallTracer)
 	}
 
+	#[inline]
+	fn trace_four_byte(&self) -> bool {
+		matches!(self.config, TracerConfig::FourByteTracer)
+	}
+
+	#[inline]
+	fn trace_prestate(&self) -> bool {
+		matches!(self.config, TracerConfig::PrestateTracer(_))
+	}
+
+	#[inline]
+	fn trace_diff(&self) -> bool {
+		matches!(
+			self.config,
+			TracerConfig::PrestateTracer(PrestateConfig { diff_mode: true })
+		)
+	}
+
+	fn account_state<T: Config>(address: &H160) -> PrestateAccount {
+		let account = Pallet::<T>::account_basic(address);
+		let code = Pallet::<T>::code_at_address(address).into_inner();
+		PrestateAccount {
+			balance: Some(account.balance),
+			nonce: Some(account.nonce),
+			code: if code.is_empty() { None } else { Some(code) },
+			storage: BTreeMap::new(),
+		}
+	}
+
+	/// Record the state of the account the first time it is touched.
+	pub(crate) fn record_account<T: Config>(&mut self, address: H160) {
+		if !self.trace_prestate() || self.prestate.contains_key(&address) {
+			return;
+		}
+		self.prestate.insert(address, Self::account_state::<T>(&address));
+	}
+
+	/// Record the value of the storage slot the first time it is touched.
+	pub(crate) fn record_storage<T: Config>(&mut self, address: H160, index: H256) {
+		if !self.trace_prestate() {
+			return;
+		}
+		self.record_account::<T>(address);
+		if let Some(account) = self.prestate.get_mut(&address) {
+			account
+				.storage
+				.entry(index)
+				.or_insert_with(|| AccountStorages::<T>::get(address, index));
+		}
+	}
+
+	/// Record the current state of all the touched accounts, used by diff mode.
+	pub(crate) fn record_poststate<T: Config>(&mut self) {
+		if !self.trace_diff() {
+			return;
+		}
+		for (address, pre) in self.prestate.iter() {
+			let mut post = Self::account_state::<T>(address);
+			post.storage = pre
+				.storage
+				.keys()
+				.map(|index| (*index, AccountStorages::<T>::get(address, index)))
+				.collect();
+			self.poststate.insert(*address, post);
+		}
+	}
+
+	fn prestate_diff(&mut self) -> PrestateTrace {
+		let mut pre = BTreeMap::new();
+		let mut post = BTreeMap::new();
+		for (address, mut before) in sp_std::mem::take(&mut self.prestate) {
+			let mut after = match self.poststate.remove(&address) {
+				Some(after) => after,
+				None => continue,
+			};
+			// only keep modified slots
+			before
+				.storage
+				.retain(|index, value| after.storage.get(index) != Some(value));
+			after.storage.retain(|index, _| before.storage.contains_key(index));
+
+			// post state only contains the modified fields
+			if after.balance == before.balance {
+				after.balance = None;
+			}
+			if after.nonce == before.nonce {
+				after.nonce = None;
+			}
+			if after.code == before.code {
+				after.code = None;
+			}
+
+			if after != PrestateAccount::default() {
+				pre.insert(address, before);
+				post.insert(address, after);
+			}
+		}
+		self.poststate.clear();
+		PrestateTrace::Diff { pre, post }
+	}
+
 	// increment step counter and check if we should record this step
 	#[inline]
 	fn count_step(&mut self) -> bool {
@@ -162,6 +280,29 @@ impl Tracer {
 				TraceOutcome::Calls(self.calls.drain(..).collect())
 			}
 			TracerConfig::OpcodeTracer(_) => TraceOutcome::Steps(self.steps.drain(..).collect()),
+			TracerConfig::PrestateTracer(PrestateConfig { diff_mode: false }) => {
+				TraceOutcome::Prestate(PrestateTrace::Prestate(sp_std::mem::take(&mut self.prestate)))
+			}
+			TracerConfig::PrestateTracer(PrestateConfig { diff_mode: true }) => {
+				TraceOutcome::Prestate(self.prestate_diff())
+			}
+			TracerConfig::FourByteTracer => {
+				assert!(self.four_byte_stack.is_empty(), "Call stack is not empty");
+				TraceOutcome::FourByte(FourByteTrace {
+					calls: sp_std::mem::take(&mut self.four_byte_calls)
+						.into_iter()
+						.map(|((selector, calldata_size), count)| FourByteCall {
+							selector,
+							calldata_size,
+							count,
+						})
+						.collect(),
+					gas: sp_std::mem::take(&mut self.four_byte_gas)
+						.into_iter()
+						.map(|(selector, gas_used)| FourByteGas { selector, gas_used })
+						.collect(),
+				})
+			}
 		}
 	}
 
@@ -403,6 +544,49 @@ impl Tracer {
 			}
 		}
 	}
+
+	#[inline]
+	fn enter_selector(&mut self, input: &[u8], gas: u64) {
+		let selector = input.get(..4).map(|x| {
+			let mut selector = [0u8; 4];
+			selector.copy_from_slice(x);
+			selector
+		});
+		if let Some(selector) = selector {
+			let count = self
+				.four_byte_calls
+				.entry((selector, (input.len() - 4) as u32))
+				.or_default();
+			*count = count.saturating_add(1);
+		}
+		self.four_byte_stack.push((selector, gas));
+	}
+
+	#[inline]
+	fn four_byte_event(&mut self, event: Event) {
+		match event {
+			Event::Call { input, target_gas, .. } | Event::PrecompileSubcall { input, target_gas, .. } => {
+				self.enter_selector(input, target_gas.unwrap_or(self.gas));
+			}
+			Event::TransactCall { data, gas_limit, .. } => {
+				self.enter_selector(data, gas_limit);
+			}
+			Event::Create { target_gas, .. } => {
+				self.four_byte_stack.push((None, target_gas.unwrap_or(self.gas)));
+			}
+			Event::TransactCreate { gas_limit, .. } | Event::TransactCreate2 { gas_limit, .. } => {
+				self.four_byte_stack.push((None, gas_limit));
+			}
+			Event::Exit { .. } => {
+				let (selector, gas) = self.four_byte_stack.pop().expect("missing call trace");
+				if let Some(selector) = selector {
+					let gas_used = self.four_byte_gas.entry(selector).or_default();
+					*gas_used = gas_used.saturating_add(gas.saturating_sub(self.gas));
+				}
+			}
+			_ => {}
+		}
+	}
 }
 
 pub trait EventListener {
@@ -413,6 +597,8 @@ impl EventListener for Tracer {
 	fn event(&mut self, event: Event) {
 		if self.trace_call() {
 			self.call_event(event);
+		} else if self.trace_four_byte() {
+			self.four_byte_event(event);
 		} else {
 			match event {
 				Event::Exit { reason, .. } => {
@@ -463,6 +649,23 @@ pub(crate) fn with<F: FnOnce(&mut Tracer)>(f: F) {
 	tracer::with(f);
 }
 
+/// Attach the runtime events emitted since `from` to the current call trace.
+/// Used by precompiles to expose the effects of the calls they make into the runtime.
+pub fn record_substrate_events<T: frame_system::Config>(from: u32) {
+	tracer::with(|tracer| {
+		if !tracer.trace_call() {
+			return;
+		}
+		if let Some(trace) = tracer.stack.last_mut() {
+			trace.substrate_events.extend(
+				frame_system::Pallet::<T>::read_events_no_consensus()
+					.skip(from as usize)
+					.map(|record| record.event.encode()),
+			);
+		}
+	});
+}
+
 trait Stringify {
 	fn stringify(&self) -> &str;
 }
@@ -918,6 +918,242 @@ fn create_predeploy_contract_works() {
 	});
 }
 
+#[test]
+fn create_deterministic_predeploy_contract_works() {
+	// same as `create_predeploy_contract_works`
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let salt = H256::repeat_byte(0x11);
+		let init_code_hash = code_hash(&contract);
+		let addr = create2_address(NetworkContractSource::get(), salt, init_code_hash);
+
+		assert_noop!(
+			EVM::create_deterministic_predeploy_contract(
+				RuntimeOrigin::signed(AccountId32::from([1u8; 32])),
+				salt,
+				contract.clone(),
+				0,
+				1000000,
+				1000000,
+				vec![]
+			),
+			BadOrigin
+		);
+
+		assert_ok!(EVM::create_deterministic_predeploy_contract(
+			RuntimeOrigin::signed(NetworkContractAccount::get()),
+			salt,
+			contract.clone(),
+			0,
+			1000000,
+			1000000,
+			vec![],
+		));
+
+		assert!(EVM::is_contract(&addr));
+		assert_eq!(
+			EVM::deterministic_deployments(addr),
+			Some(DeploymentInfo {
+				deployer: NetworkContractSource::get(),
+				salt,
+				code_hash: init_code_hash,
+			})
+		);
+
+		assert_noop!(
+			EVM::create_deterministic_predeploy_contract(
+				RuntimeOrigin::signed(NetworkContractAccount::get()),
+				salt,
+				contract.clone(),
+				0,
+				1000000,
+				1000000,
+				vec![],
+			),
+			Error::<Runtime>::ContractAlreadyExisted
+		);
+
+		// failed deployments are not recorded
+		let salt = H256::repeat_byte(0x22);
+		let addr = create2_address(NetworkContractSource::get(), salt, code_hash(&[]));
+		assert_ok!(EVM::create_deterministic_predeploy_contract(
+			RuntimeOrigin::signed(NetworkContractAccount::get()),
+			salt,
+			vec![],
+			0,
+			1000000,
+			1000000,
+			vec![],
+		));
+		assert_eq!(EVM::deterministic_deployments(addr), None);
+	});
+}
+
+#[test]
+fn create2_records_deterministic_deployment() {
+	// same as `create_predeploy_contract_works`
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let salt = H256::repeat_byte(0x33);
+		let init_code_hash = code_hash(&contract);
+		let addr = create2_address(alice(), salt, init_code_hash);
+
+		assert_ok!(EVM::create2(
+			RuntimeOrigin::signed(MockAddressMapping::get_account_id(&alice())),
+			contract,
+			salt,
+			0,
+			1000000,
+			1000000,
+			vec![],
+		));
+
+		assert!(EVM::is_contract(&addr));
+		assert_eq!(
+			EVM::deterministic_deployments(addr),
+			Some(DeploymentInfo {
+				deployer: alice(),
+				salt,
+				code_hash: init_code_hash,
+			})
+		);
+	});
+}
+
+#[test]
+fn contract_storage_info_works() {
+	// same as `create_predeploy_contract_works`
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(result.used_storage, 284);
+		let contract_address = result.value;
+
+		let info = EVM::contract_storage_info(&contract_address);
+		assert_eq!(
+			info,
+			ContractStorageInfo {
+				used_storage: 284,
+				reserved_deposit: 284 * EVM::get_storage_deposit_per_byte(),
+			}
+		);
+		assert_eq!(info.used_storage, EVM::contract_storage_sizes(contract_address));
+		System::assert_has_event(RuntimeEvent::EVM(crate::Event::ContractStorageChanged {
+			contract: contract_address,
+			used_storage: 284,
+			reserved_deposit: 284 * EVM::get_storage_deposit_per_byte(),
+		}));
+	});
+}
+
+#[test]
+fn batch_call_works() {
+	// calldata size 0: revert
+	// otherwise: sstore(0, calldataload(0))
+	let contract = from_hex("0x6011600c60003960116000f33660095760006000fd5b60003560005500").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.value;
+
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let batch_results = || {
+			System::events()
+				.into_iter()
+				.rev()
+				.find_map(|record| match record.event {
+					RuntimeEvent::EVM(crate::Event::BatchExecuted { results, .. }) => Some(results),
+					_ => None,
+				})
+				.unwrap()
+		};
+
+		// continue with the rest calls
+		assert_ok!(EVM::batch_call(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			vec![
+				(contract_address, H256::from_low_u64_be(1).as_bytes().to_vec(), 0),
+				(contract_address, vec![], 0),
+				(contract_address, H256::from_low_u64_be(2).as_bytes().to_vec(), 0),
+			]
+			.try_into()
+			.unwrap(),
+			BatchCallMode::BestEffort,
+			1000000,
+			1000,
+			vec![],
+		));
+		assert_eq!(
+			batch_results(),
+			vec![
+				(contract_address, ExitReason::Succeed(ExitSucceed::Stopped), vec![]),
+				(contract_address, ExitReason::Revert(ExitRevert::Reverted), vec![]),
+				(contract_address, ExitReason::Succeed(ExitSucceed::Stopped), vec![]),
+			]
+		);
+		assert_eq!(
+			EVM::account_storages(contract_address, H256::zero()),
+			H256::from_low_u64_be(2)
+		);
+
+		// revert all the calls
+		assert_ok!(EVM::batch_call(
+			RuntimeOrigin::signed(alice_account_id),
+			vec![
+				(contract_address, H256::from_low_u64_be(3).as_bytes().to_vec(), 0),
+				(contract_address, vec![], 0),
+				(contract_address, H256::from_low_u64_be(4).as_bytes().to_vec(), 0),
+			]
+			.try_into()
+			.unwrap(),
+			BatchCallMode::Atomic,
+			1000000,
+			1000,
+			vec![],
+		));
+		assert_eq!(
+			batch_results(),
+			vec![
+				(contract_address, ExitReason::Succeed(ExitSucceed::Stopped), vec![]),
+				(contract_address, ExitReason::Revert(ExitRevert::Reverted), vec![]),
+			]
+		);
+		assert_eq!(
+			EVM::account_storages(contract_address, H256::zero()),
+			H256::from_low_u64_be(2)
+		);
+	});
+}
+
 #[test]
 fn should_transfer_maintainer() {
 	// pragma solidity ^0.5.0;
@@ -1469,6 +1705,11 @@ fn should_selfdestruct_without_schedule_task() {
 		assert_eq!(balance(alice()), alice_balance + amount + reserved_amount);
 		assert_eq!(balance(contract_address), 0);
 		assert_eq!(reserved_balance(contract_address), 0);
+		System::assert_has_event(RuntimeEvent::EVM(crate::Event::ContractStorageChanged {
+			contract: contract_address,
+			used_storage: 0,
+			reserved_deposit: 0,
+		}));
 
 		// can publish at the same address
 		assert_ok!(EVM::create_predeploy_contract(
@@ -3007,6 +3248,101 @@ fn should_not_allow_system_contracts_send_tx() {
 	});
 }
 
+#[test]
+fn transient_storage_works() {
+	// calldata size 0: return tload(0)
+	// calldata size 1: tstore(0, 42), return tload(0)
+	// calldata size 2: tstore(0, 1), call self with calldata size 3, return tload(0)
+	// calldata size 3: tstore(0, 42), revert
+	let contract = from_hex(
+		"0x6049600c60003960496000f3368015603d5780600114601d5780600214602657602a60005d600080fd5b602a60005d603d565b600160005d60006000600360006000305af150603d565b60005c60005260206000f3",
+	)
+	.unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+
+		let contract_address = result.value;
+		let call = |input: Vec<u8>| {
+			let result = <Runtime as Config>::Runner::call(
+				alice(),
+				alice(),
+				contract_address,
+				input,
+				0,
+				1000000,
+				1000000,
+				vec![],
+				<Runtime as Config>::config(),
+			)
+			.unwrap();
+			assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			H256::from_slice(&result.value)
+		};
+
+		// available in the same transaction
+		assert_eq!(call(vec![0]), H256::from_low_u64_be(42));
+		// discarded once the transaction completes
+		assert_eq!(call(vec![]), H256::zero());
+		// reverted with the sub-call
+		assert_eq!(call(vec![0, 0]), H256::from_low_u64_be(1));
+		assert_eq!(call(vec![]), H256::zero());
+	});
+}
+
+#[test]
+fn cancun_opcodes_works() {
+	// calldata size 0: mstore(0, 42), mcopy(32, 0, 32), return mload(32)
+	// calldata size 1: return blobbasefee()
+	let contract =
+		from_hex("0x601f600c600039601f6000f336601557602a6000526020600060205e60206020f35b4a60005260206000f3").unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+
+		let contract_address = result.value;
+		let call = |input: Vec<u8>| {
+			let result = <Runtime as Config>::Runner::call(
+				alice(),
+				alice(),
+				contract_address,
+				input,
+				0,
+				1000000,
+				1000000,
+				vec![],
+				<Runtime as Config>::config(),
+			)
+			.unwrap();
+			assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			H256::from_slice(&result.value)
+		};
+
+		assert_eq!(call(vec![]), H256::from_low_u64_be(42));
+		assert_eq!(call(vec![0]), H256::from_low_u64_be(1));
+	});
+}
+
 #[cfg(feature = "tracing")]
 #[test]
 fn tracer_works() {
@@ -3052,7 +3388,9 @@ fn tracer_works() {
 		"0x608060405234801561001057600080fd5b5060405161001d9061007e565b604051809103906000f080158015610039573d6000803e3d6000fd5b506000806101000a81548173ffffffffffffffffffffffffffffffffffffffff021916908373ffffffffffffffffffffffffffffffffffffffff16021790555061008b565b610147806105be83390190565b6105248061009a6000396000f3fe608060405234801561001057600080fd5b506004361061004c5760003560e01c80630be6fe5d146100515780631358f5251461006d57806386b714e214610089578063da1385d5146100a7575b600080fd5b61006b60048036038101906100669190610298565b6100c3565b005b610087600480360381019061008291906102d8565b6100d9565b005b610091610189565b60405161009e9190610384565b60405180910390f35b6100c160048036038101906100bc91906102d8565b6101ad565b005b6100cc826101ad565b6100d5816100d9565b5050565b60005b818110156101855760008054906101000a900473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16631d834a1b8260006040518363ffffffff1660e01b81526004016101409291906103e9565b600060405180830381600087803b15801561015a57600080fd5b505af115801561016e573d6000803e3d6000fd5b50505050808061017d90610441565b9150506100dc565b5050565b60008054906101000a900473ffffffffffffffffffffffffffffffffffffffff1681565b60005b818110156102595760008054906101000a900473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16631d834a1b8260016040518363ffffffff1660e01b81526004016102149291906104c5565b600060405180830381600087803b15801561022e57600080fd5b505af1158015610242573d6000803e3d6000fd5b50505050808061025190610441565b9150506101b0565b5050565b600080fd5b6000819050919050565b61027581610262565b811461028057600080fd5b50565b6000813590506102928161026c565b92915050565b600080604083850312156102af576102ae61025d565b5b60006102bd85828601610283565b92505060206102ce85828601610283565b9150509250929050565b6000602082840312156102ee576102ed61025d565b5b60006102fc84828501610283565b91505092915050565b600073ffffffffffffffffffffffffffffffffffffffff82169050919050565b6000819050919050565b600061034a61034561034084610305565b610325565b610305565b9050919050565b600061035c8261032f565b9050919050565b600061036e82610351565b9050919050565b61037e81610363565b82525050565b60006020820190506103996000830184610375565b92915050565b6103a881610262565b82525050565b6000819050919050565b60006103d36103ce6103c9846103ae565b610325565b610262565b9050919050565b6103e3816103b8565b82525050565b60006040820190506103fe600083018561039f565b61040b60208301846103da565b9392505050565b7f4e487b7100000000000000000000000000000000000000000000000000000000600052601160045260246000fd5b600061044c82610262565b91507fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff82141561047f5761047e610412565b5b600182019050919050565b6000819050919050565b60006104af6104aa6104a58461048a565b610325565b610262565b9050919050565b6104bf81610494565b82525050565b60006040820190506104da600083018561039f565b6104e760208301846104b6565b939250505056fea2646970667358221220c53549ea0c54d760bc0fd8aa7f8eeebf806e4474546e87e9783e4ad3f55dfa6564736f6c63430008090033608060405234801561001057600080fd5b50610127806100206000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c80631d834a1b14602d575b600080fd5b60436004803603810190603f919060b8565b6045565b005b600081146067578060008084815260200190815260200160002081905550607e565b600080838152602001908152602001600020600090555b5050565b600080fd5b6000819050919050565b6098816087565b811460a257600080fd5b50565b60008135905060b2816091565b92915050565b6000806040838503121560cc5760cb6082565b5b600060d88582860160a5565b925050602060e78582860160a5565b915050925092905056fea2646970667358221220941edb58b322ea8088f4f9091a8a48c92e59c2f39db303d8e126a0c3dd434dde64736f6c63430008090033"
 	).unwrap();
 
-	use primitives::evm::tracing::{OpcodeConfig, TraceOutcome, TracerConfig};
+	use primitives::evm::tracing::{
+		FourByteCall, OpcodeConfig, PrestateConfig, PrestateTrace, TraceOutcome, TracerConfig,
+	};
 
 	new_test_ext().execute_with(|| {
 		let mut tracer = crate::runner::tracing::Tracer::new(TracerConfig::CallTracer);
@@ -3249,5 +3587,127 @@ fn tracer_works() {
 			}
 			_ => panic!("unexpected trace outcome"),
 		}
+
+		let storage_address = H160::from(hex!("7b8f8ca099f6e33cf1817cf67d0556429cfc54e4"));
+		let nonce = EVM::account_basic(&alice()).nonce;
+
+		let mut tracer = crate::runner::tracing::Tracer::new(TracerConfig::PrestateTracer(PrestateConfig {
+			diff_mode: false,
+		}));
+		crate::runner::tracing::using(&mut tracer, || {
+			assert_ok!(EVM::call(
+				RuntimeOrigin::signed(alice_account_id.clone()),
+				contract_address,
+				hex! {"
+					da1385d5
+					0000000000000000000000000000000000000000000000000000000000000002
+				"}
+				.to_vec(),
+				0,
+				1000000,
+				1000,
+				vec![],
+			));
+		});
+
+		match tracer.finalize() {
+			TraceOutcome::Prestate(PrestateTrace::Prestate(accounts)) => {
+				assert_eq!(accounts.len(), 3);
+				assert_eq!(accounts[&alice()].nonce, Some(nonce));
+				assert_eq!(accounts[&alice()].code, None);
+				assert!(accounts[&contract_address].code.is_some());
+				assert_eq!(
+					accounts[&contract_address].storage.values().collect::<Vec<_>>(),
+					vec![&H256::from(storage_address)]
+				);
+				// slot of index 0 is already set, index 1 is new
+				let mut values = accounts[&storage_address].storage.values().copied().collect::<Vec<_>>();
+				values.sort();
+				assert_eq!(values, vec![H256::zero(), H256::from_low_u64_be(1)]);
+			}
+			_ => panic!("unexpected trace outcome"),
+		}
+
+		let mut tracer = crate::runner::tracing::Tracer::new(TracerConfig::PrestateTracer(PrestateConfig {
+			diff_mode: true,
+		}));
+		crate::runner::tracing::using(&mut tracer, || {
+			assert_ok!(EVM::call(
+				RuntimeOrigin::signed(alice_account_id.clone()),
+				contract_address,
+				hex! {"
+					da1385d5
+					0000000000000000000000000000000000000000000000000000000000000003
+				"}
+				.to_vec(),
+				0,
+				1000000,
+				1000,
+				vec![],
+			));
+		});
+
+		match tracer.finalize() {
+			TraceOutcome::Prestate(PrestateTrace::Diff { pre, post }) => {
+				assert_eq!(pre[&alice()].nonce, Some(nonce + 1));
+				assert_eq!(post[&alice()].nonce, Some(nonce + 2));
+				assert_eq!(post[&alice()].code, None);
+				// only the slot of index 2 is modified
+				assert_eq!(
+					pre[&storage_address].storage.values().collect::<Vec<_>>(),
+					vec![&H256::zero()]
+				);
+				assert_eq!(
+					post[&storage_address].storage.values().collect::<Vec<_>>(),
+					vec![&H256::from_low_u64_be(1)]
+				);
+				assert_eq!(post[&storage_address].nonce, None);
+				assert_eq!(post[&storage_address].code, None);
+			}
+			_ => panic!("unexpected trace outcome"),
+		}
+
+		let mut tracer = crate::runner::tracing::Tracer::new(TracerConfig::FourByteTracer);
+		crate::runner::tracing::using(&mut tracer, || {
+			assert_ok!(EVM::call(
+				RuntimeOrigin::signed(alice_account_id.clone()),
+				contract_address,
+				hex! {"
+					da1385d5
+					0000000000000000000000000000000000000000000000000000000000000002
+				"}
+				.to_vec(),
+				0,
+				1000000,
+				1000,
+				vec![],
+			));
+		});
+
+		match tracer.finalize() {
+			TraceOutcome::FourByte(trace) => {
+				assert_eq!(
+					trace.calls,
+					vec![
+						FourByteCall {
+							selector: hex!("1d834a1b"),
+							calldata_size: 64,
+							count: 2,
+						},
+						FourByteCall {
+							selector: hex!("da1385d5"),
+							calldata_size: 32,
+							count: 1,
+						},
+					]
+				);
+				assert_eq!(trace.gas.len(), 2);
+				assert_eq!(trace.gas[0].selector, hex!("1d834a1b"));
+				assert_eq!(trace.gas[1].selector, hex!("da1385d5"));
+				// gas used by the outer call includes the sub-calls
+				assert!(trace.gas[1].gas_used > trace.gas[0].gas_used);
+			}
+			_ => panic!("unexpected trace outcome"),
+		}
 	})
 }
@@ -51,7 +51,9 @@ pub trait WeightInfo {
 	fn create2() -> Weight;
 	fn create_nft_contract() -> Weight;
 	fn create_predeploy_contract() -> Weight;
+	fn create_deterministic_predeploy_contract() -> Weight;
 	fn call() -> Weight;
+	fn batch_call(c: u32, b: u32, ) -> Weight;
 	fn transfer_maintainer() -> Weight;
 	fn publish_contract() -> Weight;
 	fn publish_free() -> Weight;
@@ -150,6 +152,28 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(9))
 	}
+	// Storage: EVM Accounts (r:2 w:2)
+	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: EvmAccounts Accounts (r:2 w:0)
+	// Proof: EvmAccounts Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: EVM CodeInfos (r:2 w:1)
+	// Proof Skipped: EVM CodeInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM ContractStorageSizes (r:1 w:1)
+	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM Codes (r:0 w:1)
+	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM DeterministicDeployments (r:0 w:1)
+	// Proof: EVM DeterministicDeployments (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn create_deterministic_predeploy_contract() -> Weight {
+		// Estimated from `create_predeploy_contract` plus the `DeterministicDeployments` write, not benchmarked yet.
+		Weight::from_parts(233_183_000, 0)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
 	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
 	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	// Storage: EVM Accounts (r:2 w:1)
@@ -170,6 +194,35 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
+	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: EVM Accounts (r:2 w:1)
+	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: EvmAccounts Accounts (r:2 w:0)
+	// Proof: EvmAccounts Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: EVM Codes (r:1 w:0)
+	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM ContractStorageSizes (r:1 w:1)
+	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM Accounts (r:1 w:0) per call
+	// Storage: EVM Codes (r:1 w:0) per call
+	// Storage: EVM ContractStorageSizes (r:1 w:1) per call
+	/// The range of component `c` is `[1, 32]`.
+	/// The range of component `b` is `[0, 65536]`.
+	fn batch_call(c: u32, b: u32, ) -> Weight {
+		// Estimated from `call` plus the storage accessed by each call and the input copied, not benchmarked yet.
+		Weight::from_parts(189_885_000, 0)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
 	// Storage: EVM Accounts (r:1 w:1)
 	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
 	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
@@ -357,6 +410,28 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11))
 			.saturating_add(RocksDbWeight::get().writes(9))
 	}
+	// Storage: EVM Accounts (r:2 w:2)
+	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: EvmAccounts Accounts (r:2 w:0)
+	// Proof: EvmAccounts Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: EVM CodeInfos (r:2 w:1)
+	// Proof Skipped: EVM CodeInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM ContractStorageSizes (r:1 w:1)
+	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM Codes (r:0 w:1)
+	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM DeterministicDeployments (r:0 w:1)
+	// Proof: EVM DeterministicDeployments (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn create_deterministic_predeploy_contract() -> Weight {
+		// Estimated from `create_predeploy_contract` plus the `DeterministicDeployments` write, not benchmarked yet.
+		Weight::from_parts(233_183_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
 	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
 	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	// Storage: EVM Accounts (r:2 w:1)
@@ -377,6 +452,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11))
 			.saturating_add(RocksDbWeight::get().writes(6))
 	}
+	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
+	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: EVM Accounts (r:2 w:1)
+	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: EvmAccounts Accounts (r:2 w:0)
+	// Proof: EvmAccounts Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: EVM Codes (r:1 w:0)
+	// Proof Skipped: EVM Codes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM ContractStorageSizes (r:1 w:1)
+	// Proof Skipped: EVM ContractStorageSizes (max_values: None, max_size: None, mode: Measured)
+	// Storage: EVM Accounts (r:1 w:0) per call
+	// Storage: EVM Codes (r:1 w:0) per call
+	// Storage: EVM ContractStorageSizes (r:1 w:1) per call
+	/// The range of component `c` is `[1, 32]`.
+	/// The range of component `b` is `[0, 65536]`.
+	fn batch_call(c: u32, b: u32, ) -> Weight {
+		// Estimated from `call` plus the storage accessed by each call and the input copied, not benchmarked yet.
+		Weight::from_parts(189_885_000, 0)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
 	// Storage: EVM Accounts (r:1 w:1)
 	// Proof Skipped: EVM Accounts (max_values: None, max_size: None, mode: Measured)
 	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
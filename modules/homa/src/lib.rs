@@ -463,37 +463,7 @@ pub mod module {
 		#[pallet::weight(< T as Config >::WeightInfo::claim_redemption())]
 		pub fn claim_redemption(origin: OriginFor<T>, redeemer: T::AccountId) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
-
-			let mut available_staking: Balance = Zero::zero();
-			let current_era = Self::relay_chain_current_era();
-			for (expired_era_index, unbonded) in Unbondings::<T>::iter_prefix(&redeemer) {
-				if expired_era_index <= current_era {
-					available_staking = available_staking.saturating_add(unbonded);
-					Unbondings::<T>::remove(&redeemer, expired_era_index);
-				}
-			}
-
-			if !available_staking.is_zero() {
-				UnclaimedRedemption::<T>::try_mutate(|total| -> DispatchResult {
-					*total = total
-						.checked_sub(available_staking)
-						.ok_or(Error::<T>::InsufficientUnclaimedRedemption)?;
-					Ok(())
-				})?;
-				T::Currency::transfer(
-					T::StakingCurrencyId::get(),
-					&Self::account_id(),
-					&redeemer,
-					available_staking,
-				)?;
-
-				Self::deposit_event(Event::<T>::WithdrawRedemption {
-					redeemer,
-					redemption_amount: available_staking,
-				});
-			}
-
-			Ok(())
+			Self::do_claim_redemption(redeemer)
 		}
 
 		/// Sets the params of Homa.
@@ -820,6 +790,39 @@ pub mod module {
 			})
 		}
 
+		pub(super) fn do_claim_redemption(redeemer: T::AccountId) -> DispatchResult {
+			let mut available_staking: Balance = Zero::zero();
+			let current_era = Self::relay_chain_current_era();
+			for (expired_era_index, unbonded) in Unbondings::<T>::iter_prefix(&redeemer) {
+				if expired_era_index <= current_era {
+					available_staking = available_staking.saturating_add(unbonded);
+					Unbondings::<T>::remove(&redeemer, expired_era_index);
+				}
+			}
+
+			if !available_staking.is_zero() {
+				UnclaimedRedemption::<T>::try_mutate(|total| -> DispatchResult {
+					*total = total
+						.checked_sub(available_staking)
+						.ok_or(Error::<T>::InsufficientUnclaimedRedemption)?;
+					Ok(())
+				})?;
+				T::Currency::transfer(
+					T::StakingCurrencyId::get(),
+					&Self::account_id(),
+					&redeemer,
+					available_staking,
+				)?;
+
+				Self::deposit_event(Event::<T>::WithdrawRedemption {
+					redeemer,
+					redemption_amount: available_staking,
+				});
+			}
+
+			Ok(())
+		}
+
 		/// Get the soft cap of total staking currency of Homa.
 		/// Soft cap = ActiveSubAccountsIndexList.len() * SoftBondedCapPerSubAccount
 		pub fn get_staking_currency_soft_cap() -> Balance {
@@ -1254,6 +1257,31 @@ impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
 	fn get_fast_match_fee() -> Rate {
 		FastMatchFeeRate::<T>::get().into_inner()
 	}
+
+	fn claim_redemption(redeemer: T::AccountId) -> DispatchResult {
+		Self::do_claim_redemption(redeemer)
+	}
+
+	fn fast_match_redeems(redeemer_list: Vec<T::AccountId>, allow_partially: bool) -> DispatchResult {
+		for redeemer in redeemer_list {
+			Self::do_fast_match_redeem(&redeemer, allow_partially)?;
+		}
+		Ok(())
+	}
+
+	fn get_redeem_request(who: &T::AccountId) -> Option<(Balance, bool)> {
+		RedeemRequests::<T>::get(who)
+	}
+
+	fn get_unbondings(who: &T::AccountId) -> Vec<(EraIndex, Balance)> {
+		let mut unbondings: Vec<(EraIndex, Balance)> = Unbondings::<T>::iter_prefix(who).collect();
+		unbondings.sort_by_key(|(era_index, _)| *era_index);
+		unbondings
+	}
+
+	fn get_relay_chain_current_era() -> EraIndex {
+		Self::relay_chain_current_era()
+	}
 }
 
 /// Helpers for distribute increment/decrement to as possible to keep the list balanced after
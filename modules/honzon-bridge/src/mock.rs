@@ -122,6 +122,8 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type DepositCurrency = Balances;
+	type DepositPerAllowance = ConstU128<1>;
 }
 
 parameter_types! {
@@ -208,24 +208,7 @@ pub mod module {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(to)?;
-			if from == to {
-				return Ok(());
-			}
-
-			Authorization::<T>::try_mutate_exists(&from, (currency_id, &to), |maybe_reserved| -> DispatchResult {
-				ensure!(maybe_reserved.is_none(), Error::<T>::AlreadyAuthorized);
-
-				let reserve_amount = T::DepositPerAuthorization::get();
-				<T as Config>::Currency::reserve_named(&RESERVE_ID, &from, reserve_amount)?;
-				*maybe_reserved = Some(reserve_amount);
-				Self::deposit_event(Event::Authorization {
-					authorizer: from.clone(),
-					authorizee: to.clone(),
-					collateral_type: currency_id,
-				});
-				Ok(())
-			})?;
-			Ok(())
+			Self::do_authorize(&from, currency_id, &to)
 		}
 
 		/// Cancel the authorization for `to` under `currency_id`
@@ -241,15 +224,7 @@ pub mod module {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(to)?;
-			let reserved =
-				Authorization::<T>::take(&from, (currency_id, &to)).ok_or(Error::<T>::AuthorizationNotExists)?;
-			<T as Config>::Currency::unreserve_named(&RESERVE_ID, &from, reserved);
-			Self::deposit_event(Event::UnAuthorization {
-				authorizer: from,
-				authorizee: to,
-				collateral_type: currency_id,
-			});
-			Ok(())
+			Self::do_unauthorize(&from, currency_id, &to)
 		}
 
 		/// Cancel all authorization of caller
@@ -277,13 +252,7 @@ pub mod module {
 			min_increase_collateral: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			<module_cdp_engine::Pallet<T>>::expand_position_collateral(
-				&who,
-				currency_id,
-				increase_debit_value,
-				min_increase_collateral,
-			)?;
-			Ok(())
+			Self::do_expand_position_collateral(&who, currency_id, increase_debit_value, min_increase_collateral)
 		}
 
 		/// Sell the collateral locked in CDP to get stable coin to repay the debit.
@@ -300,13 +269,7 @@ pub mod module {
 			min_decrease_debit_value: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			<module_cdp_engine::Pallet<T>>::shrink_position_debit(
-				&who,
-				currency_id,
-				decrease_collateral,
-				min_decrease_debit_value,
-			)?;
-			Ok(())
+			Self::do_shrink_position_debit(&who, currency_id, decrease_collateral, min_decrease_debit_value)
 		}
 
 		/// Adjust the loans of `currency_id` by specific
@@ -326,18 +289,7 @@ pub mod module {
 			debit_value_adjustment: Amount,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			// not allowed to adjust the debit after system shutdown
-			if !debit_value_adjustment.is_zero() {
-				ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
-			}
-			<module_cdp_engine::Pallet<T>>::adjust_position_by_debit_value(
-				&who,
-				currency_id,
-				collateral_adjustment,
-				debit_value_adjustment,
-			)?;
-			Ok(())
+			Self::do_adjust_loan_by_debit_value(&who, currency_id, collateral_adjustment, debit_value_adjustment)
 		}
 
 		/// Transfers debit between two CDPs
@@ -354,22 +306,7 @@ pub mod module {
 			debit_transfer: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let debit_amount: Amount = debit_transfer.try_into().map_err(|_| ArithmeticError::Overflow)?;
-			let negative_debit = debit_amount.checked_neg().ok_or(ArithmeticError::Overflow)?;
-			// Adds ausd to user account momentarily to adjust loan
-			<T as module_cdp_engine::Config>::CDPTreasury::issue_debit(&who, debit_transfer, true)?;
-
-			<module_cdp_engine::Pallet<T>>::adjust_position(&who, from_currency, Zero::zero(), negative_debit)?;
-			<module_cdp_engine::Pallet<T>>::adjust_position(&who, to_currency, Zero::zero(), debit_amount)?;
-			// Removes debit issued for debit transfer
-			<T as module_cdp_engine::Config>::CDPTreasury::burn_debit(&who, debit_transfer)?;
-
-			Self::deposit_event(Event::TransferDebit {
-				from_currency,
-				to_currency,
-				amount: debit_transfer,
-			});
-			Ok(())
+			Self::do_transfer_debit(&who, from_currency, to_currency, debit_transfer)
 		}
 	}
 }
@@ -407,6 +344,107 @@ impl<T: Config> Pallet<T> {
 		<module_cdp_engine::Pallet<T>>::close_cdp_has_debit_by_dex(who, currency_id, max_collateral_amount)?;
 		Ok(())
 	}
+
+	fn do_authorize(from: &T::AccountId, currency_id: CurrencyId, to: &T::AccountId) -> DispatchResult {
+		if from == to {
+			return Ok(());
+		}
+
+		Authorization::<T>::try_mutate_exists(from, (currency_id, to), |maybe_reserved| -> DispatchResult {
+			ensure!(maybe_reserved.is_none(), Error::<T>::AlreadyAuthorized);
+
+			let reserve_amount = T::DepositPerAuthorization::get();
+			<T as Config>::Currency::reserve_named(&RESERVE_ID, from, reserve_amount)?;
+			*maybe_reserved = Some(reserve_amount);
+			Self::deposit_event(Event::Authorization {
+				authorizer: from.clone(),
+				authorizee: to.clone(),
+				collateral_type: currency_id,
+			});
+			Ok(())
+		})
+	}
+
+	fn do_unauthorize(from: &T::AccountId, currency_id: CurrencyId, to: &T::AccountId) -> DispatchResult {
+		let reserved = Authorization::<T>::take(from, (currency_id, to)).ok_or(Error::<T>::AuthorizationNotExists)?;
+		<T as Config>::Currency::unreserve_named(&RESERVE_ID, from, reserved);
+		Self::deposit_event(Event::UnAuthorization {
+			authorizer: from.clone(),
+			authorizee: to.clone(),
+			collateral_type: currency_id,
+		});
+		Ok(())
+	}
+
+	fn do_expand_position_collateral(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		increase_debit_value: Balance,
+		min_increase_collateral: Balance,
+	) -> DispatchResult {
+		<module_cdp_engine::Pallet<T>>::expand_position_collateral(
+			who,
+			currency_id,
+			increase_debit_value,
+			min_increase_collateral,
+		)
+	}
+
+	fn do_shrink_position_debit(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		decrease_collateral: Balance,
+		min_decrease_debit_value: Balance,
+	) -> DispatchResult {
+		<module_cdp_engine::Pallet<T>>::shrink_position_debit(
+			who,
+			currency_id,
+			decrease_collateral,
+			min_decrease_debit_value,
+		)
+	}
+
+	fn do_adjust_loan_by_debit_value(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_value_adjustment: Amount,
+	) -> DispatchResult {
+		// not allowed to adjust the debit after system shutdown
+		if !debit_value_adjustment.is_zero() {
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		}
+		<module_cdp_engine::Pallet<T>>::adjust_position_by_debit_value(
+			who,
+			currency_id,
+			collateral_adjustment,
+			debit_value_adjustment,
+		)
+	}
+
+	fn do_transfer_debit(
+		who: &T::AccountId,
+		from_currency: CurrencyId,
+		to_currency: CurrencyId,
+		debit_transfer: Balance,
+	) -> DispatchResult {
+		let debit_amount: Amount = debit_transfer.try_into().map_err(|_| ArithmeticError::Overflow)?;
+		let negative_debit = debit_amount.checked_neg().ok_or(ArithmeticError::Overflow)?;
+		// Adds ausd to user account momentarily to adjust loan
+		<T as module_cdp_engine::Config>::CDPTreasury::issue_debit(who, debit_transfer, true)?;
+
+		<module_cdp_engine::Pallet<T>>::adjust_position(who, from_currency, Zero::zero(), negative_debit)?;
+		<module_cdp_engine::Pallet<T>>::adjust_position(who, to_currency, Zero::zero(), debit_amount)?;
+		// Removes debit issued for debit transfer
+		<T as module_cdp_engine::Config>::CDPTreasury::burn_debit(who, debit_transfer)?;
+
+		Self::deposit_event(Event::TransferDebit {
+			from_currency,
+			to_currency,
+			amount: debit_transfer,
+		});
+		Ok(())
+	}
 }
 
 impl<T: Config> HonzonManager<T::AccountId, CurrencyId, Amount, Balance> for Pallet<T> {
@@ -423,6 +461,50 @@ impl<T: Config> HonzonManager<T::AccountId, CurrencyId, Amount, Balance> for Pal
 		Self::do_close_loan_by_dex(who, currency_id, max_collateral_amount)
 	}
 
+	fn adjust_loan_by_debit_value(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral_adjustment: Amount,
+		debit_value_adjustment: Amount,
+	) -> DispatchResult {
+		Self::do_adjust_loan_by_debit_value(who, currency_id, collateral_adjustment, debit_value_adjustment)
+	}
+
+	fn expand_position_collateral(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		increase_debit_value: Balance,
+		min_increase_collateral: Balance,
+	) -> DispatchResult {
+		Self::do_expand_position_collateral(who, currency_id, increase_debit_value, min_increase_collateral)
+	}
+
+	fn shrink_position_debit(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		decrease_collateral: Balance,
+		min_decrease_debit_value: Balance,
+	) -> DispatchResult {
+		Self::do_shrink_position_debit(who, currency_id, decrease_collateral, min_decrease_debit_value)
+	}
+
+	fn transfer_debit(
+		who: &T::AccountId,
+		from_currency: CurrencyId,
+		to_currency: CurrencyId,
+		debit_transfer: Balance,
+	) -> DispatchResult {
+		Self::do_transfer_debit(who, from_currency, to_currency, debit_transfer)
+	}
+
+	fn authorize(from: &T::AccountId, currency_id: CurrencyId, to: &T::AccountId) -> DispatchResult {
+		Self::do_authorize(from, currency_id, to)
+	}
+
+	fn unauthorize(from: &T::AccountId, currency_id: CurrencyId, to: &T::AccountId) -> DispatchResult {
+		Self::do_unauthorize(from, currency_id, to)
+	}
+
 	fn get_position(who: &T::AccountId, currency_id: CurrencyId) -> Position {
 		<module_loans::Pallet<T>>::positions(currency_id, who)
 	}
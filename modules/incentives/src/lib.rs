@@ -45,8 +45,9 @@ use module_support::{DEXIncentives, EmergencyShutdown, FractionalRate, Incentive
 use orml_traits::{Handler, MultiCurrency, RewardHandler};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	traits::{AccountIdConversion, UniqueSaturatedInto, Zero},
-	DispatchResult, FixedPointNumber,
+	DispatchResult, FixedPointNumber, Rounding,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
@@ -571,6 +572,48 @@ impl<T: Config> IncentivesManager<T::AccountId, Balance, CurrencyId, PoolId> for
 		}
 		reward_balances
 	}
+
+	fn get_claimable_rewards(pool_id: PoolId, who: T::AccountId, reward_currencies: Vec<CurrencyId>) -> Vec<Balance> {
+		let pending_rewards = PendingMultiRewards::<T>::get(pool_id, &who);
+		let pool_info = <orml_rewards::Pallet<T>>::pool_infos(&pool_id);
+		let (share, withdrawn_rewards) = <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, &who);
+
+		reward_currencies
+			.into_iter()
+			.map(|reward_currency| {
+				let pending_reward = pending_rewards.get(&reward_currency).copied().unwrap_or_default();
+				// same as the rewards that orml_rewards will pay out for the account on claim
+				let unpaid_reward = pool_info
+					.rewards
+					.get(&reward_currency)
+					.map(|(total_reward, total_withdrawn_reward)| {
+						let withdrawn_reward = withdrawn_rewards.get(&reward_currency).copied().unwrap_or_default();
+						multiply_by_rational_with_rounding(share, *total_reward, pool_info.total_shares, Rounding::Down)
+							.unwrap_or_default()
+							.saturating_sub(withdrawn_reward)
+							.min(total_reward.saturating_sub(*total_withdrawn_reward))
+					})
+					.unwrap_or_default();
+				pending_reward.saturating_add(unpaid_reward)
+			})
+			.collect()
+	}
+
+	fn get_total_shares(pool_id: PoolId) -> Balance {
+		<orml_rewards::Pallet<T>>::pool_infos(&pool_id).total_shares
+	}
+
+	fn get_shares(pool_id: PoolId, who: T::AccountId) -> Balance {
+		<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, &who).0
+	}
+
+	fn get_reward_pool(pool_id: PoolId, currency_id: CurrencyId) -> (Balance, Balance) {
+		<orml_rewards::Pallet<T>>::pool_infos(&pool_id)
+			.rewards
+			.get(&currency_id)
+			.copied()
+			.unwrap_or_default()
+	}
 }
 
 pub struct OnUpdateLoan<T>(sp_std::marker::PhantomData<T>);
@@ -1379,3 +1379,70 @@ fn nominees_election_should_work() {
 		);
 	});
 }
+
+#[test]
+fn share_and_reward_pool_queries_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(IncentivesModule::get_total_shares(PoolId::Loans(BTC)), 0);
+		assert_eq!(IncentivesModule::get_shares(PoolId::Loans(BTC), ALICE::get()), 0);
+		assert_eq!(IncentivesModule::get_reward_pool(PoolId::Loans(BTC), AUSD), (0, 0));
+
+		assert_ok!(RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100));
+		assert_ok!(RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 300));
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), AUSD, 1_000));
+		RewardsModule::claim_rewards(&ALICE::get(), &PoolId::Loans(BTC));
+
+		assert_eq!(IncentivesModule::get_total_shares(PoolId::Loans(BTC)), 400);
+		assert_eq!(IncentivesModule::get_shares(PoolId::Loans(BTC), ALICE::get()), 100);
+		assert_eq!(IncentivesModule::get_shares(PoolId::Loans(BTC), BOB::get()), 300);
+		assert_eq!(
+			IncentivesModule::get_reward_pool(PoolId::Loans(BTC), AUSD),
+			(1_000, 250)
+		);
+		assert_eq!(IncentivesModule::get_reward_pool(PoolId::Loans(BTC), DOT), (0, 0));
+
+		assert_ok!(OnNomineesElectionBonded::<Runtime>::handle(&(ALICE::get(), 80)));
+		assert_eq!(IncentivesModule::get_total_shares(PoolId::NomineesElection), 80);
+		assert_eq!(IncentivesModule::get_shares(PoolId::NomineesElection, ALICE::get()), 80);
+	});
+}
+
+#[test]
+fn get_claimable_rewards_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			IncentivesModule::get_claimable_rewards(PoolId::Loans(BTC), ALICE::get(), vec![AUSD, DOT]),
+			vec![0, 0]
+		);
+
+		assert_ok!(RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100));
+		assert_ok!(RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 300));
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), AUSD, 1_000));
+
+		// rewards are not paid out from the rewards pool yet
+		assert_eq!(
+			IncentivesModule::get_pending_rewards(PoolId::Loans(BTC), ALICE::get(), vec![AUSD, DOT]),
+			vec![0, 0]
+		);
+		assert_eq!(
+			IncentivesModule::get_claimable_rewards(PoolId::Loans(BTC), ALICE::get(), vec![AUSD, DOT]),
+			vec![250, 0]
+		);
+
+		// pay out the rewards to pending rewards, and accumulate more
+		assert_ok!(RewardsModule::remove_share(&ALICE::get(), &PoolId::Loans(BTC), 50));
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), AUSD, 700));
+		assert_eq!(
+			IncentivesModule::get_pending_rewards(PoolId::Loans(BTC), ALICE::get(), vec![AUSD, DOT]),
+			vec![250, 0]
+		);
+		assert_eq!(
+			IncentivesModule::get_claimable_rewards(PoolId::Loans(BTC), ALICE::get(), vec![AUSD, DOT]),
+			vec![350, 0]
+		);
+		assert_eq!(
+			IncentivesModule::get_claimable_rewards(PoolId::Loans(BTC), BOB::get(), vec![AUSD, DOT]),
+			vec![1_350, 0]
+		);
+	});
+}
@@ -30,7 +30,7 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use module_support::mocks::MockAddressMapping;
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, TokenSymbol};
+use primitives::{Amount, ReserveIdentifier, TokenSymbol};
 use sp_core::H160;
 use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
 
@@ -82,7 +82,7 @@ impl pallet_balances::Config for Runtime {
 	type MaxLocks = ();
 	type WeightInfo = ();
 	type MaxReserves = ();
-	type ReserveIdentifier = ();
+	type ReserveIdentifier = ReserveIdentifier;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type FreezeIdentifier = ();
@@ -112,6 +112,8 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type DepositCurrency = Balances;
+	type DepositPerAllowance = ConstU128<1>;
 }
 
 parameter_types! {
@@ -211,43 +211,7 @@ pub mod module {
 			attributes: Attributes,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let next_id = orml_nft::Pallet::<T>::next_class_id();
-			let owner: T::AccountId = T::PalletId::get().into_sub_account_truncating(next_id);
-			let class_deposit = T::CreateClassDeposit::get();
-
-			let data_deposit = Self::data_deposit(&metadata, &attributes)?;
-			let proxy_deposit = <pallet_proxy::Pallet<T>>::deposit(1u32);
-			let deposit = class_deposit.saturating_add(data_deposit);
-			let total_deposit = proxy_deposit.saturating_add(deposit);
-
-			// https://github.com/paritytech/substrate/blob/569aae5341ea0c1d10426fa1ec13a36c0b64393b/frame/balances/src/lib.rs#L965
-			// Now the pallet-balances judges whether does provider is based on the `free balance` instead of
-			// `total balance`. When there's no other providers, error will throw in following reserve
-			// operation, which want to make `free balance` is zero and `reserved balance` is not zero.
-			// If receiver account has not enough ed, transfer an additional ED to make sure the subsequent
-			// reserve operation.
-			let total_transfer_amount =
-				total_deposit.saturating_add(<T as module::Config>::Currency::minimum_balance());
-
-			// ensure enough token for proxy deposit + class deposit + data deposit + ed
-			<T as module::Config>::Currency::transfer(&who, &owner, total_transfer_amount, KeepAlive)?;
-
-			<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &owner, deposit)?;
-
-			// owner add proxy delegate to origin
-			<pallet_proxy::Pallet<T>>::add_proxy_delegate(&owner, who, Default::default(), Zero::zero())?;
-
-			let data = ClassData {
-				deposit,
-				properties,
-				attributes,
-			};
-			orml_nft::Pallet::<T>::create_class(&owner, metadata, data)?;
-
-			Self::deposit_event(Event::CreatedClass {
-				owner,
-				class_id: next_id,
-			});
+			Self::do_create_class(&who, metadata, properties, attributes)?;
 			Ok(().into())
 		}
 
@@ -368,25 +332,78 @@ pub mod module {
 			properties: Properties,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			orml_nft::Classes::<T>::try_mutate(class_id, |class_info| {
-				let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
-				ensure!(who == class_info.owner, Error::<T>::NoPermission);
-
-				let data = &mut class_info.data;
-				ensure!(
-					data.properties.0.contains(ClassProperty::ClassPropertiesMutable),
-					Error::<T>::Immutable
-				);
-
-				data.properties = properties;
-
-				Ok(())
-			})
+			Self::do_update_class_properties(&who, class_id, properties)
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	pub fn do_create_class(
+		who: &T::AccountId,
+		metadata: CID,
+		properties: Properties,
+		attributes: Attributes,
+	) -> Result<ClassIdOf<T>, DispatchError> {
+		let next_id = orml_nft::Pallet::<T>::next_class_id();
+		let owner: T::AccountId = T::PalletId::get().into_sub_account_truncating(next_id);
+		let class_deposit = T::CreateClassDeposit::get();
+
+		let data_deposit = Self::data_deposit(&metadata, &attributes)?;
+		let proxy_deposit = <pallet_proxy::Pallet<T>>::deposit(1u32);
+		let deposit = class_deposit.saturating_add(data_deposit);
+		let total_deposit = proxy_deposit.saturating_add(deposit);
+
+		// https://github.com/paritytech/substrate/blob/569aae5341ea0c1d10426fa1ec13a36c0b64393b/frame/balances/src/lib.rs#L965
+		// Now the pallet-balances judges whether does provider is based on the `free balance` instead of
+		// `total balance`. When there's no other providers, error will throw in following reserve
+		// operation, which want to make `free balance` is zero and `reserved balance` is not zero.
+		// If receiver account has not enough ed, transfer an additional ED to make sure the subsequent
+		// reserve operation.
+		let total_transfer_amount = total_deposit.saturating_add(<T as module::Config>::Currency::minimum_balance());
+
+		// ensure enough token for proxy deposit + class deposit + data deposit + ed
+		<T as module::Config>::Currency::transfer(who, &owner, total_transfer_amount, KeepAlive)?;
+
+		<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &owner, deposit)?;
+
+		// owner add proxy delegate to origin
+		<pallet_proxy::Pallet<T>>::add_proxy_delegate(&owner, who.clone(), Default::default(), Zero::zero())?;
+
+		let data = ClassData {
+			deposit,
+			properties,
+			attributes,
+		};
+		orml_nft::Pallet::<T>::create_class(&owner, metadata, data)?;
+
+		Self::deposit_event(Event::CreatedClass {
+			owner,
+			class_id: next_id,
+		});
+		Ok(next_id)
+	}
+
+	pub fn do_update_class_properties(
+		who: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		properties: Properties,
+	) -> DispatchResult {
+		orml_nft::Classes::<T>::try_mutate(class_id, |class_info| {
+			let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(*who == class_info.owner, Error::<T>::NoPermission);
+
+			let data = &mut class_info.data;
+			ensure!(
+				data.properties.0.contains(ClassProperty::ClassPropertiesMutable),
+				Error::<T>::Immutable
+			);
+
+			data.properties = properties;
+
+			Ok(())
+		})
+	}
+
 	#[require_transactional]
 	pub fn do_transfer(from: &T::AccountId, to: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
@@ -429,7 +446,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[require_transactional]
-	fn do_mint(
+	pub fn do_mint(
 		who: &T::AccountId,
 		to: &T::AccountId,
 		class_id: ClassIdOf<T>,
@@ -488,7 +505,7 @@ impl<T: Config> Pallet<T> {
 		Ok(token_ids)
 	}
 
-	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
+	pub fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
 		ensure!(
@@ -522,6 +539,23 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Returns the owner of `class_id` if `who` is the owner or a proxy delegate of it.
+	pub fn ensure_class_owner_or_proxy(
+		who: &T::AccountId,
+		class_id: ClassIdOf<T>,
+	) -> Result<T::AccountId, DispatchError> {
+		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		if *who != class_info.owner {
+			pallet_proxy::Pallet::<T>::find_proxy(&class_info.owner, who, None)
+				.map_err(|_| Error::<T>::NoPermission)?;
+		}
+		Ok(class_info.owner)
+	}
+
+	pub fn class_properties(class_id: ClassIdOf<T>) -> Option<Properties> {
+		orml_nft::Pallet::<T>::classes(class_id).map(|class_info| class_info.data.properties)
+	}
+
 	fn data_deposit(metadata: &[u8], attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes.iter().fold(0, |acc, (k, v)| {
@@ -562,6 +596,14 @@ impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
 		orml_nft::Pallet::<T>::classes(class).map(|c| c.owner)
 	}
 
+	fn attribute(class: &Self::CollectionId, instance: &Self::ItemId, key: &[u8]) -> Option<Vec<u8>> {
+		orml_nft::Pallet::<T>::tokens(class, instance).and_then(|t| t.data.attributes.get(key).cloned())
+	}
+
+	fn collection_attribute(class: &Self::CollectionId, key: &[u8]) -> Option<Vec<u8>> {
+		orml_nft::Pallet::<T>::classes(class).and_then(|c| c.data.attributes.get(key).cloned())
+	}
+
 	fn can_transfer(class: &Self::CollectionId, _: &Self::ItemId) -> bool {
 		orml_nft::Pallet::<T>::classes(class).map_or(false, |class_info| {
 			class_info.data.properties.0.contains(ClassProperty::Transferable)
@@ -166,6 +166,8 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<One, AccountId>;
 	type OnDust = ();
+	type DepositCurrency = Balances;
+	type DepositPerAllowance = ConstU128<1>;
 }
 
 parameter_types! {
@@ -21,7 +21,9 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{construct_runtime, derive_impl, ord_parameter_types, parameter_types, traits::Nothing};
+use frame_support::{
+	construct_runtime, derive_impl, ord_parameter_types, parameter_types, traits::Nothing, weights::Weight,
+};
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockErc20InfoMapping, ExchangeRate, SwapLimit};
 use orml_traits::{parameter_type_with_key, DataFeeder};
@@ -119,6 +121,10 @@ impl DEXManager<AccountId, Balance, CurrencyId> for MockDEX {
 		}
 	}
 
+	fn get_exchange_fee(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> (u32, u32) {
+		unimplemented!()
+	}
+
 	fn get_liquidity_token_address(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> Option<H160> {
 		unimplemented!()
 	}
@@ -144,6 +150,10 @@ impl DEXManager<AccountId, Balance, CurrencyId> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_concentrated_swap_weight(_: &[CurrencyId]) -> Weight {
+		unimplemented!()
+	}
+
 	fn add_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
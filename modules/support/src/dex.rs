@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{ensure, traits::Get};
+use frame_support::{ensure, pallet_prelude::Weight, traits::Get};
 use nutsfinance_stable_asset::{PoolTokenIndex, StableAssetPoolId};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
@@ -44,6 +44,8 @@ pub enum AggregatedSwapPath<CurrencyId> {
 pub trait DEXManager<AccountId, Balance, CurrencyId> {
 	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance);
 
+	fn get_exchange_fee(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (u32, u32);
+
 	fn get_liquidity_token_address(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> Option<H160>;
 
 	fn get_swap_amount(path: &[CurrencyId], limit: SwapLimit<Balance>) -> Option<(Balance, Balance)>;
@@ -61,6 +63,9 @@ pub trait DEXManager<AccountId, Balance, CurrencyId> {
 		limit: SwapLimit<Balance>,
 	) -> Result<(Balance, Balance), DispatchError>;
 
+	/// Get the extra weight of swapping by `path` through the concentrated liquidity pools on it.
+	fn get_concentrated_swap_weight(path: &[CurrencyId]) -> Weight;
+
 	fn add_liquidity(
 		who: &AccountId,
 		currency_id_a: CurrencyId,
@@ -141,6 +146,17 @@ where
 		swap_path: &[AggregatedSwapPath<CurrencyId>],
 		limit: SwapLimit<Balance>,
 	) -> Result<(Balance, Balance), DispatchError>;
+
+	/// Get the maximum extra weight of swapping `supply_currency_id` to `target_currency_id`
+	/// through concentrated liquidity pools.
+	fn get_concentrated_swap_weight(_supply_currency_id: CurrencyId, _target_currency_id: CurrencyId) -> Weight {
+		Weight::zero()
+	}
+
+	/// Get the extra weight of swapping by `swap_path` through concentrated liquidity pools.
+	fn get_concentrated_swap_weight_by_aggregated_path(_swap_path: &[AggregatedSwapPath<CurrencyId>]) -> Weight {
+		Weight::zero()
+	}
 }
 
 #[derive(Eq, PartialEq, RuntimeDebug)]
@@ -218,6 +234,30 @@ where
 			_ => Err(Into::<DispatchError>::into(SwapError::CannotSwap)),
 		}
 	}
+
+	fn get_concentrated_swap_weight(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Weight {
+		Joints::get().into_iter().fold(
+			<Dex as DEXManager<AccountId, Balance, CurrencyId>>::get_concentrated_swap_weight(&[
+				supply_currency_id.clone(),
+				target_currency_id.clone(),
+			]),
+			|weight, joint| {
+				let mut path = sp_std::vec![supply_currency_id.clone()];
+				path.extend(joint);
+				path.push(target_currency_id.clone());
+				weight.max(<Dex as DEXManager<AccountId, Balance, CurrencyId>>::get_concentrated_swap_weight(&path))
+			},
+		)
+	}
+
+	fn get_concentrated_swap_weight_by_aggregated_path(swap_path: &[AggregatedSwapPath<CurrencyId>]) -> Weight {
+		match swap_path {
+			[AggregatedSwapPath::<CurrencyId>::Dex(path)] => {
+				<Dex as DEXManager<AccountId, Balance, CurrencyId>>::get_concentrated_swap_weight(path)
+			}
+			_ => Weight::zero(),
+		}
+	}
 }
 
 #[cfg(feature = "std")]
@@ -229,6 +269,10 @@ where
 		Default::default()
 	}
 
+	fn get_exchange_fee(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> (u32, u32) {
+		Default::default()
+	}
+
 	fn get_liquidity_token_address(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> Option<H160> {
 		Some(Default::default())
 	}
@@ -254,6 +298,10 @@ where
 		Ok(Default::default())
 	}
 
+	fn get_concentrated_swap_weight(_path: &[CurrencyId]) -> Weight {
+		Weight::zero()
+	}
+
 	fn add_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
@@ -183,6 +183,35 @@ impl LiquidationEvmBridge for () {
 	fn on_repayment_refund(_context: InvokeContext, _collateral: EvmAddress, _repayment: Balance) {}
 }
 
+/// EVM bridge for DEX flash swap.
+pub trait FlashSwapEvmBridge {
+	/// Called after the target amount is transferred to the flash swap receiver. The supply amount
+	/// is expected to be held by the receiver when it returns, or the flash swap would be reverted.
+	/// The callback runs with at most `gas_limit` gas.
+	fn on_flash_swap(
+		context: InvokeContext,
+		supply_token: EvmAddress,
+		target_token: EvmAddress,
+		supply_amount: Balance,
+		target_amount: Balance,
+		data: Vec<u8>,
+		gas_limit: u64,
+	) -> DispatchResult;
+}
+impl FlashSwapEvmBridge for () {
+	fn on_flash_swap(
+		_context: InvokeContext,
+		_supply_token: EvmAddress,
+		_target_token: EvmAddress,
+		_supply_amount: Balance,
+		_target_amount: Balance,
+		_data: Vec<u8>,
+		_gas_limit: u64,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+}
+
 /// An abstraction of EVMManager
 pub trait EVMManager<AccountId, Balance> {
 	/// Query the constants `NewContractExtraBytes` value from evm module.
@@ -326,4 +355,9 @@ pub mod limits {
 		pub const ON_COLLATERAL_TRANSFER: Limit = Limit::new(200_000, 1_000);
 		pub const ON_REPAYMENT_REFUND: Limit = Limit::new(200_000, 1_000);
 	}
+
+	pub mod flash_swap {
+		/// The gas limit of the callback is given by the flash swap caller.
+		pub const ON_FLASH_SWAP_STORAGE_LIMIT: u32 = 10_000;
+	}
 }
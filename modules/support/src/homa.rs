@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{ExchangeRate, Rate};
+use primitives::EraIndex;
 use sp_runtime::DispatchResult;
 use sp_std::{fmt::Debug, vec::Vec};
 use xcm::v4::prelude::*;
@@ -53,4 +54,14 @@ pub trait HomaManager<AccountId, Balance> {
 	fn get_commission_rate() -> Rate;
 	/// Fee for fast matching redeem request
 	fn get_fast_match_fee() -> Rate;
+	/// Withdraw the expired redemption of specific redeemer by unbond
+	fn claim_redemption(redeemer: AccountId) -> DispatchResult;
+	/// Execute fast match for specific redeem requests
+	fn fast_match_redeems(redeemer_list: Vec<AccountId>, allow_partially: bool) -> DispatchResult;
+	/// Gets the pending redeem request of `who`: (liquid amount, allow fast match)
+	fn get_redeem_request(who: &AccountId) -> Option<(Balance, bool)>;
+	/// Gets the unbondings of `who`: (expire era index, staking amount)
+	fn get_unbondings(who: &AccountId) -> Vec<(EraIndex, Balance)>;
+	/// Gets the current era of relaychain
+	fn get_relay_chain_current_era() -> EraIndex;
 }
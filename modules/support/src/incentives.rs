@@ -52,6 +52,15 @@ pub trait IncentivesManager<AccountId, Balance, CurrencyId, PoolId> {
 	fn get_claim_reward_deduction_rate(pool_id: PoolId) -> Rate;
 	/// Gets the pending rewards for a pool, for an account
 	fn get_pending_rewards(pool_id: PoolId, who: AccountId, reward_currency: Vec<CurrencyId>) -> Vec<Balance>;
+	/// Gets the rewards for a pool, for an account, that will be claimed by `claim_rewards` before
+	/// deduction, including the rewards not yet paid out from the rewards pool
+	fn get_claimable_rewards(pool_id: PoolId, who: AccountId, reward_currency: Vec<CurrencyId>) -> Vec<Balance>;
+	/// Gets the total shares of a pool
+	fn get_total_shares(pool_id: PoolId) -> Balance;
+	/// Gets the shares of a pool, for an account
+	fn get_shares(pool_id: PoolId, who: AccountId) -> Balance;
+	/// Gets the total rewards and total withdrawn rewards of a pool for the given reward currency
+	fn get_reward_pool(pool_id: PoolId, currency_id: CurrencyId) -> (Balance, Balance);
 }
 
 pub trait DEXIncentives<AccountId, CurrencyId, Balance> {
@@ -578,23 +578,7 @@ pub mod module {
 			fee_swap_path: Option<Vec<CurrencyId>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			if let Some(path) = fee_swap_path {
-				let path: BoundedVec<CurrencyId, T::TradingPathLimit> =
-					path.try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
-				ensure!(
-					path.len() > 1
-						&& path.first() != Some(&T::NativeCurrencyId::get())
-						&& path.last() == Some(&T::NativeCurrencyId::get()),
-					Error::<T>::InvalidSwapPath
-				);
-				T::Currency::ensure_reserved_named(&DEPOSIT_ID, &who, T::AlternativeFeeSwapDeposit::get())?;
-				AlternativeFeeSwapPath::<T>::insert(&who, &path);
-			} else {
-				AlternativeFeeSwapPath::<T>::remove(&who);
-				T::Currency::unreserve_all_named(&DEPOSIT_ID, &who);
-			}
-			Ok(())
+			Self::do_set_alternative_fee_swap_path(&who, fee_swap_path)
 		}
 
 		/// Enable and initialize charge fee pool.
@@ -623,7 +607,15 @@ pub mod module {
 		#[pallet::call_index(3)]
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(T::WeightInfo::with_fee_path().saturating_add(dispatch_info.weight), dispatch_info.class,)
+			let concentrated_swap_weight = T::Swap::get_concentrated_swap_weight_by_aggregated_path(&[
+				AggregatedSwapPath::Dex(_fee_swap_path.clone()),
+			]);
+			(
+				T::WeightInfo::with_fee_path()
+					.saturating_add(concentrated_swap_weight)
+					.saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
 		})]
 		pub fn with_fee_path(
 			origin: OriginFor<T>,
@@ -639,7 +631,14 @@ pub mod module {
 		#[pallet::call_index(4)]
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(T::WeightInfo::with_fee_currency().saturating_add(dispatch_info.weight), dispatch_info.class,)
+			let concentrated_swap_weight =
+				T::Swap::get_concentrated_swap_weight(*_currency_id, T::NativeCurrencyId::get());
+			(
+				T::WeightInfo::with_fee_currency()
+					.saturating_add(concentrated_swap_weight)
+					.saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
 		})]
 		pub fn with_fee_currency(
 			origin: OriginFor<T>,
@@ -658,7 +657,14 @@ pub mod module {
 		#[pallet::call_index(6)]
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(T::WeightInfo::with_fee_aggregated_path().saturating_add(dispatch_info.weight), dispatch_info.class,)
+			let concentrated_swap_weight =
+				T::Swap::get_concentrated_swap_weight_by_aggregated_path(_fee_aggregated_path);
+			(
+				T::WeightInfo::with_fee_aggregated_path()
+					.saturating_add(concentrated_swap_weight)
+					.saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
 		})]
 		pub fn with_fee_aggregated_path(
 			origin: OriginFor<T>,
@@ -1137,6 +1143,30 @@ where
 		Ok(new_exchange_rate)
 	}
 
+	/// Set the alternative fee swap path of `who` and reserve the deposit, or remove it and
+	/// unreserve the deposit if `fee_swap_path` is none.
+	pub fn do_set_alternative_fee_swap_path(
+		who: &T::AccountId,
+		fee_swap_path: Option<Vec<CurrencyId>>,
+	) -> DispatchResult {
+		if let Some(path) = fee_swap_path {
+			let path: BoundedVec<CurrencyId, T::TradingPathLimit> =
+				path.try_into().map_err(|_| Error::<T>::InvalidSwapPath)?;
+			ensure!(
+				path.len() > 1
+					&& path.first() != Some(&T::NativeCurrencyId::get())
+					&& path.last() == Some(&T::NativeCurrencyId::get()),
+				Error::<T>::InvalidSwapPath
+			);
+			T::Currency::ensure_reserved_named(&DEPOSIT_ID, who, T::AlternativeFeeSwapDeposit::get())?;
+			AlternativeFeeSwapPath::<T>::insert(who, &path);
+		} else {
+			AlternativeFeeSwapPath::<T>::remove(who);
+			T::Currency::unreserve_all_named(&DEPOSIT_ID, who);
+		}
+		Ok(())
+	}
+
 	/// Initiate a charge fee pool, transfer token from treasury account to sub account.
 	pub fn initialize_pool(currency_id: CurrencyId, pool_size: Balance, swap_threshold: Balance) -> DispatchResult {
 		ensure!(currency_id != T::NativeCurrencyId::get(), Error::<T>::InvalidSwapPath);
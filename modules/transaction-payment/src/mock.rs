@@ -101,8 +101,8 @@ impl orml_tokens::Config for Runtime {
 	type ExistentialDeposits = ExistentialDeposits;
 	type CurrencyHooks = ();
 	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -141,6 +141,8 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<Zero, AccountId>;
 	type OnDust = ();
+	type DepositCurrency = PalletBalances;
+	type DepositPerAllowance = ConstU128<1>;
 }
 
 ord_parameter_types! {
@@ -170,14 +172,25 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Zero, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type FlashSwapEvmBridge = ();
 }
 
 impl module_aggregated_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
 	type DEX = DEXModule;
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
 	type GovernanceOrigin = EnsureSignedBy<Zero, AccountId>;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxOrdersPerAccount = ConstU32<20>;
+	type MaxOrderDuration = ConstU64<100>;
+	type OrderDeposit = ConstU128<1_000>;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type DexTradingPathLimit = TradingPathLimit;
+	type Task = ();
+	type IdleScheduler = ();
 	type WeightInfo = ();
 }
 
@@ -309,6 +322,7 @@ construct_runtime!(
 		Tokens: orml_tokens,
 		Currencies: module_currencies,
 		DEXModule: module_dex,
+		AggregatedDex: module_aggregated_dex,
 	}
 );
 
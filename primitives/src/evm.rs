@@ -89,6 +89,8 @@ pub struct ExecutionInfo<T> {
 
 pub type CallInfo = ExecutionInfo<Vec<u8>>;
 pub type CreateInfo = ExecutionInfo<H160>;
+/// The exit reason and output of each call in the batch.
+pub type BatchCallInfo = ExecutionInfo<Vec<(ExitReason, Vec<u8>)>>;
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -99,6 +101,15 @@ pub struct BlockLimits {
 	pub max_storage_limit: u32,
 }
 
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContractStorageInfo<Balance> {
+	/// Storage used by the contract, including code size, extra bytes and storages
+	pub used_storage: u32,
+	/// Storage deposit reserved by the contract account
+	pub reserved_deposit: Balance,
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct EstimateResourcesRequest {
@@ -341,7 +352,7 @@ pub mod tracing {
 	use scale_info::TypeInfo;
 	use sp_core::{H160, H256, U256};
 	use sp_runtime::RuntimeDebug;
-	use sp_std::vec::Vec;
+	use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 	#[cfg(feature = "std")]
 	use serde::{Deserialize, Serialize};
@@ -405,6 +416,22 @@ pub mod tracing {
 		}
 	}
 
+	#[cfg(feature = "std")]
+	mod hex_vec {
+		use serde::{Deserialize, Deserializer, Serializer};
+		pub fn serialize<S: Serializer>(data: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.collect_seq(data.iter().map(|x| sp_core::bytes::to_hex(x, false)))
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error> {
+			use serde::de::Error;
+			Vec::<String>::deserialize(deserializer)?
+				.into_iter()
+				.map(|x| sp_core::bytes::from_hex(&x).map_err(Error::custom))
+				.collect()
+		}
+	}
+
 	#[derive(Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
 	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
@@ -437,6 +464,12 @@ pub mod tracing {
 		pub logs: Vec<LogTrace>,
 		// List of sub-calls
 		pub calls: Vec<CallTrace>,
+		// SCALE encoded runtime events emitted by the precompile, if any
+		#[cfg_attr(
+			feature = "std",
+			serde(with = "hex_vec", default, skip_serializing_if = "Vec::is_empty")
+		)]
+		pub substrate_events: Vec<Vec<u8>>,
 	}
 
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -484,6 +517,82 @@ pub mod tracing {
 	pub enum TraceOutcome {
 		Calls(Vec<CallTrace>),
 		Steps(Vec<Step>),
+		Prestate(PrestateTrace),
+		FourByte(FourByteTrace),
+	}
+
+	impl TraceOutcome {
+		pub fn is_empty(&self) -> bool {
+			match self {
+				TraceOutcome::Calls(calls) => calls.is_empty(),
+				TraceOutcome::Steps(steps) => steps.is_empty(),
+				TraceOutcome::Prestate(PrestateTrace::Prestate(accounts)) => accounts.is_empty(),
+				TraceOutcome::Prestate(PrestateTrace::Diff { pre, post }) => pre.is_empty() && post.is_empty(),
+				TraceOutcome::FourByte(trace) => trace.calls.is_empty() && trace.gas.is_empty(),
+			}
+		}
+	}
+
+	#[derive(Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct PrestateAccount {
+		#[cfg_attr(feature = "std", serde(default, skip_serializing_if = "Option::is_none"))]
+		pub balance: Option<U256>,
+		#[cfg_attr(feature = "std", serde(default, skip_serializing_if = "Option::is_none"))]
+		pub nonce: Option<U256>,
+		#[cfg_attr(
+			feature = "std",
+			serde(with = "maybe_hex", default, skip_serializing_if = "Option::is_none")
+		)]
+		pub code: Option<Vec<u8>>,
+		// touched storage slots
+		#[cfg_attr(feature = "std", serde(default, skip_serializing_if = "BTreeMap::is_empty"))]
+		pub storage: BTreeMap<H256, H256>,
+	}
+
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(untagged))]
+	pub enum PrestateTrace {
+		// state of every touched account before execution
+		Prestate(BTreeMap<H160, PrestateAccount>),
+		// state of modified accounts before and after execution,
+		// post state only contains the fields which have changed
+		Diff {
+			pre: BTreeMap<H160, PrestateAccount>,
+			post: BTreeMap<H160, PrestateAccount>,
+		},
+	}
+
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct FourByteCall {
+		pub selector: [u8; 4],
+		// size of the calldata excluding the selector
+		#[codec(compact)]
+		pub calldata_size: u32,
+		#[codec(compact)]
+		pub count: u32,
+	}
+
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct FourByteGas {
+		pub selector: [u8; 4],
+		// cumulative gas used by all the calls of the selector, including their sub-calls
+		#[codec(compact)]
+		pub gas_used: u64,
+	}
+
+	#[derive(Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct FourByteTrace {
+		pub calls: Vec<FourByteCall>,
+		pub gas: Vec<FourByteGas>,
 	}
 
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -491,6 +600,8 @@ pub mod tracing {
 	pub enum TracerConfig {
 		CallTracer,
 		OpcodeTracer(OpcodeConfig),
+		PrestateTracer(PrestateConfig),
+		FourByteTracer,
 	}
 
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -505,4 +616,12 @@ pub mod tracing {
 		pub disable_stack: bool,
 		pub enable_memory: bool,
 	}
+
+	#[derive(Clone, Eq, PartialEq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct PrestateConfig {
+		// Return the difference between pre and post state instead of the pre state only.
+		pub diff_mode: bool,
+	}
 }
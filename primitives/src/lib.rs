@@ -189,6 +189,8 @@ pub enum ReserveIdentifier {
 	Nft,
 	TransactionPayment,
 	TransactionPaymentDeposit,
+	Currencies,
+	AggregatedDex,
 
 	// always the last, indicate number of variants
 	Count,
@@ -238,3 +238,28 @@ fn decode_gas_limit_works() {
 		(15330000, 2u32.pow(MAX_GAS_LIMIT_CC))
 	);
 }
+
+#[test]
+fn trace_outcome_is_empty_works() {
+	use crate::evm::tracing::{FourByteCall, FourByteGas, FourByteTrace, TraceOutcome};
+
+	assert!(TraceOutcome::Calls(vec![]).is_empty());
+	assert!(TraceOutcome::FourByte(FourByteTrace::default()).is_empty());
+	assert!(!TraceOutcome::FourByte(FourByteTrace {
+		calls: vec![FourByteCall {
+			selector: [1, 2, 3, 4],
+			calldata_size: 32,
+			count: 1,
+		}],
+		gas: vec![],
+	})
+	.is_empty());
+	assert!(!TraceOutcome::FourByte(FourByteTrace {
+		calls: vec![],
+		gas: vec![FourByteGas {
+			selector: [1, 2, 3, 4],
+			gas_used: 21_000,
+		}],
+	})
+	.is_empty());
+}
@@ -316,4 +316,102 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `Dex::ExchangeFees` (r:0 w:1)
+	// Proof: `Dex::ExchangeFees` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	fn update_exchange_fee() -> Weight {
+		// Estimated from the single `ExchangeFees` write, not benchmarked yet.
+		Weight::from_parts(15_212_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Dex::LiquidityPool` (r:1 w:0)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ProtocolFeeRates` (r:1 w:1)
+	// Proof: `Dex::ProtocolFeeRates` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ProtocolFeeKLast` (r:1 w:1)
+	// Proof: `Dex::ProtocolFeeKLast` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_protocol_fee_rate() -> Weight {
+		// Estimated from `add_liquidity` for minting the protocol fee share, not benchmarked yet.
+		Weight::from_parts(44_516_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `Dex::ConcentratedPools` (r:1 w:1)
+	// Proof: `Dex::ConcentratedPools` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	fn create_concentrated_pool() -> Weight {
+		// Estimated from `update_exchange_fee` plus the `ConcentratedPools` read, not benchmarked yet.
+		Weight::from_parts(17_834_000, 3603)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedPools` (r:1 w:1)
+	// Proof: `Dex::ConcentratedPools` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedTicks` (r:100 w:100)
+	// Proof: `Dex::ConcentratedTicks` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedPositions` (r:100 w:100)
+	// Proof: `Dex::ConcentratedPositions` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[1, 100]`.
+	fn add_concentrated_liquidity(t: u32, ) -> Weight {
+		// Estimated from `add_liquidity` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(71_526_000, 11478)
+			.saturating_add(Weight::from_parts(13_452_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2629).saturating_mul(t.into()))
+	}
+	// Storage: `Dex::ConcentratedPools` (r:1 w:1)
+	// Proof: `Dex::ConcentratedPools` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedPositions` (r:100 w:100)
+	// Proof: `Dex::ConcentratedPositions` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedTicks` (r:100 w:100)
+	// Proof: `Dex::ConcentratedTicks` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[1, 100]`.
+	fn remove_concentrated_liquidity(t: u32, ) -> Weight {
+		// Estimated from `remove_liquidity` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(65_341_000, 11478)
+			.saturating_add(Weight::from_parts(12_118_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2629).saturating_mul(t.into()))
+	}
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ExchangeFees` (r:1 w:0)
+	// Proof: `Dex::ExchangeFees` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:0)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedPools` (r:1 w:1)
+	// Proof: `Dex::ConcentratedPools` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedTicks` (r:100 w:100)
+	// Proof: `Dex::ConcentratedTicks` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ProtocolFeeRates` (r:1 w:0)
+	// Proof: `Dex::ProtocolFeeRates` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:5 w:5)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[1, 100]`.
+	fn concentrated_swap(t: u32, ) -> Weight {
+		// Estimated from `swap_with_exact_target` plus the storage accessed by each tick, not benchmarked yet.
+		Weight::from_parts(93_966_000, 11478)
+			.saturating_add(Weight::from_parts(12_118_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2629).saturating_mul(t.into()))
+	}
 }
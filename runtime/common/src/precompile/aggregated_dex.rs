@@ -0,0 +1,780 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2024 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use frame_support::traits::Get;
+use module_aggregated_dex::{AggregatedSwap, SwapPath, WeightInfo};
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use module_support::{Erc20InfoMapping as Erc20InfoMappingT, Swap, SwapLimit};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::Balance;
+use sp_core::H160;
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The `AggregatedDex` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// The swap path is packed bytes of the hops:
+/// - DEX hop: `0x00`, path length (`uint8`), currency addresses (20 bytes each).
+/// - StableAsset hop: `0x01`, pool id (`uint32`), supply asset index (`uint32`), target asset index
+///   (`uint32`).
+///
+/// Actions:
+/// - Get swap target amount. Rest `input` bytes: `path`, `supply_amount`.
+/// - Get swap supply amount. Rest `input` bytes: `path`, `target_amount`.
+/// - Swap with exact supply. Rest `input` bytes: `who`, `path`, `supply_amount`,
+///   `min_target_amount`.
+/// - Swap with exact target. Rest `input` bytes: `who`, `path`, `target_amount`,
+///   `max_supply_amount`.
+/// - Get aggregated swap paths. Rest `input` bytes: `supply_currency_id`, `target_currency_id`.
+pub struct AggregatedDexPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetSwapTargetAmount = "getSwapTargetAmount(bytes,uint256)",
+	GetSwapSupplyAmount = "getSwapSupplyAmount(bytes,uint256)",
+	SwapWithExactSupply = "swapWithExactSupply(address,bytes,uint256,uint256)",
+	SwapWithExactTarget = "swapWithExactTarget(address,bytes,uint256,uint256)",
+	GetAggregatedSwapPaths = "getAggregatedSwapPaths(address,address)",
+}
+
+const DEX_HOP: u8 = 0;
+const STABLE_ASSET_HOP: u8 = 1;
+
+fn invalid_swap_path() -> PrecompileFailure {
+	PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: "invalid swap path".into(),
+	}
+}
+
+fn read_u32(data: &[u8], start: usize) -> Result<u32, PrecompileFailure> {
+	let bytes = data.get(start..start + 4).ok_or_else(invalid_swap_path)?;
+	Ok(u32::from_be_bytes(bytes.try_into().map_err(|_| invalid_swap_path())?))
+}
+
+fn decode_swap_paths<Runtime>(data: &[u8]) -> Result<Vec<SwapPath>, PrecompileFailure>
+where
+	Runtime: module_dex::Config + module_aggregated_dex::Config,
+{
+	let mut paths = vec![];
+	let mut i = 0;
+	while i < data.len() {
+		match data[i] {
+			DEX_HOP => {
+				let path_len = *data.get(i + 1).ok_or_else(invalid_swap_path)? as usize;
+				i += 2;
+
+				let mut path = vec![];
+				for _ in 0..path_len {
+					let address = data.get(i..i + 20).ok_or_else(invalid_swap_path)?;
+					let currency_id = <Runtime as module_dex::Config>::Erc20InfoMapping::decode_evm_address(
+						H160::from_slice(address),
+					)
+					.ok_or_else(|| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "invalid currency id".into(),
+					})?;
+					path.push(currency_id);
+					i += 20;
+				}
+				paths.push(SwapPath::Dex(path));
+			}
+			STABLE_ASSET_HOP => {
+				let pool_id = read_u32(data, i + 1)?;
+				let supply_asset_index = read_u32(data, i + 5)?;
+				let target_asset_index = read_u32(data, i + 9)?;
+				i += 13;
+
+				paths.push(SwapPath::Taiga(pool_id, supply_asset_index, target_asset_index));
+			}
+			_ => return Err(invalid_swap_path()),
+		}
+	}
+
+	if paths.is_empty() || paths.len() > <Runtime as module_aggregated_dex::Config>::SwapPathLimit::get() as usize {
+		return Err(invalid_swap_path());
+	}
+
+	Ok(paths)
+}
+
+fn encode_swap_paths<Runtime>(paths: &[SwapPath]) -> Option<Vec<u8>>
+where
+	Runtime: module_dex::Config,
+{
+	let mut data = vec![];
+	for path in paths {
+		match path {
+			SwapPath::Dex(path) => {
+				data.push(DEX_HOP);
+				data.push(u8::try_from(path.len()).ok()?);
+				for currency_id in path {
+					let address = <Runtime as module_dex::Config>::Erc20InfoMapping::encode_evm_address(*currency_id)?;
+					data.extend_from_slice(address.as_bytes());
+				}
+			}
+			SwapPath::Taiga(pool_id, supply_asset_index, target_asset_index) => {
+				data.push(STABLE_ASSET_HOP);
+				data.extend_from_slice(&pool_id.to_be_bytes());
+				data.extend_from_slice(&supply_asset_index.to_be_bytes());
+				data.extend_from_slice(&target_asset_index.to_be_bytes());
+			}
+		}
+	}
+	Some(data)
+}
+
+// Same as the weight of `module_aggregated_dex`.
+fn swap_path_weight_len(paths: &[SwapPath]) -> u32 {
+	paths.iter().fold(0, |u, swap_path| match swap_path {
+		SwapPath::Dex(v) => u + (v.len() as u32),
+		SwapPath::Taiga(_, _, _) => u + 1,
+	})
+}
+
+impl<Runtime> Precompile for AggregatedDexPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_dex::Config + module_aggregated_dex::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			Runtime::AddressMapping,
+			<Runtime as module_dex::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetSwapTargetAmount => {
+				let paths = decode_swap_paths::<Runtime>(&input.bytes_at(1)?)?;
+				let supply_amount = input.balance_at(2)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: get_swap_target_amount paths: {:?}, supply_amount: {:?}",
+					paths, supply_amount
+				);
+
+				// If get_aggregated_swap_amount fail, return 0.
+				let target = module_aggregated_dex::Pallet::<Runtime>::get_aggregated_swap_amount(
+					&paths,
+					SwapLimit::ExactSupply(supply_amount, Balance::MIN),
+				)
+				.map(|(_, target)| target)
+				.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(target),
+				})
+			}
+			Action::GetSwapSupplyAmount => {
+				let paths = decode_swap_paths::<Runtime>(&input.bytes_at(1)?)?;
+				let target_amount = input.balance_at(2)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: get_swap_supply_amount paths: {:?}, target_amount: {:?}",
+					paths, target_amount
+				);
+
+				// If get_aggregated_swap_amount fail, return 0.
+				let supply = module_aggregated_dex::Pallet::<Runtime>::get_aggregated_swap_amount(
+					&paths,
+					SwapLimit::ExactTarget(Balance::MAX, target_amount),
+				)
+				.map(|(supply, _)| supply)
+				.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(supply),
+				})
+			}
+			Action::SwapWithExactSupply => {
+				let who = input.account_id_at(1)?;
+				let paths = decode_swap_paths::<Runtime>(&input.bytes_at(2)?)?;
+				let supply_amount = input.balance_at(3)?;
+				let min_target_amount = input.balance_at(4)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: swap_with_exact_supply who: {:?}, paths: {:?}, supply_amount: {:?}, min_target_amount: {:?}",
+					who, paths, supply_amount, min_target_amount
+				);
+
+				let (_, value) = AggregatedSwap::<Runtime>::swap_by_aggregated_path(
+					&who,
+					&paths,
+					SwapLimit::ExactSupply(supply_amount, min_target_amount),
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("AggregatedDex SwapWithExactSupply failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(value),
+				})
+			}
+			Action::SwapWithExactTarget => {
+				let who = input.account_id_at(1)?;
+				let paths = decode_swap_paths::<Runtime>(&input.bytes_at(2)?)?;
+				let target_amount = input.balance_at(3)?;
+				let max_supply_amount = input.balance_at(4)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: swap_with_exact_target who: {:?}, paths: {:?}, target_amount: {:?}, max_supply_amount: {:?}",
+					who, paths, target_amount, max_supply_amount
+				);
+
+				let (value, _) = AggregatedSwap::<Runtime>::swap_by_aggregated_path(
+					&who,
+					&paths,
+					SwapLimit::ExactTarget(max_supply_amount, target_amount),
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("AggregatedDex SwapWithExactTarget failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(value),
+				})
+			}
+			Action::GetAggregatedSwapPaths => {
+				let supply_currency_id = input.currency_id_at(1)?;
+				let target_currency_id = input.currency_id_at(2)?;
+				log::debug!(
+					target: "evm",
+					"aggregated_dex: get_aggregated_swap_paths supply_currency_id: {:?}, target_currency_id: {:?}",
+					supply_currency_id, target_currency_id
+				);
+
+				// If the paths do not exist, return empty bytes.
+				let paths = module_aggregated_dex::Pallet::<Runtime>::aggregated_swap_paths((
+					supply_currency_id,
+					target_currency_id,
+				))
+				.unwrap_or_default();
+				let data = encode_swap_paths::<Runtime>(&paths).ok_or_else(|| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid currency id".into(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&data),
+				})
+			}
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_dex::Config + module_aggregated_dex::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn read_paths(paths: &[SwapPath]) -> (u64, u64) {
+		let mut read_currency = 0u64;
+		let mut reads = 0u64;
+		for path in paths {
+			match path {
+				SwapPath::Dex(path) => {
+					for currency_id in path {
+						read_currency =
+							read_currency.saturating_add(InputPricer::<Runtime>::read_currency(*currency_id));
+					}
+					// DEX::TradingPairStatuses (r: 1 * (path_len - 1))
+					// DEX::LiquidityPool (r: 1 * (path_len - 1))
+					reads = reads.saturating_add((path.len() as u64).saturating_sub(1).saturating_mul(2));
+				}
+				SwapPath::Taiga(_, _, _) => {
+					// StableAsset::Pools (r: 1)
+					reads = reads.saturating_add(1);
+				}
+			}
+		}
+		(read_currency, reads)
+	}
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			Runtime::AddressMapping,
+			<Runtime as module_dex::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::GetSwapTargetAmount | Action::GetSwapSupplyAmount => {
+				let paths = decode_swap_paths::<Runtime>(&input.bytes_at(1)?)?;
+				let (read_currency, reads) = Self::read_paths(&paths);
+
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(reads);
+
+				Self::BASE_COST
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SwapWithExactSupply => {
+				let paths = decode_swap_paths::<Runtime>(&input.bytes_at(2)?)?;
+				let (read_currency, _) = Self::read_paths(&paths);
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+
+				let weight = <Runtime as module_aggregated_dex::Config>::WeightInfo::swap_with_exact_supply(
+					swap_path_weight_len(&paths),
+				)
+				.saturating_add(module_aggregated_dex::Pallet::<Runtime>::concentrated_swap_weight(
+					&paths,
+				));
+
+				Self::BASE_COST
+					.saturating_add(read_currency)
+					.saturating_add(read_account)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SwapWithExactTarget => {
+				let paths = decode_swap_paths::<Runtime>(&input.bytes_at(2)?)?;
+				let (read_currency, _) = Self::read_paths(&paths);
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+
+				let weight = <Runtime as module_aggregated_dex::Config>::WeightInfo::swap_with_exact_target(
+					swap_path_weight_len(&paths),
+				)
+				.saturating_add(module_aggregated_dex::Pallet::<Runtime>::concentrated_swap_weight(
+					&paths,
+				));
+
+				Self::BASE_COST
+					.saturating_add(read_currency)
+					.saturating_add(read_account)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetAggregatedSwapPaths => {
+				let currency_id_a = input.currency_id_at(1)?;
+				let currency_id_b = input.currency_id_at(2)?;
+				let read_currency_a = InputPricer::<Runtime>::read_currency(currency_id_a);
+				let read_currency_b = InputPricer::<Runtime>::read_currency(currency_id_b);
+
+				// AggregatedDex::AggregatedSwapPaths (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST
+					.saturating_add(read_currency_a)
+					.saturating_add(read_currency_b)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(cost)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, new_test_ext, AggregatedDex, Currencies, DexModule, RuntimeOrigin, StableAsset, Test,
+		Tokens, ALICE, AUSD, DOT, LDOT,
+	};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use orml_traits::MultiCurrency;
+	use primitives::CurrencyId;
+
+	type AggregatedDexPrecompile = crate::AggregatedDexPrecompile<Test>;
+
+	fn enable_dot_ausd() {
+		assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+
+		assert_ok!(DexModule::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+	}
+
+	fn create_dot_ldot_pool() {
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			ALICE,
+			LDOT,
+			1_000_000_000
+		));
+		assert_ok!(StableAsset::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			CurrencyId::StableAssetPoolToken(0),
+			vec![DOT, LDOT],
+			vec![1, 1],
+			2u128,
+			3u128,
+			4u128,
+			10000,
+			ALICE,
+			ALICE,
+			1u128
+		));
+		assert_ok!(StableAsset::mint(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			vec![1_000_000u128, 1_000_000u128],
+			0u128
+		));
+	}
+
+	#[test]
+	fn get_swap_target_amount_works() {
+		new_test_ext().execute_with(|| {
+			enable_dot_ausd();
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getSwapTargetAmount(bytes,uint256) -> 0x6ea235a0
+			// offset
+			// supply_amount
+			// path bytes len
+			// DEX hop: 0x00, path_len, DOT, AUSD
+			let input = hex! {"
+				6ea235a0
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 0000000000000000000000000000002a
+				0002 0000000000000000000100000000000000000002 0000000000000000000100000000000000000001 00000000000000000000
+				000000000000000000000000
+			"};
+
+			// 989
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000003dd
+			"};
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn get_swap_supply_amount_works() {
+		new_test_ext().execute_with(|| {
+			enable_dot_ausd();
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getSwapSupplyAmount(bytes,uint256) -> 0x4eaf0de8
+			// offset
+			// target_amount
+			// path bytes len
+			// DEX hop: 0x00, path_len, DOT, AUSD
+			let input = hex! {"
+				4eaf0de8
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 000000000000000000000000000003dd
+				00000000000000000000000000000000 0000000000000000000000000000002a
+				0002 0000000000000000000100000000000000000002 0000000000000000000100000000000000000001 00000000000000000000
+				000000000000000000000000
+			"};
+
+			// 1
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn invalid_swap_path_reverts() {
+		new_test_ext().execute_with(|| {
+			enable_dot_ausd();
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getSwapTargetAmount(bytes,uint256) -> 0x6ea235a0
+			// offset
+			// supply_amount
+			// path bytes len
+			// unknown hop: 0x02
+			let input = hex! {"
+				6ea235a0
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+				02000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			assert_noop!(
+				AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(
+					&input,
+					Some(100_000),
+					&context,
+					false
+				)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid swap path".into(),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn get_aggregated_swap_paths_works() {
+		new_test_ext().execute_with(|| {
+			enable_dot_ausd();
+
+			assert_ok!(AggregatedDex::update_aggregated_swap_paths(
+				RuntimeOrigin::signed(ALICE),
+				vec![((DOT, AUSD), Some(vec![SwapPath::Dex(vec![DOT, AUSD])]))]
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getAggregatedSwapPaths(address,address) -> 0x36f84d01
+			// DOT
+			// AUSD
+			let input = hex! {"
+				36f84d01
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// offset
+			// path bytes len
+			// DEX hop: 0x00, path_len, DOT, AUSD
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 0000000000000000000000000000002a
+				0002 0000000000000000000100000000000000000002 0000000000000000000100000000000000000001 00000000000000000000
+				000000000000000000000000
+			"};
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn swap_with_exact_supply_works() {
+		new_test_ext().execute_with(|| {
+			enable_dot_ausd();
+			create_dot_ldot_pool();
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			let paths = vec![SwapPath::Dex(vec![AUSD, DOT]), SwapPath::Taiga(0, 0, 1)];
+			let (_, target_amount) =
+				AggregatedDex::get_aggregated_swap_amount(&paths, SwapLimit::ExactSupply(100_000, 0)).unwrap();
+			assert!(target_amount > 0);
+
+			// swapWithExactSupply(address,bytes,uint256,uint256) -> 0xaff3667f
+			// who
+			// offset
+			// supply_amount 100_000
+			// min_target_amount 0xffff
+			// path bytes len
+			// DEX hop: 0x00, path_len, AUSD, DOT
+			// StableAsset hop: 0x01, pool_id, supply_asset_index, target_asset_index
+			let input = hex! {"
+				aff3667f
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 0000000000000000000000000000ffff
+				00000000000000000000000000000000 00000000000000000000000000000037
+				0002 0000000000000000000100000000000000000001 0000000000000000000100000000000000000002
+				01 00000000 00000000 00000001 000000000000000000
+			"};
+
+			// the min target amount is not met
+			assert_noop!(
+				AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "AggregatedDex SwapWithExactSupply failed: CannotSwap".into(),
+				}
+			);
+
+			// swapWithExactSupply(address,bytes,uint256,uint256) -> 0xaff3667f
+			// who
+			// offset
+			// supply_amount 100_000
+			// min_target_amount 0
+			// path bytes len
+			// DEX hop: 0x00, path_len, AUSD, DOT
+			// StableAsset hop: 0x01, pool_id, supply_asset_index, target_asset_index
+			let input = hex! {"
+				aff3667f
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000037
+				0002 0000000000000000000100000000000000000001 0000000000000000000100000000000000000002
+				01 00000000 00000000 00000001 000000000000000000
+			"};
+
+			let ausd_balance = Tokens::free_balance(AUSD, &alice());
+			let dot_balance = Tokens::free_balance(DOT, &alice());
+			let ldot_balance = Tokens::free_balance(LDOT, &alice());
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(target_amount));
+
+			assert_eq!(Tokens::free_balance(AUSD, &alice()), ausd_balance - 100_000);
+			assert_eq!(Tokens::free_balance(DOT, &alice()), dot_balance);
+			assert_eq!(Tokens::free_balance(LDOT, &alice()), ldot_balance + target_amount);
+		});
+	}
+
+	#[test]
+	fn swap_with_exact_target_works() {
+		new_test_ext().execute_with(|| {
+			enable_dot_ausd();
+			create_dot_ldot_pool();
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			let paths = vec![SwapPath::Dex(vec![AUSD, DOT]), SwapPath::Taiga(0, 0, 1)];
+			let (supply_amount, _) =
+				AggregatedDex::get_aggregated_swap_amount(&paths, SwapLimit::ExactTarget(100_000, 50)).unwrap();
+			// the swap is executed with the exact supply amount, which gets at least the target amount
+			let (_, target_amount) =
+				AggregatedDex::get_aggregated_swap_amount(&paths, SwapLimit::ExactSupply(supply_amount, 0)).unwrap();
+			assert!(target_amount >= 50);
+
+			// swapWithExactTarget(address,bytes,uint256,uint256) -> 0xae5d9706
+			// who
+			// offset
+			// target_amount 50
+			// max_supply_amount 1
+			// path bytes len
+			// DEX hop: 0x00, path_len, AUSD, DOT
+			// StableAsset hop: 0x01, pool_id, supply_asset_index, target_asset_index
+			let input = hex! {"
+				ae5d9706
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 00000000000000000000000000000032
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000037
+				0002 0000000000000000000100000000000000000001 0000000000000000000100000000000000000002
+				01 00000000 00000000 00000001 000000000000000000
+			"};
+
+			// the max supply amount is not met
+			assert_noop!(
+				AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "AggregatedDex SwapWithExactTarget failed: CannotSwap".into(),
+				}
+			);
+
+			// swapWithExactTarget(address,bytes,uint256,uint256) -> 0xae5d9706
+			// who
+			// offset
+			// target_amount 50
+			// max_supply_amount 100_000
+			// path bytes len
+			// DEX hop: 0x00, path_len, AUSD, DOT
+			// StableAsset hop: 0x01, pool_id, supply_asset_index, target_asset_index
+			let input = hex! {"
+				ae5d9706
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 00000000000000000000000000000032
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 00000000000000000000000000000037
+				0002 0000000000000000000100000000000000000001 0000000000000000000100000000000000000002
+				01 00000000 00000000 00000001 000000000000000000
+			"};
+
+			let ausd_balance = Tokens::free_balance(AUSD, &alice());
+			let dot_balance = Tokens::free_balance(DOT, &alice());
+			let ldot_balance = Tokens::free_balance(LDOT, &alice());
+
+			let resp = AggregatedDexPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(supply_amount));
+
+			assert_eq!(Tokens::free_balance(AUSD, &alice()), ausd_balance - supply_amount);
+			assert_eq!(Tokens::free_balance(DOT, &alice()), dot_balance);
+			assert_eq!(Tokens::free_balance(LDOT, &alice()), ldot_balance + target_amount);
+		});
+	}
+}
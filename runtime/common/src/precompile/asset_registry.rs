@@ -0,0 +1,408 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2024 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use ethabi::Token;
+use frame_support::traits::Get;
+use module_asset_registry::{AssetIdMaps, BalanceOf};
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use module_support::{AssetIdMapping, Erc20InfoMapping};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+use primitives::{
+	currency::{AssetIds, ForeignAssetId},
+	CurrencyId,
+};
+use sp_core::U256;
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::{v4::Location, VersionedLocation};
+
+/// The `AssetRegistry` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Get asset metadata. Rest `input` bytes: `currency_id`.
+/// - Get the SCALE encoded location of foreign asset. Rest `input` bytes: `foreign_asset_id`.
+/// - Get the token address of foreign asset. Rest `input` bytes: `foreign_asset_id`.
+/// - Get the token address by SCALE encoded location. Rest `input` bytes: `location`.
+pub struct AssetRegistryPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetAssetMetadata = "getAssetMetadata(address)",
+	GetForeignAssetLocation = "getForeignAssetLocation(uint16)",
+	GetForeignAssetAddress = "getForeignAssetAddress(uint16)",
+	GetAddressByLocation = "getAddressByLocation(bytes)",
+}
+
+impl<Runtime> Precompile for AssetRegistryPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_asset_registry::Config,
+	BalanceOf<Runtime>: Into<U256>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			<Runtime as module_evm::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetAssetMetadata => {
+				let currency_id = input.currency_id_at(1)?;
+				let metadata = asset_ids_of(currency_id)
+					.and_then(AssetIdMaps::<Runtime>::get_asset_metadata)
+					.ok_or_else(|| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "asset metadata not found".into(),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: ethabi::encode(&[
+						Token::Bytes(metadata.name),
+						Token::Bytes(metadata.symbol),
+						Token::Uint(metadata.decimals.into()),
+						Token::Uint(metadata.minimal_balance.into()),
+					]),
+				})
+			}
+			Action::GetForeignAssetLocation => {
+				let foreign_asset_id: ForeignAssetId =
+					input.u32_at(1)?.try_into().map_err(|_| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "failed to convert uint256 into u16".into(),
+					})?;
+				// returns empty bytes if the foreign asset is not registered
+				let location = AssetIdMaps::<Runtime>::get_location(foreign_asset_id)
+					.map(|location| VersionedLocation::V4(location).encode())
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&location),
+				})
+			}
+			Action::GetForeignAssetAddress => {
+				let foreign_asset_id: ForeignAssetId =
+					input.u32_at(1)?.try_into().map_err(|_| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "failed to convert uint256 into u16".into(),
+					})?;
+				// returns zero address if the foreign asset is not registered
+				let address = AssetIdMaps::<Runtime>::get_location(foreign_asset_id)
+					.and_then(|_| {
+						<Runtime as module_evm::Config>::Erc20InfoMapping::encode_evm_address(CurrencyId::ForeignAsset(
+							foreign_asset_id,
+						))
+					})
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_address(address),
+				})
+			}
+			Action::GetAddressByLocation => {
+				let location = input.bytes_at(1)?;
+				let location = VersionedLocation::decode(&mut &location[..])
+					.ok()
+					.and_then(|location| Location::try_from(location).ok())
+					.ok_or_else(|| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "invalid location".into(),
+					})?;
+
+				// returns zero address if the location is not registered
+				let address = AssetIdMaps::<Runtime>::get_currency_id(location)
+					.and_then(<Runtime as module_evm::Config>::Erc20InfoMapping::encode_evm_address)
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_address(address),
+				})
+			}
+		}
+	}
+}
+
+fn asset_ids_of(currency_id: CurrencyId) -> Option<AssetIds> {
+	match currency_id {
+		CurrencyId::Token(_) | CurrencyId::LiquidCrowdloan(_) => Some(AssetIds::NativeAssetId(currency_id)),
+		CurrencyId::Erc20(address) => Some(AssetIds::Erc20(address)),
+		CurrencyId::StableAssetPoolToken(stable_asset_id) => Some(AssetIds::StableAssetId(stable_asset_id)),
+		CurrencyId::ForeignAsset(foreign_asset_id) => Some(AssetIds::ForeignAssetId(foreign_asset_id)),
+		CurrencyId::DexShare(..) => None,
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_asset_registry::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			<Runtime as module_evm::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::GetAssetMetadata => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// AssetRegistry::AssetMetadatas (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetForeignAssetLocation | Action::GetForeignAssetAddress => {
+				// AssetRegistry::ForeignAssetLocations (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				WeightToGas::convert(weight)
+			}
+			Action::GetAddressByLocation => {
+				// AssetRegistry::LocationToCurrencyIds (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				WeightToGas::convert(weight)
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{alice_evm_addr, new_test_ext, AssetRegistry, CouncilAccount, RuntimeOrigin, Test};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use primitives::currency::AssetMetadata;
+	use xcm::v4::Junction::Parachain;
+
+	type AssetRegistryPrecompile = super::AssetRegistryPrecompile<Test>;
+
+	#[test]
+	fn get_asset_metadata_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getAssetMetadata(address) -> 0x33f12ab8
+			// ACA
+			let input = hex! {"
+				33f12ab8
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+
+			// offset of name
+			// offset of symbol
+			// decimals
+			// minimal_balance
+			// name
+			// symbol
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 000000000000000000000000000000c0
+				00000000000000000000000000000000 0000000000000000000000000000000c
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000005
+				4163616c610000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000003
+				41434100000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// getAssetMetadata(address) -> 0x33f12ab8
+			// ForeignAsset(0)
+			let input = hex! {"
+				33f12ab8
+				000000000000000000000000 0000000000000000000500000000000000000000
+			"};
+
+			assert_noop!(
+				AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "asset metadata not found".into(),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn foreign_asset_location_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getForeignAssetLocation(uint16) -> 0x33cda95b
+			// foreign_asset_id
+			let location_input = hex! {"
+				33cda95b
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// getForeignAssetAddress(uint16) -> 0xcf5d419a
+			// foreign_asset_id
+			let address_input = hex! {"
+				cf5d419a
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// getAddressByLocation(bytes) -> 0x8743ea50
+			// offset
+			// length
+			// VersionedLocation::V4(Location::new(1, [Parachain(2000)]))
+			let by_location_input = hex! {"
+				8743ea50
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000006
+				04010100411f00000000000000000000 00000000000000000000000000000000
+			"};
+
+			// empty bytes
+			let empty_bytes_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let zero_address_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(
+				&location_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, empty_bytes_output.to_vec());
+
+			for input in [&address_input[..], &by_location_input[..]] {
+				let resp =
+					AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(input, None, &context, false))
+						.unwrap();
+				assert_eq!(resp.exit_status, ExitSucceed::Returned);
+				assert_eq!(resp.output, zero_address_output.to_vec());
+			}
+
+			let location = Location::new(1, [Parachain(2000)]);
+			assert_eq!(
+				VersionedLocation::V4(location.clone()).encode(),
+				hex!("04010100411f").to_vec()
+			);
+			assert_ok!(AssetRegistry::register_foreign_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				Box::new(VersionedLocation::V4(location)),
+				Box::new(AssetMetadata {
+					name: b"Token Name".to_vec(),
+					symbol: b"TN".to_vec(),
+					decimals: 12,
+					minimal_balance: 1,
+				})
+			));
+
+			// SCALE encoded location
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000006
+				04010100411f00000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(
+				&location_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// ForeignAsset(0)
+			let expected_output = hex! {"
+				000000000000000000000000 0000000000000000000500000000000000000000
+			"};
+
+			for input in [&address_input[..], &by_location_input[..]] {
+				let resp =
+					AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(input, None, &context, false))
+						.unwrap();
+				assert_eq!(resp.exit_status, ExitSucceed::Returned);
+				assert_eq!(resp.output, expected_output.to_vec());
+			}
+
+			// getAddressByLocation(bytes) -> 0x8743ea50
+			// offset
+			// length
+			// invalid location
+			let input = hex! {"
+				8743ea50
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				ff000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			assert_noop!(
+				AssetRegistryPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid location".into(),
+				}
+			);
+		});
+	}
+}
@@ -24,7 +24,7 @@ use module_evm::{
 	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
 	PrecompileResult,
 };
-use module_support::{DEXBootstrap, DEXManager, SwapLimit};
+use module_support::{DEXBootstrap, DEXManager, InvokeContext, SwapLimit};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{traits::Convert, RuntimeDebug};
@@ -37,8 +37,13 @@ use sp_std::{marker::PhantomData, prelude::*};
 ///
 /// Actions:
 /// - Get liquidity. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
+/// - Get exchange fee. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
 /// - Swap with exact supply. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
 ///   `supply_amount`, `min_target_amount`.
+/// - Flash swap. Rest `input` bytes: `who`, `supply_currency_id`, `target_currency_id`,
+///   `target_amount`, `max_supply_amount`, `gas_limit`, `data`. The target amount is sent to `who`
+///   first, then `onFlashSwap(address,address,uint256,uint256,bytes)` of `who` is called with
+///   `gas_limit`, and the supply amount is taken from `who` at the end.
 pub struct DEXPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -59,6 +64,8 @@ pub enum Action {
 	AddProvision = "addProvision(address,address,address,uint256,uint256)",
 	ClaimDexShare = "claimDexShare(address,address,address)",
 	RefundProvision = "refundProvision(address,address,address)",
+	GetExchangeFee = "getExchangeFee(address,address)",
+	FlashSwap = "flashSwap(address,address,address,uint256,uint256,uint256,bytes)",
 }
 
 impl<Runtime> Precompile for DEXPrecompile<Runtime>
@@ -438,6 +445,66 @@ where
 					output: vec![],
 				})
 			}
+			Action::GetExchangeFee => {
+				let currency_id_a = input.currency_id_at(1)?;
+				let currency_id_b = input.currency_id_at(2)?;
+				log::debug!(
+					target: "evm",
+					"dex: get_exchange_fee currency_id_a: {:?}, currency_id_b: {:?}",
+					currency_id_a, currency_id_b
+				);
+
+				let (fee_numerator, fee_denominator) = <module_dex::Pallet<Runtime> as DEXManager<
+					Runtime::AccountId,
+					Balance,
+					CurrencyId,
+				>>::get_exchange_fee(currency_id_a, currency_id_b);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_tuple(vec![fee_numerator, fee_denominator]),
+				})
+			}
+			Action::FlashSwap => {
+				let who = input.account_id_at(1)?;
+				let receiver = input.evm_address_at(1)?;
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				let target_amount = input.balance_at(4)?;
+				let max_supply_amount = input.balance_at(5)?;
+				let gas_limit = input.u64_at(6)?;
+				let data = input.bytes_at(7)?;
+				log::debug!(
+					target: "evm",
+					"dex: flash_swap who: {:?}, supply_currency_id: {:?}, target_currency_id: {:?}, target_amount: {:?}, max_supply_amount: {:?}, gas_limit: {:?}",
+					who, supply_currency_id, target_currency_id, target_amount, max_supply_amount, gas_limit
+				);
+
+				let context = InvokeContext {
+					contract: receiver,
+					sender: handle.code_address(),
+					origin: receiver,
+				};
+				let supply_amount = module_dex::Pallet::<Runtime>::do_flash_swap(
+					&who,
+					context,
+					supply_currency_id,
+					target_currency_id,
+					target_amount,
+					max_supply_amount,
+					data,
+					gas_limit,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Into::<&str>::into(e).as_bytes().to_vec(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(supply_amount),
+				})
+			}
 		}
 	}
 }
@@ -493,15 +560,25 @@ where
 				let path_len = input.u32_at(3)?;
 
 				let mut read_currency = 0u64;
+				let mut path = vec![];
 				for i in 0..path_len {
 					let currency_id = input.currency_id_at((4 + i) as usize)?;
 					read_currency += InputPricer::<Runtime>::read_currency(currency_id);
+					path.push(currency_id);
 				}
+				let concentrated_ticks = module_dex::Pallet::<Runtime>::concentrated_swap_ticks_of_path(&path);
 
 				// DEX::TradingPairStatuses (r: 1 * (path_len - 1))
 				// DEX::LiquidityPool (r: 1 * (path_len - 1))
-				let weight = <Runtime as frame_system::Config>::DbWeight::get()
-					.reads(path_len.saturating_sub(1).saturating_mul(2).into());
+				// DEX::ConcentratedPools (r: 1 * (path_len - 1))
+				// DEX::ConcentratedTicks (r: concentrated_ticks)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(
+					path_len
+						.saturating_sub(1)
+						.saturating_mul(3)
+						.saturating_add(concentrated_ticks)
+						.into(),
+				);
 
 				Self::BASE_COST
 					.saturating_add(read_currency)
@@ -511,15 +588,25 @@ where
 				let path_len = input.u32_at(3)?;
 
 				let mut read_currency = 0u64;
+				let mut path = vec![];
 				for i in 0..path_len {
 					let currency_id = input.currency_id_at((4 + i) as usize)?;
 					read_currency += InputPricer::<Runtime>::read_currency(currency_id);
+					path.push(currency_id);
 				}
+				let concentrated_ticks = module_dex::Pallet::<Runtime>::concentrated_swap_ticks_of_path(&path);
 
 				// DEX::TradingPairStatuses (r: 1 * (path_len - 1))
 				// DEX::LiquidityPool (r: 1 * (path_len - 1))
-				let weight = <Runtime as frame_system::Config>::DbWeight::get()
-					.reads(path_len.saturating_sub(1).saturating_mul(2).into());
+				// DEX::ConcentratedPools (r: 1 * (path_len - 1))
+				// DEX::ConcentratedTicks (r: concentrated_ticks)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(
+					path_len
+						.saturating_sub(1)
+						.saturating_mul(3)
+						.saturating_add(concentrated_ticks)
+						.into(),
+				);
 
 				Self::BASE_COST
 					.saturating_add(read_currency)
@@ -529,14 +616,23 @@ where
 				let path_len = input.u32_at(5)?;
 
 				let mut read_currency = 0u64;
+				let mut path = vec![];
 				for i in 0..path_len {
 					let currency_id = input.currency_id_at((6 + i) as usize)?;
 					read_currency += InputPricer::<Runtime>::read_currency(currency_id);
+					path.push(currency_id);
 				}
 
 				let read_account = InputPricer::<Runtime>::read_accounts(1);
 
-				let weight = <Runtime as module_dex::Config>::WeightInfo::swap_with_exact_supply(path_len);
+				// the swap can't be refunded, so charge the maximum ticks of the concentrated
+				// liquidity pools on the path
+				let concentrated_ticks = module_dex::Pallet::<Runtime>::concentrated_swap_ticks_of_path(&path);
+				// DEX::ConcentratedPools (r: 1 * (path_len - 1))
+				let weight = module_dex::Pallet::<Runtime>::swap_with_exact_supply_weight(path_len, concentrated_ticks)
+					.saturating_add(
+						<Runtime as frame_system::Config>::DbWeight::get().reads(path_len.saturating_sub(1).into()),
+					);
 
 				Self::BASE_COST
 					.saturating_add(read_currency)
@@ -547,14 +643,23 @@ where
 				let path_len = input.u32_at(5)?;
 
 				let mut read_currency = 0u64;
+				let mut path = vec![];
 				for i in 0..path_len {
 					let currency_id = input.currency_id_at((6 + i) as usize)?;
 					read_currency += InputPricer::<Runtime>::read_currency(currency_id);
+					path.push(currency_id);
 				}
 
 				let read_account = InputPricer::<Runtime>::read_accounts(1);
 
-				let weight = <Runtime as module_dex::Config>::WeightInfo::swap_with_exact_target(path_len);
+				// the swap can't be refunded, so charge the maximum ticks of the concentrated
+				// liquidity pools on the path
+				let concentrated_ticks = module_dex::Pallet::<Runtime>::concentrated_swap_ticks_of_path(&path);
+				// DEX::ConcentratedPools (r: 1 * (path_len - 1))
+				let weight = module_dex::Pallet::<Runtime>::swap_with_exact_target_weight(path_len, concentrated_ticks)
+					.saturating_add(
+						<Runtime as frame_system::Config>::DbWeight::get().reads(path_len.saturating_sub(1).into()),
+					);
 
 				Self::BASE_COST
 					.saturating_add(read_currency)
@@ -685,6 +790,40 @@ where
 					.saturating_add(read_currency_b)
 					.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::GetExchangeFee => {
+				let currency_id_a = input.currency_id_at(1)?;
+				let currency_id_b = input.currency_id_at(2)?;
+				let read_currency_a = InputPricer::<Runtime>::read_currency(currency_id_a);
+				let read_currency_b = InputPricer::<Runtime>::read_currency(currency_id_b);
+
+				// DEX::ExchangeFees (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST
+					.saturating_add(read_currency_a)
+					.saturating_add(read_currency_b)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::FlashSwap => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				// the callback is charged by its gas limit upfront
+				let gas_limit = input.u64_at(6)?;
+
+				let read_supply_currency = InputPricer::<Runtime>::read_currency(supply_currency_id);
+				let read_target_currency = InputPricer::<Runtime>::read_currency(target_currency_id);
+
+				// same storage access as swapping on a single trading pair
+				let weight = <Runtime as module_dex::Config>::WeightInfo::swap_with_exact_target(2);
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_supply_currency)
+					.saturating_add(read_target_currency)
+					.saturating_add(WeightToGas::convert(weight))
+					.saturating_add(gas_limit)
+			}
 		};
 		Ok(cost)
 	}
@@ -695,11 +834,13 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		alice, alice_evm_addr, new_test_ext, run_to_block, Currencies, DexModule, RuntimeOrigin, Test, ALICE, AUSD, DOT,
+		alice, alice_evm_addr, new_test_ext, run_to_block, Currencies, DexModule, RuntimeOrigin, Test, Tokens, ALICE,
+		AUSD, DOT,
 	};
 	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context, ExitRevert};
+	use orml_traits::MultiCurrency;
 
 	type DEXPrecompile = crate::DEXPrecompile<Test>;
 
@@ -747,6 +888,53 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn get_exchange_fee_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getExchangeFee(address,address) -> 0x02cd5bef
+			// AUSD
+			// DOT
+			let input = hex! {"
+				02cd5bef
+				000000000000000000000000 0000000000000000000100000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// default fee: 1 / 100
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000064
+			"};
+
+			let resp = DEXPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			assert_ok!(DexModule::update_exchange_fee(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				DOT,
+				Some((1, 1000))
+			));
+
+			// 1 / 1000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000003e8
+			"};
+
+			let resp = DEXPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
 	#[test]
 	fn get_liquidity_token_address_works() {
 		new_test_ext().execute_with(|| {
@@ -1006,6 +1194,102 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn flash_swap_works() {
+		new_test_ext().execute_with(|| {
+			// enable DOT/AUSD
+			assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AUSD,
+				1_000,
+				1_000_000,
+				0,
+				true
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// flashSwap(address,address,address,uint256,uint256,uint256,bytes) -> 0x8e04ad43
+			// who
+			// supply_currency_id DOT
+			// target_currency_id AUSD
+			// target_amount
+			// max_supply_amount
+			// gas_limit
+			// offset
+			// data_len
+			let input = hex! {"
+				8e04ad43
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 000000000000000000000000000000e0
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			assert_noop!(
+				DEXPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "ExcessiveSupplyAmount".into(),
+				}
+			);
+
+			// who
+			// supply_currency_id DOT
+			// target_currency_id AUSD
+			// target_amount 10_000
+			// max_supply_amount 100
+			// gas_limit 100_000
+			// offset
+			// data_len
+			// data
+			let input = hex! {"
+				8e04ad43
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000002710
+				00000000000000000000000000000000 00000000000000000000000000000064
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 000000000000000000000000000000e0
+				00000000000000000000000000000000 00000000000000000000000000000003
+				01020300000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// supply_amount 11
+			let expected_output = hex! {"
+				00000000000000000000000000000000 0000000000000000000000000000000b
+			"};
+
+			let dot_balance = Tokens::free_balance(DOT, &alice());
+			let ausd_balance = Tokens::free_balance(AUSD, &alice());
+
+			// `who` has no code, so the callback succeeds and the supply amount is taken from
+			// the balance it holds
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = DEXPrecompile::execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+			// the gas limit of the callback is charged
+			assert!(handle.gas_used > 100_000);
+
+			assert_eq!(Tokens::free_balance(DOT, &alice()), dot_balance - 11);
+			assert_eq!(Tokens::free_balance(AUSD, &alice()), ausd_balance + 10_000);
+			assert_eq!(DexModule::get_liquidity_pool(DOT, AUSD), (1_011, 990_000));
+		});
+	}
+
 	#[test]
 	fn get_provision_pool_works() {
 		new_test_ext().execute_with(|| {
@@ -18,18 +18,19 @@
 
 use super::input::{Input, InputPricer, InputT, Output};
 use crate::WeightToGas;
-use frame_support::traits::Get;
+use frame_support::traits::{Currency, Get};
 use module_evm::{
 	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
 	PrecompileResult,
 };
-use module_support::EarningManager;
+use module_support::{EarningManager, IncentivesManager, PoolId};
 
 use ethabi::Token;
 use frame_system::pallet_prelude::BlockNumberFor;
 use module_earning::{BondingLedgerOf, WeightInfo};
+use module_incentives::WeightInfo as _;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use primitives::Balance;
+use primitives::{Balance, CurrencyId};
 use sp_core::U256;
 use sp_runtime::{
 	traits::{Convert, Zero},
@@ -51,6 +52,9 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Get minimum bond amount.
 /// - Get unbonding period.
 /// - Get maximum unbonding chunks amount.
+/// - Get pending rewards of earning pool. `input` bytes: `who`, `reward_currencies`.
+/// - Claim rewards of earning pool. `input` bytes: `who`.
+/// - Compound, claim rewards of earning pool and bond the native rewards. `input` bytes: `who`.
 
 pub struct EarningPrecompile<R>(PhantomData<R>);
 
@@ -68,11 +72,14 @@ pub enum Action {
 	GetMinBond = "getMinBond()",
 	GetUnbondingPeriod = "getUnbondingPeriod()",
 	GetMaxUnbondingChunks = "getMaxUnbondingChunks()",
+	GetPendingRewards = "getPendingRewards(address,address[])",
+	ClaimRewards = "claimRewards(address)",
+	Compound = "compound(address)",
 }
 
 impl<Runtime> Precompile for EarningPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_earning::Config + module_prices::Config,
+	Runtime: module_evm::Config + module_earning::Config + module_incentives::Config + module_prices::Config,
 	module_earning::Pallet<Runtime>: EarningManager<
 		Runtime::AccountId,
 		Balance,
@@ -80,6 +87,7 @@ where
 		FeeRatio = Permill,
 		Moment = BlockNumberFor<Runtime>,
 	>,
+	module_incentives::Pallet<Runtime>: IncentivesManager<Runtime::AccountId, Balance, CurrencyId, PoolId>,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		let gas_cost = Pricer::<Runtime>::cost(handle)?;
@@ -263,15 +271,106 @@ where
 					output: Output::encode_uint(amount),
 				})
 			}
+			Action::GetPendingRewards => {
+				let who = input.account_id_at(1)?;
+				// solidity abi encode array will add an offset at input[2]
+				let reward_currency_ids_len = input.u32_at(3)?;
+				let mut reward_currency_ids = vec![];
+				for i in 0..reward_currency_ids_len {
+					reward_currency_ids.push(input.currency_id_at((4 + i) as usize)?);
+				}
+
+				let value = <module_incentives::Pallet<Runtime> as IncentivesManager<
+					Runtime::AccountId,
+					Balance,
+					CurrencyId,
+					PoolId,
+				>>::get_claimable_rewards(earning_pool_id::<Runtime>(), who, reward_currency_ids);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_array(value),
+				})
+			}
+			Action::ClaimRewards => {
+				let who = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"earning: claim_rewards, who: {:?}",
+					&who
+				);
+
+				<module_incentives::Pallet<Runtime> as IncentivesManager<
+					Runtime::AccountId,
+					Balance,
+					CurrencyId,
+					PoolId,
+				>>::claim_rewards(who, earning_pool_id::<Runtime>())
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Earning claimRewards failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::Compound => {
+				let who = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"earning: compound, who: {:?}",
+					&who
+				);
+
+				let free_balance = <Runtime as module_earning::Config>::Currency::free_balance(&who);
+
+				<module_incentives::Pallet<Runtime> as IncentivesManager<
+					Runtime::AccountId,
+					Balance,
+					CurrencyId,
+					PoolId,
+				>>::claim_rewards(who.clone(), earning_pool_id::<Runtime>())
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Earning compound failed", e),
+				})?;
+
+				// only the claimed native rewards can be bonded
+				let rewards =
+					<Runtime as module_earning::Config>::Currency::free_balance(&who).saturating_sub(free_balance);
+				let bonded_amount = if rewards.is_zero() {
+					Zero::zero()
+				} else {
+					<module_earning::Pallet<Runtime> as EarningManager<_, _, _>>::bond(who, rewards).map_err(|e| {
+						PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("Earning compound failed", e),
+						}
+					})?
+				};
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(bonded_amount),
+				})
+			}
 		}
 	}
 }
 
+fn earning_pool_id<Runtime: module_incentives::Config>() -> PoolId {
+	PoolId::Earning(<Runtime as module_incentives::Config>::NativeCurrencyId::get())
+}
+
 struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
 where
-	Runtime: module_evm::Config + module_earning::Config + module_prices::Config,
+	Runtime: module_evm::Config + module_earning::Config + module_incentives::Config + module_prices::Config,
 {
 	const BASE_COST: u64 = 200;
 
@@ -332,6 +431,36 @@ where
 				// Runtime Config
 				Default::default()
 			}
+			Action::GetPendingRewards => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let reward_currency_ids_len = input.u32_at(3)?;
+				let mut read_currency = 0u64;
+				for i in 0..reward_currency_ids_len {
+					let currency_id = input.currency_id_at((4 + i) as usize)?;
+					read_currency = read_currency.saturating_add(InputPricer::<Runtime>::read_currency(currency_id));
+				}
+
+				// Incentives::PendingMultiRewards (r: 1)
+				// Rewards::PoolInfos (r: 1)
+				// Rewards::SharesAndWithdrawnRewards (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(3);
+
+				cost.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::ClaimRewards => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_incentives::Config>::WeightInfo::claim_rewards();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Compound => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_incentives::Config>::WeightInfo::claim_rewards()
+					.saturating_add(<Runtime as module_earning::Config>::WeightInfo::bond());
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(Self::BASE_COST.saturating_add(cost))
 	}
@@ -341,7 +470,8 @@ where
 mod tests {
 	use super::*;
 	use crate::precompile::mock::{
-		alice, alice_evm_addr, new_test_ext, Currencies, Earning, RuntimeOrigin, System, Test, UnbondingPeriod, ACA,
+		alice, alice_evm_addr, new_test_ext, Currencies, Earning, Incentives, Rewards, RuntimeOrigin, System, Test,
+		Tokens, UnbondingPeriod, ACA, AUSD,
 	};
 	use frame_support::assert_ok;
 	use hex_literal::hex;
@@ -688,4 +818,109 @@ mod tests {
 			assert_eq!(res.output, expected_output);
 		});
 	}
+
+	#[test]
+	fn pending_rewards_and_compound_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				ACA,
+				99_000_000_000_000
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				Incentives::account_id(),
+				ACA,
+				1_000_000_000_000
+			));
+			assert_ok!(Tokens::deposit(AUSD, &Incentives::account_id(), 1_000_000));
+			assert_ok!(Earning::bond(RuntimeOrigin::signed(alice()), 20_000_000_000_000));
+
+			assert_ok!(Rewards::accumulate_reward(&PoolId::Earning(ACA), ACA, 1_000_000_000));
+			assert_ok!(Rewards::accumulate_reward(&PoolId::Earning(ACA), AUSD, 500));
+
+			// getPendingRewards(address,address[]) -> 0x35ed32b5
+			// who 0x1000000000000000000000000000000000000001
+			// offset
+			// reward_currency_ids_len
+			// ACA
+			// AUSD
+			let input = hex! {"
+				35ed32b5
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000000
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// encoded array of [1_000_000_000, 500]
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+				00000000000000000000000000000000 000000000000000000000000000001f4
+			"};
+
+			let res =
+				EarningPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			let ausd_balance = Tokens::free_balance(AUSD, &alice());
+
+			// compound(address) -> 0x284dac23
+			// who 0x1000000000000000000000000000000000000001
+			let compound_input = hex! {"
+				284dac23
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let res =
+				EarningPrecompile::execute(&mut MockPrecompileHandle::new(&compound_input, None, &context, false))
+					.unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			// encoded value of 1_000_000_000
+			let expected_output = hex! {"00000000000000000000000000000000 0000000000000000000000003b9aca00"}.to_vec();
+			assert_eq!(res.output, expected_output);
+			assert_eq!(Earning::ledger(&alice()).unwrap().active(), 20_001_000_000_000);
+			assert_eq!(Tokens::free_balance(AUSD, &alice()), ausd_balance + 500);
+
+			// encoded array of [0, 0]
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let res =
+				EarningPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			assert_ok!(Rewards::accumulate_reward(&PoolId::Earning(ACA), AUSD, 300));
+
+			// claimRewards(address) -> 0xef5cfb8c
+			// who 0x1000000000000000000000000000000000000001
+			let input = hex! {"
+				ef5cfb8c
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let res =
+				EarningPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Tokens::free_balance(AUSD, &alice()), ausd_balance + 800);
+			assert_eq!(Earning::ledger(&alice()).unwrap().active(), 20_001_000_000_000);
+		});
+	}
 }
@@ -42,6 +42,12 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Get estimated reward rate.
 /// - Get commission rate.
 /// - Get fast match fee.
+/// - Claim redemption. Rest `input` bytes: `redeemer`.
+/// - Fast match redeems. Rest `input` bytes: `redeemer_list`.
+/// - Fast match redeems completely. Rest `input` bytes: `redeemer_list`.
+/// - Get redeem request. Rest `input` bytes: `who`.
+/// - Get unbondings. Rest `input` bytes: `who`.
+/// - Get current era.
 
 pub struct HomaPrecompile<R>(PhantomData<R>);
 
@@ -55,6 +61,12 @@ pub enum Action {
 	GetEstimatedRewardRate = "getEstimatedRewardRate()",
 	GetCommissionRate = "getCommissionRate()",
 	GetFastMatchFee = "getFastMatchFee()",
+	ClaimRedemption = "claimRedemption(address)",
+	FastMatchRedeems = "fastMatchRedeems(address[])",
+	FastMatchRedeemsCompletely = "fastMatchRedeemsCompletely(address[])",
+	GetRedeemRequest = "getRedeemRequest(address)",
+	GetUnbondings = "getUnbondings(address)",
+	GetCurrentEra = "getCurrentEra()",
 }
 
 impl<Runtime> Precompile for HomaPrecompile<Runtime>
@@ -153,6 +165,90 @@ where
 					output: Output::encode_uint(rate.into_inner()),
 				})
 			}
+			Action::ClaimRedemption => {
+				let redeemer = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"homa: claim_redemption, redeemer: {:?}",
+					&redeemer
+				);
+
+				<module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::claim_redemption(redeemer)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Homa ClaimRedemption failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::FastMatchRedeems | Action::FastMatchRedeemsCompletely => {
+				let allow_partially = action == Action::FastMatchRedeems;
+				let redeemer_len = input.u32_at(2)?;
+				let mut redeemer_list = vec![];
+				for i in 0..redeemer_len {
+					redeemer_list.push(input.account_id_at((3 + i) as usize)?);
+				}
+
+				log::debug!(
+					target: "evm",
+					"homa: fast_match_redeems, redeemer_list: {:?}, allow_partially: {:?}",
+					&redeemer_list, allow_partially
+				);
+
+				<module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::fast_match_redeems(
+					redeemer_list,
+					allow_partially,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Homa FastMatchRedeems failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::GetRedeemRequest => {
+				let who = input.account_id_at(1)?;
+				let (amount, allow_fast_match) = <module_homa::Pallet<Runtime> as HomaManager<
+					Runtime::AccountId,
+					Balance,
+				>>::get_redeem_request(&who)
+				.unwrap_or_default();
+
+				// `bool` is abi encoded the same as `uint256` of 0 or 1.
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_tuple(vec![amount, Balance::from(allow_fast_match)]),
+				})
+			}
+			Action::GetUnbondings => {
+				let who = input.account_id_at(1)?;
+				let (era_indexes, amounts): (Vec<Balance>, Vec<Balance>) =
+					<module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::get_unbondings(&who)
+						.into_iter()
+						.map(|(era_index, amount)| (Balance::from(era_index), amount))
+						.unzip();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_arrays(vec![era_indexes, amounts]),
+				})
+			}
+			Action::GetCurrentEra => {
+				let era_index =
+					<module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::get_relay_chain_current_era(
+					);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(era_index),
+				})
+			}
 		}
 	}
 }
@@ -203,6 +299,41 @@ where
 				// Homa::FastMatchFeeRate (r: 1)
 				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
 			}
+			Action::ClaimRedemption => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_homa::Config>::WeightInfo::claim_redemption();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::FastMatchRedeems | Action::FastMatchRedeemsCompletely => {
+				let redeemer_len = input.u32_at(2)?;
+				let cost = InputPricer::<Runtime>::read_accounts(redeemer_len.into());
+				let weight = <Runtime as module_homa::Config>::WeightInfo::fast_match_redeems(redeemer_len);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetRedeemRequest => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Homa::RedeemRequests (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetUnbondings => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Homa::Unbondings (r: BondingDuration + 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(
+					<Runtime as module_homa::Config>::BondingDuration::get()
+						.saturating_add(1)
+						.into(),
+				);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetCurrentEra => {
+				// Homa::RelayChainCurrentEra (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
 		};
 		Ok(Self::BASE_COST.saturating_add(cost))
 	}
@@ -214,9 +345,10 @@ mod tests {
 	use crate::precompile::mock::{
 		alice, alice_evm_addr, new_test_ext, Currencies, Homa, HomaAdmin, RuntimeOrigin, StakingCurrencyId, Test, ACA,
 	};
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use orml_traits::MultiCurrency;
 	use sp_runtime::{FixedPointNumber, FixedU128};
 
 	type HomaPrecompile = super::HomaPrecompile<Test>;
@@ -431,4 +563,240 @@ mod tests {
 			assert_eq!(res.output, expected_output);
 		});
 	}
+
+	#[test]
+	fn get_redeem_request_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000_000_000),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				None,
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				StakingCurrencyId::get(),
+				1_000_000_000_000
+			));
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(alice()), 1_000_000_000));
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(alice()), 707_072, true));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getRedeemRequest(address) => 0x3c05e18f
+			// who
+			let input = hex! {"
+				3c05e18f
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			// amount
+			// allow_fast_match
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000aca00
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn fast_match_redeems_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000_000_000),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				None,
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				StakingCurrencyId::get(),
+				1_000_000_000_000
+			));
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(alice()), 1_000_000_000));
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(alice()), 707_072, true));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// keep the exchange rate but leave only a little staking currency in ToBondPool,
+			// so the redeem request can only be matched partially
+			module_homa::ToBondPool::<Test>::put(10);
+			module_homa::TotalStakingBonded::<Test>::put(999_999_990);
+
+			// fastMatchRedeems(address[]) => 0xf3d71053
+			// offset of redeemer list
+			// redeemer list: [alice]
+			let input = hex! {"
+				f3d71053
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			let (remainder, allow_fast_match) = Homa::redeem_requests(alice()).unwrap();
+			assert!(remainder > 0 && remainder < 707_072);
+			assert!(allow_fast_match);
+			assert!(Homa::to_bond_pool() < 10);
+
+			// the remaining request is fully matched once ToBondPool is enough
+			module_homa::ToBondPool::<Test>::put(999_999_990);
+			module_homa::TotalStakingBonded::<Test>::put(10);
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Homa::redeem_requests(alice()), None);
+		});
+	}
+
+	#[test]
+	fn fast_match_redeems_completely_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000_000_000),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				None,
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				StakingCurrencyId::get(),
+				1_000_000_000_000
+			));
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(alice()), 1_000_000_000));
+			assert_ok!(Homa::request_redeem(RuntimeOrigin::signed(alice()), 707_072, true));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			module_homa::ToBondPool::<Test>::put(10);
+			module_homa::TotalStakingBonded::<Test>::put(999_999_990);
+
+			// fastMatchRedeemsCompletely(address[]) => 0xedbe1651
+			// offset of redeemer list
+			// redeemer list: [alice]
+			let input = hex! {"
+				edbe1651
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			assert_noop!(
+				HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Homa FastMatchRedeems failed: CannotCompletelyFastMatch".into(),
+				}
+			);
+
+			module_homa::ToBondPool::<Test>::put(999_999_990);
+			module_homa::TotalStakingBonded::<Test>::put(10);
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Homa::redeem_requests(alice()), None);
+		});
+	}
+
+	#[test]
+	fn unbondings_and_claim_redemption_works() {
+		new_test_ext().execute_with(|| {
+			module_homa::Unbondings::<Test>::insert(alice(), 1, 1_000_000);
+			module_homa::Unbondings::<Test>::insert(alice(), 5, 2_000_000);
+			module_homa::UnclaimedRedemption::<Test>::put(3_000_000);
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				Homa::account_id(),
+				StakingCurrencyId::get(),
+				3_000_000
+			));
+			assert_ok!(Homa::reset_current_era(RuntimeOrigin::signed(HomaAdmin::get()), 1));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getCurrentEra() => 0xd250b6cb
+			let input = hex! {"d250b6cb"};
+
+			let expected_output = hex! {"00000000000000000000000000000000 00000000000000000000000000000001"}.to_vec();
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output);
+
+			// getUnbondings(address) => 0x43a1b9e8
+			// who
+			let input = hex! {"
+				43a1b9e8
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			// offset of era indexes
+			// offset of amounts
+			// era indexes: [1, 5]
+			// amounts: [1_000_000, 2_000_000]
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 000000000000000000000000000000a0
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000005
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 000000000000000000000000001e8480
+			"};
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// claimRedemption(address) => 0xc0598b0d
+			// redeemer
+			let input = hex! {"
+				c0598b0d
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let res = HomaPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			assert_eq!(
+				Currencies::free_balance(StakingCurrencyId::get(), &alice()),
+				1_001_000_000
+			);
+			assert_eq!(Homa::unbondings(alice(), 1), 0);
+			assert_eq!(Homa::unbondings(alice(), 5), 2_000_000);
+			assert_eq!(Homa::unclaimed_redemption(), 2_000_000);
+		});
+	}
 }
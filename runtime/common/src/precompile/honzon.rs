@@ -41,6 +41,15 @@ use sp_std::{marker::PhantomData, prelude::*};
 ///  - Get position. `input` bytes: `who`, `currency_id`.
 ///  - Get liquidation ratio. `input` bytes: `currency_id`.
 ///  - Get current collateral ratio. `input` bytes: `who`, `currency_id`.
+///  - Expand position collateral. `input` bytes: `who`, `currency_id`, `increase_debit_value`,
+///    `min_increase_collateral`.
+///  - Shrink position debit. `input` bytes: `who`, `currency_id`, `decrease_collateral`,
+///    `min_decrease_debit_value`.
+///  - Adjust loan by debit value. `input` bytes: `who`, `currency_id`, `collateral_adjustment`,
+///    `debit_value_adjustment`.
+///  - Transfer debit. `input` bytes: `who`, `from_currency_id`, `to_currency_id`, `debit_transfer`.
+///  - Authorize. `input` bytes: `from`, `currency_id`, `to`.
+///  - Unauthorize. `input` bytes: `from`, `currency_id`, `to`.
 pub struct HonzonPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -53,6 +62,12 @@ pub enum Action {
 	GetCollateralParameters = "getCollateralParameters(address)",
 	GetCurrentCollateralRatio = "getCurrentCollateralRatio(address,address)",
 	GetDebitExchangeRate = "getDebitExchangeRate(address)",
+	ExpandPositionCollateral = "expandPositionCollateral(address,address,uint256,uint256)",
+	ShrinkPositionDebit = "shrinkPositionDebit(address,address,uint256,uint256)",
+	AdjustLoanByDebitValue = "adjustLoanByDebitValue(address,address,int128,int128)",
+	TransferDebit = "transferDebit(address,address,address,uint256)",
+	Authorize = "authorize(address,address,address)",
+	Unauthorize = "unauthorize(address,address,address)",
 }
 
 impl<Runtime> Precompile for HonzonPrecompile<Runtime>
@@ -188,6 +203,172 @@ where
 					output: Output::encode_uint(exchange_rate.into_inner()),
 				})
 			}
+			Action::ExpandPositionCollateral => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let increase_debit_value = input.balance_at(3)?;
+				let min_increase_collateral = input.balance_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: expand_position_collateral who: {:?}, currency_id: {:?}, increase_debit_value: {:?}, min_increase_collateral: {:?}",
+					who, currency_id, increase_debit_value, min_increase_collateral
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::expand_position_collateral(&who, currency_id, increase_debit_value, min_increase_collateral)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon ExpandPositionCollateral failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::ShrinkPositionDebit => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let decrease_collateral = input.balance_at(3)?;
+				let min_decrease_debit_value = input.balance_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: shrink_position_debit who: {:?}, currency_id: {:?}, decrease_collateral: {:?}, min_decrease_debit_value: {:?}",
+					who, currency_id, decrease_collateral, min_decrease_debit_value
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::shrink_position_debit(&who, currency_id, decrease_collateral, min_decrease_debit_value)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon ShrinkPositionDebit failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::AdjustLoanByDebitValue => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let collateral_adjustment = input.i128_at(3)?;
+				let debit_value_adjustment = input.i128_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: adjust_loan_by_debit_value who: {:?}, currency_id: {:?}, collateral_adjustment: {:?}, debit_value_adjustment: {:?}",
+					who, currency_id, collateral_adjustment, debit_value_adjustment
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::adjust_loan_by_debit_value(&who, currency_id, collateral_adjustment, debit_value_adjustment)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon AdjustLoanByDebitValue failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::TransferDebit => {
+				let who = input.account_id_at(1)?;
+				let from_currency = input.currency_id_at(2)?;
+				let to_currency = input.currency_id_at(3)?;
+				let debit_transfer = input.balance_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: transfer_debit who: {:?}, from_currency: {:?}, to_currency: {:?}, debit_transfer: {:?}",
+					who, from_currency, to_currency, debit_transfer
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::transfer_debit(&who, from_currency, to_currency, debit_transfer)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon TransferDebit failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::Authorize => {
+				let from = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let to = input.account_id_at(3)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: authorize from: {:?}, currency_id: {:?}, to: {:?}",
+					from, currency_id, to
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::authorize(&from, currency_id, &to)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon Authorize failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::Unauthorize => {
+				let from = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let to = input.account_id_at(3)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: unauthorize from: {:?}, currency_id: {:?}, to: {:?}",
+					from, currency_id, to
+				);
+
+				<module_honzon::Pallet<Runtime> as HonzonManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					Balance,
+				>>::unauthorize(&from, currency_id, &to)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon Unauthorize failed", e),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
 		}
 	}
 }
@@ -271,6 +452,81 @@ where
 					.saturating_add(read_currency)
 					.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::ExpandPositionCollateral => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::expand_position_collateral();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::ShrinkPositionDebit => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::shrink_position_debit();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::AdjustLoanByDebitValue => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::adjust_loan();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::TransferDebit => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let from_currency = input.currency_id_at(2)?;
+				let to_currency = input.currency_id_at(3)?;
+				let read_from_currency = InputPricer::<Runtime>::read_currency(from_currency);
+				let read_to_currency = InputPricer::<Runtime>::read_currency(to_currency);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::transfer_debit();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_from_currency)
+					.saturating_add(read_to_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Authorize => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(2);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::authorize();
+
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Unauthorize => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(2);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::unauthorize();
+
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(cost)
 	}
@@ -281,10 +537,10 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		alice, alice_evm_addr, new_test_ext, CDPEngine, Currencies, DexModule, Honzon, Loans, One, RuntimeOrigin, Test,
-		AUSD, BOB, DOT,
+		alice, alice_evm_addr, bob, new_test_ext, CDPEngine, Currencies, DexModule, Honzon, Loans, One, RuntimeOrigin,
+		Test, AUSD, BOB, DOT, LDOT,
 	};
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
 	use module_support::{Rate, Ratio};
@@ -598,4 +854,330 @@ mod tests {
 			assert_eq!(res.output, expected_output.to_vec());
 		})
 	}
+
+	#[test]
+	fn adjust_loan_by_debit_value_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(CDPEngine::set_collateral_params(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(10000)
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			// adjustLoanByDebitValue(address,address,int128,int128) => 0x11b2c4c9
+			// who
+			// currency_id
+			// collateral_adjustment
+			// debit_value_adjustment
+			let input = hex! {"
+				11b2c4c9
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000010000000
+				00000000000000000000000000000000 00000000000000000000000000001000
+			"};
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Loans::positions(DOT, alice()).collateral, 268435456);
+			// debit exchange rate is one
+			assert_eq!(Loans::positions(DOT, alice()).debit, 4096)
+		})
+	}
+
+	#[test]
+	fn expand_position_collateral_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(CDPEngine::set_collateral_params(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(1_000_000_000)
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+			assert_ok!(Honzon::adjust_loan(
+				RuntimeOrigin::signed(alice()),
+				DOT,
+				100_000_000_000,
+				1_000_000
+			));
+
+			assert_ok!(DexModule::enable_trading_pair(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				AUSD
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				BOB,
+				AUSD,
+				1_000_000_000_000
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				BOB,
+				DOT,
+				1_000_000_000_000
+			));
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				DOT,
+				AUSD,
+				1_000_000_000,
+				1_000_000_000,
+				0,
+				false
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			// expandPositionCollateral(address,address,uint256,uint256) => 0x3ab49d74
+			// who
+			// currency_id
+			// increase_debit_value
+			// min_increase_collateral
+			let input = hex! {"
+				3ab49d74
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			// 1_000_000 aUSD swapped to 989_020 DOT
+			assert_eq!(Loans::positions(DOT, alice()).collateral, 100_000_989_020);
+			assert_eq!(Loans::positions(DOT, alice()).debit, 2_000_000);
+		});
+	}
+
+	#[test]
+	fn shrink_position_debit_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(CDPEngine::set_collateral_params(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(1_000_000_000)
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+			assert_ok!(Honzon::adjust_loan(
+				RuntimeOrigin::signed(alice()),
+				DOT,
+				100_000_000_000,
+				1_000_000
+			));
+
+			assert_ok!(DexModule::enable_trading_pair(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				AUSD
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				BOB,
+				AUSD,
+				1_000_000_000_000
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				BOB,
+				DOT,
+				1_000_000_000_000
+			));
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				DOT,
+				AUSD,
+				1_000_000_000,
+				1_000_000_000,
+				0,
+				false
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			// shrinkPositionDebit(address,address,uint256,uint256) => 0xff23666a
+			// who
+			// currency_id
+			// decrease_collateral
+			// min_decrease_debit_value
+			let input = hex! {"
+				ff23666a
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			// 100_000 DOT swapped to 98_990 aUSD
+			assert_eq!(Loans::positions(DOT, alice()).collateral, 99_999_900_000);
+			assert_eq!(Loans::positions(DOT, alice()).debit, 901_010);
+
+			// decrease more collateral than the CDP has
+			// shrinkPositionDebit(address,address,uint256,uint256) => 0xff23666a
+			// who
+			// currency_id
+			// decrease_collateral
+			// min_decrease_debit_value
+			let input = hex! {"
+				ff23666a
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000010000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			assert_noop!(
+				HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Honzon ShrinkPositionDebit failed: CollateralNotEnough".into(),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_debit_works() {
+		new_test_ext().execute_with(|| {
+			for currency_id in [DOT, LDOT] {
+				assert_ok!(CDPEngine::set_collateral_params(
+					RuntimeOrigin::signed(One::get()),
+					currency_id,
+					Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+					Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+					Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+					Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+					Change::NewValue(10000)
+				));
+				assert_ok!(Currencies::update_balance(
+					RuntimeOrigin::root(),
+					alice(),
+					currency_id,
+					1_000_000_000_000
+				));
+				assert_ok!(Honzon::adjust_loan(
+					RuntimeOrigin::signed(alice()),
+					currency_id,
+					10_000,
+					500
+				));
+			}
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			// transferDebit(address,address,address,uint256) => 0xc11eb064
+			// who
+			// from_currency
+			// to_currency
+			// debit_transfer
+			let input = hex! {"
+				c11eb064
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000003
+				00000000000000000000000000000000 00000000000000000000000000000032
+			"};
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Loans::positions(DOT, alice()).debit, 450);
+			assert_eq!(Loans::positions(DOT, alice()).collateral, 10_000);
+			assert_eq!(Loans::positions(LDOT, alice()).debit, 550);
+			assert_eq!(Loans::positions(LDOT, alice()).collateral, 10_000);
+		});
+	}
+
+	#[test]
+	fn authorize_and_unauthorize_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			// authorize(address,address,address) => 0xc4b2e400
+			// from
+			// currency_id
+			// to
+			let input = hex! {"
+				c4b2e400
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Honzon::authorization(alice(), (DOT, bob())), Some(100));
+
+			// authorize twice
+			assert_noop!(
+				HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Honzon Authorize failed: AlreadyAuthorized".into(),
+				}
+			);
+
+			// unauthorize(address,address,address) => 0xe86d7e5d
+			// from
+			// currency_id
+			// to
+			let input = hex! {"
+				e86d7e5d
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+
+			let res = HonzonPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Honzon::authorization(alice(), (DOT, bob())), None);
+		})
+	}
 }
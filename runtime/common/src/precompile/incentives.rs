@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use super::input::{Input, InputPricer, InputT, Output};
+use super::input::{Input, InputPricer, InputT, Output, PER_PARAM_BYTES};
 use crate::WeightToGas;
 use frame_support::traits::Get;
 use module_evm::{
@@ -42,6 +42,12 @@ use sp_std::{marker::PhantomData, prelude::*};
 ///  - ClaimRewards `input` bytes: `who`, `pool`, `pool_currency_id`.
 ///  - GetClaimRewardDeductionRate `input` bytes: `pool`, `pool_currency_id`.
 ///  - GetPendingRewards `input` bytes: `reward_currencies`, `pool`, `pool_currency_id`, `who`.
+///  - GetTotalShares `input` bytes: `pool`, `pool_currency_id`.
+///  - GetShares `input` bytes: `pool`, `pool_currency_id`, `who`.
+///  - GetRewardPool `input` bytes: `pool`, `pool_currency_id`, `reward_currency_id`.
+///  - ClaimRewardsBatch `input` bytes: `who`, `pools`, `pool_currency_ids`.
+///
+/// `pool_currency_id` is ignored by the `NomineesElection` pool.
 pub struct IncentivesPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -54,6 +60,10 @@ pub enum Action {
 	ClaimRewards = "claimRewards(address,PoolId,address)",
 	GetClaimRewardDeductionRate = "getClaimRewardDeductionRate(PoolId,address)",
 	GetPendingRewards = "getPendingRewards(address[],PoolId,address,address)",
+	GetTotalShares = "getTotalShares(PoolId,address)",
+	GetShares = "getShares(PoolId,address,address)",
+	GetRewardPool = "getRewardPool(PoolId,address,address)",
+	ClaimRewardsBatch = "claimRewards(address,PoolId[],address[])",
 }
 
 impl<Runtime> Precompile for IncentivesPrecompile<Runtime>
@@ -195,6 +205,84 @@ where
 					output: Output::encode_uint_array(value),
 				})
 			}
+			Action::GetTotalShares => {
+				let pool = input.u32_at(1)?;
+				let pool_currency_id = input.currency_id_at(2)?;
+				let pool_id = init_pool_id(pool, pool_currency_id)?;
+
+				let value = <module_incentives::Pallet<Runtime> as IncentivesManager<
+					Runtime::AccountId,
+					Balance,
+					CurrencyId,
+					PoolId,
+				>>::get_total_shares(pool_id);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(value),
+				})
+			}
+			Action::GetShares => {
+				let pool = input.u32_at(1)?;
+				let pool_currency_id = input.currency_id_at(2)?;
+				let pool_id = init_pool_id(pool, pool_currency_id)?;
+				let who = input.account_id_at(3)?;
+
+				let value = <module_incentives::Pallet<Runtime> as IncentivesManager<
+					Runtime::AccountId,
+					Balance,
+					CurrencyId,
+					PoolId,
+				>>::get_shares(pool_id, who);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(value),
+				})
+			}
+			Action::GetRewardPool => {
+				let pool = input.u32_at(1)?;
+				let pool_currency_id = input.currency_id_at(2)?;
+				let pool_id = init_pool_id(pool, pool_currency_id)?;
+				let reward_currency_id = input.currency_id_at(3)?;
+
+				let (total_reward, total_withdrawn_reward) =
+					<module_incentives::Pallet<Runtime> as IncentivesManager<
+						Runtime::AccountId,
+						Balance,
+						CurrencyId,
+						PoolId,
+					>>::get_reward_pool(pool_id, reward_currency_id);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_tuple(vec![total_reward, total_withdrawn_reward]),
+				})
+			}
+			Action::ClaimRewardsBatch => {
+				let who = input.account_id_at(1)?;
+				let pools = pools_at(&input, 2)?;
+
+				for (pool, pool_currency_id) in pools {
+					let pool_id = init_pool_id(pool, pool_currency_id)?;
+
+					<module_incentives::Pallet<Runtime> as IncentivesManager<
+						Runtime::AccountId,
+						Balance,
+						CurrencyId,
+						PoolId,
+					>>::claim_rewards(who.clone(), pool_id)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Incentives ClaimRewards failed", e),
+					})?;
+				}
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
 		}
 	}
 }
@@ -291,6 +379,60 @@ where
 					.saturating_add(read_currency)
 					.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::GetTotalShares => {
+				let pool_currency_id = input.currency_id_at(2)?;
+				let read_pool_currency = InputPricer::<Runtime>::read_currency(pool_currency_id);
+
+				// Rewards::PoolInfos (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST
+					.saturating_add(read_pool_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetShares => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let pool_currency_id = input.currency_id_at(2)?;
+				let read_pool_currency = InputPricer::<Runtime>::read_currency(pool_currency_id);
+
+				// Rewards::SharesAndWithdrawnRewards (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_pool_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetRewardPool => {
+				let pool_currency_id = input.currency_id_at(2)?;
+				let reward_currency_id = input.currency_id_at(3)?;
+				let read_pool_currency = InputPricer::<Runtime>::read_currency(pool_currency_id);
+				let read_reward_currency = InputPricer::<Runtime>::read_currency(reward_currency_id);
+
+				// Rewards::PoolInfos (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST
+					.saturating_add(read_pool_currency)
+					.saturating_add(read_reward_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::ClaimRewardsBatch => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let pools = pools_at(&input, 2)?;
+				let read_pool_currency = pools
+					.iter()
+					.map(|(_, currency_id)| InputPricer::<Runtime>::read_currency(*currency_id))
+					.sum::<u64>();
+
+				let weight = <Runtime as module_incentives::Config>::WeightInfo::claim_rewards()
+					.saturating_mul(pools.len() as u64);
+
+				Self::BASE_COST
+					.saturating_add(read_pool_currency)
+					.saturating_add(read_account)
+					.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(cost)
 	}
@@ -300,6 +442,8 @@ fn init_pool_id(pool_id_number: u32, pool_currency_id: CurrencyId) -> Result<Poo
 	match pool_id_number {
 		0 => Ok(PoolId::Loans(pool_currency_id)),
 		1 => Ok(PoolId::Dex(pool_currency_id)),
+		2 => Ok(PoolId::Earning(pool_currency_id)),
+		3 => Ok(PoolId::NomineesElection),
 		// Shouldn't happen as solidity compiler should not allow nonexistent enum value
 		_ => Err(PrecompileFailure::Revert {
 			exit_status: ExitRevert::Reverted,
@@ -308,6 +452,38 @@ fn init_pool_id(pool_id_number: u32, pool_currency_id: CurrencyId) -> Result<Poo
 	}
 }
 
+/// Reads the `PoolId[]` and `address[]` arrays whose offsets are at `index` and `index + 1`, and
+/// returns them as pairs of pool id number and pool currency id.
+fn pools_at(
+	input: &impl InputT<Error = PrecompileFailure>,
+	index: usize,
+) -> Result<Vec<(u32, CurrencyId)>, PrecompileFailure> {
+	// solidity abi encode array will add an offset at input[index]
+	let pools_index = (input.u32_at(index)? as usize)
+		.saturating_div(PER_PARAM_BYTES)
+		.saturating_add(1);
+	let pool_currency_ids_index = (input.u32_at(index.saturating_add(1))? as usize)
+		.saturating_div(PER_PARAM_BYTES)
+		.saturating_add(1);
+
+	let pools_len = input.u32_at(pools_index)?;
+	if input.u32_at(pool_currency_ids_index)? != pools_len {
+		return Err(PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: "Incentives: pools and pool currency ids length mismatch".into(),
+		});
+	}
+
+	let mut pools = vec![];
+	for i in 0..pools_len as usize {
+		pools.push((
+			input.u32_at(pools_index.saturating_add(1).saturating_add(i))?,
+			input.currency_id_at(pool_currency_ids_index.saturating_add(1).saturating_add(i))?,
+		));
+	}
+	Ok(pools)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -315,7 +491,7 @@ mod tests {
 		alice, alice_evm_addr, bob, new_test_ext, Currencies, Incentives, Rewards, RuntimeOrigin, Test, Tokens, ACA,
 		ALICE, AUSD, DOT, LP_ACA_AUSD,
 	};
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
 	use module_support::Rate;
@@ -625,4 +801,195 @@ mod tests {
 			assert_eq!(res.output, expected_output.to_vec());
 		})
 	}
+
+	#[test]
+	fn share_queries_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Rewards::add_share(&alice(), &PoolId::Earning(ACA), 100));
+			assert_ok!(Rewards::add_share(&bob(), &PoolId::Earning(ACA), 300));
+			assert_ok!(Rewards::accumulate_reward(&PoolId::Earning(ACA), AUSD, 1_000));
+			assert_ok!(Rewards::add_share(&bob(), &PoolId::NomineesElection, 50));
+
+			// getTotalShares(PoolId,address) -> 0x128823e6
+			// pool
+			// pool_currency_id
+			let input = hex! {"
+				128823e6
+				00000000000000000000000000000000 00000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+
+			// value of 400
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000190
+			"};
+
+			let res =
+				IncentivesPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// getShares(PoolId,address,address) -> 0xb4e6ff20
+			// pool
+			// pool_currency_id
+			// who
+			let input = hex! {"
+				b4e6ff20
+				00000000000000000000000000000000 00000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000000
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			// value of 100
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000064
+			"};
+
+			let res =
+				IncentivesPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// getShares(PoolId,address,address) -> 0xb4e6ff20
+			// pool
+			// pool_currency_id
+			// who
+			let input = hex! {"
+				b4e6ff20
+				00000000000000000000000000000000 00000000000000000000000000000003
+				000000000000000000000000 0000000000000000000100000000000000000000
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+
+			// value of 50
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000032
+			"};
+
+			let res =
+				IncentivesPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// getRewardPool(PoolId,address,address) -> 0x4c911466
+			// pool
+			// pool_currency_id
+			// reward_currency_id
+			let input = hex! {"
+				4c911466
+				00000000000000000000000000000000 00000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000000
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// total_reward of 1000
+			// total_withdrawn_reward of 0
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000003e8
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let res =
+				IncentivesPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn claim_rewards_batch_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Tokens::deposit(AUSD, &Incentives::account_id(), 1_000_000));
+			assert_ok!(Rewards::add_share(&alice(), &PoolId::Loans(ACA), 100));
+			assert_ok!(Rewards::accumulate_reward(&PoolId::Loans(ACA), AUSD, 1_000));
+			assert_ok!(Rewards::add_share(&alice(), &PoolId::NomineesElection, 100));
+			assert_ok!(Rewards::accumulate_reward(&PoolId::NomineesElection, AUSD, 500));
+
+			let alice_balance = Tokens::free_balance(AUSD, &alice());
+
+			// claimRewards(address,PoolId[],address[]) -> 0x911223ab
+			// who
+			// offset of pools
+			// offset of pool_currency_ids
+			// pools_len
+			// Loans
+			// NomineesElection
+			// pool_currency_ids_len
+			// ACA
+			// ACA
+			let input = hex! {"
+				911223ab
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000060
+				00000000000000000000000000000000 000000000000000000000000000000c0
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000003
+				00000000000000000000000000000000 00000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000000
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+
+			let res =
+				IncentivesPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			assert_eq!(Tokens::free_balance(AUSD, &alice()), alice_balance + 1_500);
+			assert_eq!(
+				Rewards::pool_infos(PoolId::Loans(ACA)),
+				PoolInfo {
+					total_shares: 100,
+					rewards: vec![(AUSD, (1_000, 1_000))].into_iter().collect(),
+				}
+			);
+			assert_eq!(
+				Rewards::pool_infos(PoolId::NomineesElection),
+				PoolInfo {
+					total_shares: 100,
+					rewards: vec![(AUSD, (500, 500))].into_iter().collect(),
+				}
+			);
+
+			// claimRewards(address,PoolId[],address[]) -> 0x911223ab
+			// who
+			// offset of pools
+			// offset of pool_currency_ids
+			// pools_len
+			// Loans
+			// NomineesElection
+			// pool_currency_ids_len
+			// ACA
+			let input = hex! {"
+				911223ab
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000060
+				00000000000000000000000000000000 000000000000000000000000000000c0
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000003
+				00000000000000000000000000000000 00000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+
+			assert_noop!(
+				IncentivesPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Incentives: pools and pool currency ids length mismatch".into(),
+				}
+			);
+		});
+	}
 }
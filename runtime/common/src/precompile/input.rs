@@ -245,6 +245,17 @@ impl Output {
 		ethabi::encode(&[Token::Array(b.into_iter().map(U256::from).map(Token::Uint).collect())])
 	}
 
+	pub fn encode_uint_arrays<T>(b: Vec<Vec<T>>) -> Vec<u8>
+	where
+		U256: From<T>,
+	{
+		let tokens = b
+			.into_iter()
+			.map(|v| Token::Array(v.into_iter().map(U256::from).map(Token::Uint).collect()))
+			.collect::<Vec<_>>();
+		ethabi::encode(&tokens)
+	}
+
 	pub fn encode_bytes(b: &[u8]) -> Vec<u8> {
 		ethabi::encode(&[Token::Bytes(b.to_vec())])
 	}
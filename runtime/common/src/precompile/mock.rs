@@ -29,6 +29,7 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::{offchain::SendTransactionTypes, EnsureRoot, EnsureSignedBy};
+use module_aggregated_dex::AggregatedDexTask;
 use module_cdp_engine::CollateralCurrencyIds;
 use module_evm::{EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
@@ -138,8 +139,8 @@ impl orml_tokens::Config for Test {
 	type ExistentialDeposits = ExistentialDeposits;
 	type CurrencyHooks = ();
 	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type DustRemovalWhitelist = Nothing;
 }
 
@@ -184,6 +185,8 @@ impl module_currencies::Config for Test {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = ();
+	type DepositCurrency = Balances;
+	type DepositPerAllowance = ConstU128<1>;
 }
 
 impl module_evm_bridge::Config for Test {
@@ -203,6 +206,7 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Test>),
+		AggregatedDexTask(AggregatedDexTask<Test>),
 	}
 }
 
@@ -391,6 +395,8 @@ impl module_dex::Config for Test {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type FlashSwapEvmBridge = module_evm_bridge::FlashSwapEvmBridge<Test>;
 }
 
 parameter_types! {
@@ -555,6 +561,31 @@ impl nutsfinance_stable_asset::Config for Test {
 	type EnsurePoolAssetId = EnsurePoolAssetId;
 }
 
+impl module_aggregated_dex::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type DEX = DexModule;
+	type StableAsset = StableAsset;
+	type GovernanceOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type DexSwapJointList = AlternativeSwapPathJointList;
+	type SwapPathLimit = ConstU32<3>;
+	type MaxOrdersPerAccount = ConstU32<20>;
+	type MaxOrderDuration = ConstU32<100>;
+	type OrderDeposit = ConstU128<1_000>;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type DexTradingPathLimit = TradingPathLimit;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
+	type WeightInfo = ();
+}
+
+impl module_dex_oracle::Config for Test {
+	type DEX = DexModule;
+	type Time = Timestamp;
+	type UpdateOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type WeightInfo = ();
+}
+
 impl module_transaction_pause::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
@@ -1045,6 +1076,8 @@ frame_support::construct_runtime!(
 		Utility: pallet_utility,
 		Scheduler: pallet_scheduler,
 		DexModule: module_dex,
+		AggregatedDex: module_aggregated_dex,
+		DexOracle: module_dex_oracle,
 		EVMModule: module_evm,
 		EvmAccounts: module_evm_accounts,
 		IdleScheduler: module_idle_scheduler,
@@ -38,6 +38,8 @@ use sp_core::H160;
 use sp_runtime::traits::Zero;
 use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData};
 
+pub mod aggregated_dex;
+pub mod asset_registry;
 pub mod dex;
 pub mod earning;
 pub mod evm;
@@ -52,9 +54,12 @@ pub mod nft;
 pub mod oracle;
 pub mod schedule;
 pub mod stable_asset;
+pub mod transaction_payment;
 pub mod xtokens;
 
 use crate::SystemContractsFilter;
+pub use aggregated_dex::AggregatedDexPrecompile;
+pub use asset_registry::AssetRegistryPrecompile;
 pub use dex::DEXPrecompile;
 pub use earning::EarningPrecompile;
 pub use evm::EVMPrecompile;
@@ -68,6 +73,7 @@ pub use nft::NFTPrecompile;
 pub use oracle::OraclePrecompile;
 pub use schedule::SchedulePrecompile;
 pub use stable_asset::StableAssetPrecompile;
+pub use transaction_payment::TransactionPaymentPrecompile;
 pub use xtokens::XtokensPrecompile;
 
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
@@ -100,6 +106,9 @@ pub const INCENTIVES: H160 = H160(hex!("000000000000000000000000000000000000040a
 pub const XTOKENS: H160 = H160(hex!("000000000000000000000000000000000000040b"));
 pub const LIQUID_CROWDLOAN: H160 = H160(hex!("000000000000000000000000000000000000040c"));
 pub const EARNING: H160 = H160(hex!("000000000000000000000000000000000000040d"));
+pub const AGGREGATED_DEX: H160 = H160(hex!("000000000000000000000000000000000000040e"));
+pub const TRANSACTION_PAYMENT: H160 = H160(hex!("000000000000000000000000000000000000040f"));
+pub const ASSET_REGISTRY: H160 = H160(hex!("0000000000000000000000000000000000000410"));
 
 pub struct AllPrecompiles<R, F, E> {
 	set: BTreeSet<H160>,
@@ -142,6 +151,9 @@ where
 				XTOKENS,
 				LIQUID_CROWDLOAN,
 				EARNING,
+				AGGREGATED_DEX,
+				TRANSACTION_PAYMENT,
+				ASSET_REGISTRY,
 			]),
 			_marker: Default::default(),
 		}
@@ -178,6 +190,9 @@ where
 				XTOKENS,
 				// LIQUID_CROWDLOAN,
 				EARNING,
+				AGGREGATED_DEX,
+				TRANSACTION_PAYMENT,
+				ASSET_REGISTRY,
 			]),
 			_marker: Default::default(),
 		}
@@ -214,6 +229,9 @@ where
 				XTOKENS,
 				// LIQUID_CROWDLOAN,
 				EARNING,
+				AGGREGATED_DEX,
+				TRANSACTION_PAYMENT,
+				ASSET_REGISTRY,
 			]),
 			_marker: Default::default(),
 		}
@@ -238,6 +256,9 @@ where
 	IncentivesPrecompile<R>: Precompile,
 	XtokensPrecompile<R>: Precompile,
 	EarningPrecompile<R>: Precompile,
+	AggregatedDexPrecompile<R>: Precompile,
+	TransactionPaymentPrecompile<R>: Precompile,
+	AssetRegistryPrecompile<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		let context = handle.context();
@@ -269,6 +290,9 @@ where
 
 		log::trace!(target: "evm", "Precompile begin, address: {:?}, input: {:?}, context: {:?}", address, handle.input(), context);
 
+		#[cfg(feature = "tracing")]
+		let event_count = frame_system::Pallet::<R>::event_count();
+
 		// https://github.com/ethereum/go-ethereum/blob/9357280fce5c5d57111d690a336cca5f89e34da6/core/vm/contracts.go#L83
 		let result = if address == ECRECOVER {
 			Some(ECRecover::execute(handle))
@@ -345,11 +369,20 @@ where
 				Some(XtokensPrecompile::<R>::execute(handle))
 			} else if address == EARNING {
 				Some(EarningPrecompile::<R>::execute(handle))
+			} else if address == AGGREGATED_DEX {
+				Some(AggregatedDexPrecompile::<R>::execute(handle))
+			} else if address == TRANSACTION_PAYMENT {
+				Some(TransactionPaymentPrecompile::<R>::execute(handle))
+			} else if address == ASSET_REGISTRY {
+				Some(AssetRegistryPrecompile::<R>::execute(handle))
 			} else {
 				E::execute(&Default::default(), handle)
 			}
 		};
 
+		#[cfg(feature = "tracing")]
+		module_evm::runner::tracing::record_substrate_events::<R>(event_count);
+
 		log::trace!(target: "evm", "Precompile end, address: {:?}, input: {:?}, context: {:?}, result: {:?}", address, handle.input(), handle.context(), result);
 		if let Some(Err(PrecompileFailure::Revert { ref output, .. })) = result {
 			log::debug!(target: "evm", "Precompile failed: {:?}", core::str::from_utf8(output));
@@ -18,6 +18,7 @@
 
 use super::input::{Input, InputPricer, InputT, Output};
 use crate::WeightToGas;
+use ethabi::Token;
 use frame_support::{
 	pallet_prelude::IsType,
 	traits::{Currency, Get},
@@ -27,11 +28,17 @@ use module_evm::{
 	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
 	PrecompileResult,
 };
+use module_evm_utility_macro::keccak256;
 use module_support::Erc20InfoMapping as Erc20InfoMappingT;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::MultiCurrency as MultiCurrencyT;
 use primitives::{currency::DexShare, Balance, CurrencyId};
-use sp_runtime::{traits::Convert, AccountId32, RuntimeDebug};
+use sp_core::{H160, U256};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_runtime::{
+	traits::{Convert, UniqueSaturatedInto},
+	AccountId32, RuntimeDebug,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// The `MultiCurrency` impl precompile.
@@ -43,6 +50,12 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query total issuance.
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Transfer. Rest `input` bytes: `from`, `to`, `amount`.
+/// - Approve. Rest `input` bytes: `owner`, `spender`, `amount`.
+/// - Query allowance. Rest `input` bytes: `owner`, `spender`.
+/// - Transfer from. Rest `input` bytes: `spender`, `from`, `to`, `amount`.
+/// - Permit. Rest `input` bytes: `owner`, `spender`, `value`, `deadline`, `v`, `r`, `s`.
+/// - Query permit nonce. Rest `input` bytes: `owner`.
+/// - Query EIP-712 domain separator.
 pub struct MultiCurrencyPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -56,6 +69,12 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	Transfer = "transfer(address,address,uint256)",
 	TransferToAccountId = "transferToAccountId(address,bytes32,uint256)",
+	Approve = "approve(address,address,uint256)",
+	QueryAllowance = "allowance(address,address)",
+	TransferFrom = "transferFrom(address,address,address,uint256)",
+	Permit = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+	QueryNonces = "nonces(address)",
+	QueryDomainSeparator = "DOMAIN_SEPARATOR()",
 }
 
 impl<Runtime> Precompile for MultiCurrencyPrecompile<Runtime>
@@ -64,9 +83,12 @@ where
 		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
 	Runtime::AccountId: IsType<AccountId32>,
 	module_currencies::Pallet<Runtime>: MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
+	<Runtime as module_currencies::Config>::MultiCurrency:
+		MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		let context = handle.context();
+		let token = context.caller;
 		let currency_id =
 			Runtime::Erc20InfoMapping::decode_evm_address(context.caller).ok_or_else(|| PrecompileFailure::Revert {
 				exit_status: ExitRevert::Reverted,
@@ -199,10 +221,195 @@ where
 					output: vec![],
 				})
 			}
+			Action::Approve => {
+				let owner = input.account_id_at(1)?;
+				let spender = input.account_id_at(2)?;
+				let amount = saturated_balance(input.u256_at(3)?);
+				log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+				module_currencies::Pallet::<Runtime>::approve(currency_id, &owner, &spender, amount).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Multicurrency Approve failed", e),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::QueryAllowance => {
+				let owner = input.account_id_at(1)?;
+				let spender = input.account_id_at(2)?;
+				let allowance = module_currencies::Pallet::<Runtime>::allowances(currency_id, (&owner, &spender));
+				log::debug!(target: "evm", "multicurrency: owner: {:?}, spender: {:?}, allowance: {:?}", owner, spender, allowance);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(allowance),
+				})
+			}
+			Action::TransferFrom => {
+				let spender = input.account_id_at(1)?;
+				let from = input.account_id_at(2)?;
+				let to = input.account_id_at(3)?;
+				let amount = input.balance_at(4)?;
+				log::debug!(target: "evm", "multicurrency: transferFrom spender: {:?}, from: {:?}, to: {:?}, amount: {:?}", spender, from, to, amount);
+
+				module_currencies::Pallet::<Runtime>::transfer_from(currency_id, &spender, &from, &to, amount)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Multicurrency TransferFrom failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::Permit => {
+				let owner_address = input.evm_address_at(1)?;
+				let owner = input.account_id_at(1)?;
+				let spender_address = input.evm_address_at(2)?;
+				let spender = input.account_id_at(2)?;
+				let value = input.u256_at(3)?;
+				let deadline = input.u256_at(4)?;
+				let v = input.u32_at(5)?;
+				let r = input.bytes32_at(6)?;
+				let s = input.bytes32_at(7)?;
+				log::debug!(target: "evm", "multicurrency: permit owner: {:?}, spender: {:?}, value: {:?}, deadline: {:?}", owner, spender, value, deadline);
+
+				let now: u128 = pallet_timestamp::Pallet::<Runtime>::get().unique_saturated_into();
+				if deadline < U256::from(now / 1000) {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "Permit expired".into(),
+					});
+				}
+
+				let nonce = module_currencies::Pallet::<Runtime>::permit_nonces(currency_id, &owner);
+				let domain_separator = domain_separator::<Runtime>(currency_id, token)?;
+				let digest = permit_digest(domain_separator, owner_address, spender_address, value, nonce, deadline);
+
+				// only accept the canonical form of the signature, like `ecrecover` based permits do
+				if (v != 27 && v != 28) || U256::from_big_endian(&s) > SECP256K1N_HALF {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "Invalid permit signature".into(),
+					});
+				}
+
+				let mut sig = [0u8; 65];
+				sig[0..32].copy_from_slice(&r);
+				sig[32..64].copy_from_slice(&s);
+				sig[64] = (v - 27) as u8;
+
+				let signer = secp256k1_ecdsa_recover(&sig, &digest)
+					.map(|pubkey| H160::from_slice(&keccak_256(&pubkey)[12..]))
+					.ok();
+				if signer != Some(owner_address) {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "Invalid permit signature".into(),
+					});
+				}
+
+				module_currencies::Pallet::<Runtime>::use_permit_nonce(currency_id, &owner);
+				module_currencies::Pallet::<Runtime>::approve(currency_id, &owner, &spender, saturated_balance(value))
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Multicurrency Permit failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::QueryNonces => {
+				let owner = input.account_id_at(1)?;
+				let nonce = module_currencies::Pallet::<Runtime>::permit_nonces(currency_id, &owner);
+				log::debug!(target: "evm", "multicurrency: owner: {:?}, nonce: {:?}", owner, nonce);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(nonce),
+				})
+			}
+			Action::QueryDomainSeparator => {
+				let domain_separator = domain_separator::<Runtime>(currency_id, token)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_fixed_bytes(&domain_separator),
+				})
+			}
 		}
 	}
 }
 
+/// Half of the secp256k1 curve order, the upper bound of `s` in a canonical signature.
+const SECP256K1N_HALF: U256 = U256([
+	0xdfe92f46681b20a0,
+	0x5d576e7357a4501d,
+	0xffffffffffffffff,
+	0x7fffffffffffffff,
+]);
+
+/// Convert the uint256 amount into Balance, the value exceeding the max Balance is treated as the
+/// max Balance, which means infinite allowance.
+fn saturated_balance(value: U256) -> Balance {
+	value.try_into().unwrap_or(Balance::MAX)
+}
+
+/// The EIP-712 domain separator of the ERC20 token `token` which maps to `currency_id`.
+fn domain_separator<Runtime>(currency_id: CurrencyId, token: H160) -> Result<[u8; 32], PrecompileFailure>
+where
+	Runtime: module_evm::Config,
+{
+	let name = Runtime::Erc20InfoMapping::name(currency_id).ok_or_else(|| PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: "Get name failed".into(),
+	})?;
+
+	Ok(keccak_256(&ethabi::encode(&[
+		Token::FixedBytes(
+			keccak256!("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)").to_vec(),
+		),
+		Token::FixedBytes(keccak_256(&name).to_vec()),
+		Token::FixedBytes(keccak256!("1").to_vec()),
+		Token::Uint(U256::from(module_evm::Pallet::<Runtime>::chain_id())),
+		Token::Address(token),
+	])))
+}
+
+/// The EIP-712 digest of EIP-2612 permit to be signed by the owner.
+fn permit_digest(
+	domain_separator: [u8; 32],
+	owner: H160,
+	spender: H160,
+	value: U256,
+	nonce: u64,
+	deadline: U256,
+) -> [u8; 32] {
+	let struct_hash = keccak_256(&ethabi::encode(&[
+		Token::FixedBytes(
+			keccak256!("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)").to_vec(),
+		),
+		Token::Address(owner),
+		Token::Address(spender),
+		Token::Uint(value),
+		Token::Uint(U256::from(nonce)),
+		Token::Uint(deadline),
+	]));
+
+	let mut msg = b"\x19\x01".to_vec();
+	msg.extend_from_slice(&domain_separator);
+	msg.extend_from_slice(&struct_hash);
+	keccak_256(msg.as_slice())
+}
+
 struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
@@ -211,6 +418,8 @@ where
 		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
 {
 	const BASE_COST: u64 = 200;
+	// The same as the cost of the `ecrecover` precompile of Ethereum.
+	const ECRECOVER_COST: u64 = 3_000;
 
 	fn cost(handle: &mut impl PrecompileHandle, currency_id: CurrencyId) -> Result<u64, PrecompileFailure> {
 		let input = Input::<
@@ -251,6 +460,67 @@ where
 
 				cost.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::Approve => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// Currencies::Allowances (r: 1, w: 1)
+				// System::Account (r: 1, w: 1)
+				// Balances::Reserves (r: 1, w: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 3),
+				))
+			}
+			Action::QueryAllowance => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// Currencies::Allowances (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::TransferFrom => {
+				let cost = InputPricer::<Runtime>::read_accounts(3);
+
+				// transfer weight
+				let weight = if currency_id == <Runtime as module_transaction_payment::Config>::NativeCurrencyId::get()
+				{
+					<Runtime as module_currencies::Config>::WeightInfo::transfer_native_currency()
+				} else {
+					<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency()
+				};
+				// Currencies::Allowances (r: 1, w: 1)
+				// System::Account (r: 1, w: 1)
+				// Balances::Reserves (r: 1, w: 1)
+				let weight =
+					weight.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 3));
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Permit => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// EVM::ChainId (r: 1)
+				// Timestamp::Now (r: 1)
+				// Currencies::PermitNonces (r: 1, w: 1)
+				// Currencies::Allowances (r: 1, w: 1)
+				// System::Account (r: 1, w: 1)
+				// Balances::Reserves (r: 1, w: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(6, 4);
+
+				cost.saturating_add(Self::erc20_info(currency_id))
+					.saturating_add(Self::ECRECOVER_COST)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::QueryNonces => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Currencies::PermitNonces (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::QueryDomainSeparator => {
+				// EVM::ChainId (r: 1)
+				Self::erc20_info(currency_id).saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
 		};
 
 		Ok(Self::BASE_COST.saturating_add(read_currency).saturating_add(cost))
@@ -281,12 +551,14 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		aca_evm_address, alice, ausd_evm_address, bob, erc20_address_not_exists, lp_aca_ausd_evm_address, new_test_ext,
-		Balances, Test,
+		aca_evm_address, alice, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists, lp_aca_ausd_evm_address,
+		new_test_ext, Balances, RuntimeOrigin, Test, Timestamp, AUSD,
 	};
-	use frame_support::assert_noop;
+	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+	use module_support::AddressMapping;
+	use sp_core::{ecdsa, Pair};
 
 	type MultiCurrencyPrecompile = crate::MultiCurrencyPrecompile<Test>;
 
@@ -624,4 +896,266 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			// approve(address,address,uint256) -> 0xe1f21c67
+			// owner
+			// spender
+			// amount
+			let input = hex! {"
+				e1f21c67
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 0].to_vec());
+
+			// allowance(address,address) -> 0xdd62ed3e
+			// owner
+			// spender
+			let allowance_input = hex! {"
+				dd62ed3e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+
+			let expected_output = hex! {"
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(
+				&allowance_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// transferFrom(address,address,address,uint256) -> 0x15dacbea
+			// spender
+			// from
+			// to
+			// amount
+			let input = hex! {"
+				15dacbea
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000004
+			"};
+
+			let from_balance = Balances::free_balance(alice());
+			let to_balance = Balances::free_balance(bob());
+
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 0].to_vec());
+
+			assert_eq!(Balances::free_balance(alice()), from_balance - 4);
+			assert_eq!(Balances::free_balance(bob()), to_balance + 4);
+
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000006
+			"};
+
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(
+				&allowance_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// exceeds the allowance
+			let input = hex! {"
+				15dacbea
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000007
+			"};
+
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency TransferFrom failed: AllowanceExceeded".into(),
+				}
+			);
+		})
+	}
+
+	#[test]
+	fn permit_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: ausd_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			let mut seed = [0u8; 32];
+			seed[31] = 1;
+			let pair = ecdsa::Pair::from_seed(&seed);
+			let owner_address = H160::from(hex!("7e5f4552091a69125d5dfcb7b8c2659029395bdf"));
+			let owner = <Test as module_evm::Config>::AddressMapping::get_account_id(&owner_address);
+			// the owner pays the allowance deposit
+			assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				owner.clone(),
+				1_000_000
+			));
+			let value = U256::from(1_000);
+
+			let permit_input = |deadline: U256| {
+				let nonce = module_currencies::Pallet::<Test>::permit_nonces(AUSD, &owner);
+				let digest = permit_digest(
+					domain_separator::<Test>(AUSD, ausd_evm_address()).unwrap(),
+					owner_address,
+					bob_evm_addr(),
+					value,
+					nonce,
+					deadline,
+				);
+				let signature = pair.sign_prehashed(&digest);
+				let signature: &[u8] = signature.as_ref();
+
+				let mut input = Into::<u32>::into(Action::Permit).to_be_bytes().to_vec();
+				input.extend_from_slice(&ethabi::encode(&[
+					Token::Address(owner_address),
+					Token::Address(bob_evm_addr()),
+					Token::Uint(value),
+					Token::Uint(deadline),
+					Token::Uint(U256::from(signature[64]).saturating_add(U256::from(27))),
+					Token::FixedBytes(signature[0..32].to_vec()),
+					Token::FixedBytes(signature[32..64].to_vec()),
+				]));
+				input
+			};
+
+			// DOMAIN_SEPARATOR() -> 0x3644e515
+			let input = hex! {"
+				3644e515
+			"};
+
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				resp.output,
+				domain_separator::<Test>(AUSD, ausd_evm_address()).unwrap().to_vec()
+			);
+
+			// nonces(address) -> 0x7ecebe00
+			// owner
+			let nonces_input = hex! {"
+				7ecebe00
+				000000000000000000000000 7e5f4552091a69125d5dfcb7b8c2659029395bdf
+			"};
+
+			let resp =
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&nonces_input, None, &context, false))
+					.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(0u64));
+
+			let input = permit_input(U256::from(100));
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 0].to_vec());
+
+			assert_eq!(
+				module_currencies::Pallet::<Test>::allowances(AUSD, (&owner, &bob())),
+				1_000
+			);
+			assert_eq!(Balances::reserved_balance(&owner), 1);
+
+			let resp =
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&nonces_input, None, &context, false))
+					.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(1u64));
+
+			// replay the used permit
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Invalid permit signature".into(),
+				}
+			);
+
+			// the malleable form of a valid signature is rejected
+			let input = permit_input(U256::from(100));
+			let secp256k1n = U256::from_big_endian(&hex!(
+				"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+			));
+			let v = U256::from_big_endian(&input[132..164]);
+			let s = U256::from_big_endian(&input[196..228]);
+			let mut malleable_input = input.clone();
+			(U256::from(55) - v).to_big_endian(&mut malleable_input[132..164]);
+			(secp256k1n - s).to_big_endian(&mut malleable_input[196..228]);
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(
+					&malleable_input,
+					None,
+					&context,
+					false
+				)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Invalid permit signature".into(),
+				}
+			);
+
+			// v must be 27 or 28
+			let mut invalid_v_input = input.clone();
+			(v - 27).to_big_endian(&mut invalid_v_input[132..164]);
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(
+					&invalid_v_input,
+					None,
+					&context,
+					false
+				)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Invalid permit signature".into(),
+				}
+			);
+
+			let resp = MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+
+			// expired
+			Timestamp::set_timestamp(101_000);
+			let input = permit_input(U256::from(100));
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Permit expired".into(),
+				}
+			);
+		})
+	}
 }
@@ -16,18 +16,27 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use super::input::{Input, InputT, Output};
-use frame_support::traits::tokens::nonfungibles::{Inspect, Transfer};
+use super::input::{Input, InputT, Output, PER_PARAM_BYTES};
+use crate::WeightToGas;
+use frame_support::{
+	ensure,
+	traits::{
+		tokens::nonfungibles::{Inspect, Transfer},
+		Get,
+	},
+};
 use module_evm::{
 	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
 	PrecompileResult,
 };
+use module_nft::WeightInfo;
 use module_support::AddressMapping;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::InspectExtended;
-use primitives::nft::NFTBalance;
-use sp_core::H160;
-use sp_runtime::RuntimeDebug;
+use parity_scale_codec::Decode;
+use primitives::nft::{Attributes, NFTBalance, Properties};
+use sp_core::{H160, H256};
+use sp_runtime::{traits::Convert, DispatchError, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// The `NFT` impl precompile.
@@ -38,6 +47,21 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Query owner. Rest `input` bytes: `class_id`, `token_id`.
 /// - Transfer. Rest `input`bytes: `from`, `to`, `class_id`, `token_id`.
+/// - Create class. Rest `input` bytes: `who`, `metadata`, `properties`, `attribute_keys`,
+///   `attribute_values`.
+/// - Mint. Rest `input` bytes: `who`, `to`, `class_id`, `metadata`, `quantity`, `attribute_keys`,
+///   `attribute_values`.
+/// - Burn. Rest `input` bytes: `who`, `class_id`, `token_id`.
+/// - Burn with remark. Rest `input` bytes: `who`, `class_id`, `token_id`, `remark`.
+/// - Update class properties. Rest `input` bytes: `who`, `class_id`, `properties`.
+/// - Get class properties. Rest `input` bytes: `class_id`.
+/// - Get class attribute. Rest `input` bytes: `class_id`, `key`.
+/// - Get token attribute. Rest `input` bytes: `class_id`, `token_id`, `key`.
+///
+/// Creating class, minting, burning and transferring emit ERC-721 style logs:
+/// - `ClassCreated(address indexed creator, uint256 indexed classId)`
+/// - `Transfer(address indexed from, address indexed to, uint256 indexed tokenId)` with `classId`
+///   as data.
 pub struct NFTPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -47,11 +71,108 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	QueryOwner = "ownerOf(uint256,uint256)",
 	Transfer = "transfer(address,address,uint256,uint256)",
+	CreateClass = "createClass(address,bytes,uint8,bytes[],bytes[])",
+	Mint = "mint(address,address,uint256,bytes,uint256,bytes[],bytes[])",
+	Burn = "burn(address,uint256,uint256)",
+	BurnWithRemark = "burnWithRemark(address,uint256,uint256,bytes)",
+	UpdateClassProperties = "updateClassProperties(address,uint256,uint8)",
+	GetClassProperties = "getClassProperties(uint256)",
+	GetClassAttribute = "getClassAttribute(uint256,bytes)",
+	GetTokenAttribute = "getTokenAttribute(uint256,uint256,bytes)",
+}
+
+/// `ClassCreated(address,uint256)`
+const CLASS_CREATED_EVENT: [u8; 32] =
+	hex_literal::hex!("d3db2c54d834ab65eeff3a8d4737fbd3151e14849319f1b2065f70683126f4f2");
+/// `Transfer(address,address,uint256)`
+const TRANSFER_EVENT: [u8; 32] = hex_literal::hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+fn evm_address_of<Runtime: module_evm::Config>(who: &Runtime::AccountId) -> H160 {
+	Runtime::AddressMapping::get_evm_address(who)
+		.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(who))
+}
+
+fn log_transfer(
+	handle: &mut impl PrecompileHandle,
+	from: H160,
+	to: H160,
+	class_id: u32,
+	token_id: u64,
+) -> Result<(), PrecompileFailure> {
+	let address = handle.code_address();
+	handle.log(
+		address,
+		vec![
+			H256::from(TRANSFER_EVENT),
+			H256::from(from),
+			H256::from(to),
+			H256::from_low_u64_be(token_id),
+		],
+		Output::encode_uint(class_id),
+	)?;
+	Ok(())
+}
+
+fn properties_at(
+	input: &impl InputT<Error = PrecompileFailure>,
+	index: usize,
+) -> Result<Properties, PrecompileFailure> {
+	// `Properties` decoding rejects unknown flags.
+	u8::try_from(input.u32_at(index)?)
+		.ok()
+		.and_then(|bits| Properties::decode(&mut &[bits][..]).ok())
+		.ok_or_else(|| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: "invalid properties".into(),
+		})
+}
+
+fn bytes_array_at(
+	input: &impl InputT<Error = PrecompileFailure>,
+	index: usize,
+) -> Result<Vec<Vec<u8>>, PrecompileFailure> {
+	let offset = input.u32_at(index)?;
+	let array_index = (offset as usize).saturating_div(PER_PARAM_BYTES).saturating_add(1);
+	let array_len = input.u32_at(array_index)?;
+
+	// element offsets are relative to the start of the array content
+	let content_index = array_index.saturating_add(1);
+	let mut items = vec![];
+	for i in 0..array_len {
+		let item_offset = input.u32_at(content_index.saturating_add(i as usize))?;
+		let item_index = content_index.saturating_add((item_offset as usize).saturating_div(PER_PARAM_BYTES));
+		let item_len = input.u32_at(item_index)?;
+		items.push(
+			input
+				.nth_param(item_index.saturating_add(1), Some(item_len as usize))?
+				.to_vec(),
+		);
+	}
+
+	Ok(items)
+}
+
+fn attributes_at(
+	input: &impl InputT<Error = PrecompileFailure>,
+	keys_index: usize,
+	values_index: usize,
+) -> Result<Attributes, PrecompileFailure> {
+	let keys = bytes_array_at(input, keys_index)?;
+	let values = bytes_array_at(input, values_index)?;
+	ensure!(
+		keys.len() == values.len(),
+		PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: "invalid attributes".into(),
+		}
+	);
+
+	Ok(keys.into_iter().zip(values).collect())
 }
 
 impl<Runtime> Precompile for NFTPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config + module_nft::Config,
+	Runtime: module_evm::Config + module_prices::Config + module_nft::Config<ClassId = u32, TokenId = u64>,
 	module_nft::Pallet<Runtime>: InspectExtended<Runtime::AccountId, Balance = NFTBalance>
 		+ Inspect<Runtime::AccountId, ItemId = u64, CollectionId = u32>
 		+ Transfer<Runtime::AccountId>,
@@ -86,8 +207,7 @@ where
 				log::debug!(target: "evm", "nft: query_owner class_id: {:?}, token_id: {:?}", class_id, token_id);
 
 				let owner: H160 = if let Some(o) = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id) {
-					Runtime::AddressMapping::get_evm_address(&o)
-						.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(&o))
+					evm_address_of::<Runtime>(&o)
 				} else {
 					Default::default()
 				};
@@ -106,17 +226,184 @@ where
 
 				log::debug!(target: "evm", "nft: transfer from: {:?}, to: {:?}, class_id: {:?}, token_id: {:?}", from, to, class_id, token_id);
 
+				let owner = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id);
+
 				<module_nft::Pallet<Runtime> as Transfer<Runtime::AccountId>>::transfer(&class_id, &token_id, &to)
 					.map_err(|e| PrecompileFailure::Revert {
 						exit_status: ExitRevert::Reverted,
 						output: Output::encode_error_msg("NFT Transfer failed", e),
 					})?;
 
+				if let Some(owner) = owner {
+					log_transfer(
+						handle,
+						evm_address_of::<Runtime>(&owner),
+						evm_address_of::<Runtime>(&to),
+						class_id,
+						token_id,
+					)?;
+				}
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::CreateClass => {
+				let who = input.account_id_at(1)?;
+				let metadata = input.bytes_at(2)?;
+				let properties = properties_at(&input, 3)?;
+				let attributes = attributes_at(&input, 4, 5)?;
+
+				log::debug!(target: "evm", "nft: create_class who: {:?}, metadata: {:?}, properties: {:?}, attributes: {:?}", who, metadata, properties, attributes);
+
+				let class_id = module_nft::Pallet::<Runtime>::do_create_class(&who, metadata, properties, attributes)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT CreateClass failed", e),
+					})?;
+
+				let address = handle.code_address();
+				handle.log(
+					address,
+					vec![
+						H256::from(CLASS_CREATED_EVENT),
+						H256::from(evm_address_of::<Runtime>(&who)),
+						H256::from_low_u64_be(class_id.into()),
+					],
+					vec![],
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(class_id),
+				})
+			}
+			Action::Mint => {
+				let who = input.account_id_at(1)?;
+				let to = input.account_id_at(2)?;
+				let class_id = input.u32_at(3)?;
+				let metadata = input.bytes_at(4)?;
+				let quantity = input.u32_at(5)?;
+				let attributes = attributes_at(&input, 6, 7)?;
+
+				log::debug!(target: "evm", "nft: mint who: {:?}, to: {:?}, class_id: {:?}, metadata: {:?}, quantity: {:?}, attributes: {:?}", who, to, class_id, metadata, quantity, attributes);
+
+				let token_ids = module_nft::Pallet::<Runtime>::ensure_class_owner_or_proxy(&who, class_id)
+					.and_then(|owner| {
+						module_nft::Pallet::<Runtime>::do_mint(&owner, &to, class_id, metadata, attributes, quantity)
+					})
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT Mint failed", e),
+					})?;
+
+				let to_address = evm_address_of::<Runtime>(&to);
+				for token_id in token_ids.iter() {
+					log_transfer(handle, H160::zero(), to_address, class_id, *token_id)?;
+				}
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_array(token_ids),
+				})
+			}
+			Action::Burn | Action::BurnWithRemark => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let remark = if action == Action::BurnWithRemark {
+					Some(input.bytes_at(4)?)
+				} else {
+					None
+				};
+
+				log::debug!(target: "evm", "nft: burn who: {:?}, class_id: {:?}, token_id: {:?}, remark: {:?}", who, class_id, token_id, remark);
+
+				module_nft::Pallet::<Runtime>::do_burn(who.clone(), (class_id, token_id), remark).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT Burn failed", e),
+					}
+				})?;
+
+				log_transfer(
+					handle,
+					evm_address_of::<Runtime>(&who),
+					H160::zero(),
+					class_id,
+					token_id,
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::UpdateClassProperties => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let properties = properties_at(&input, 3)?;
+
+				log::debug!(target: "evm", "nft: update_class_properties who: {:?}, class_id: {:?}, properties: {:?}", who, class_id, properties);
+
+				module_nft::Pallet::<Runtime>::ensure_class_owner_or_proxy(&who, class_id)
+					.and_then(|owner| {
+						module_nft::Pallet::<Runtime>::do_update_class_properties(&owner, class_id, properties)
+					})
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT UpdateClassProperties failed", e),
+					})?;
+
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					output: vec![],
 				})
 			}
+			Action::GetClassProperties => {
+				let class_id = input.u32_at(1)?;
+
+				let properties = module_nft::Pallet::<Runtime>::class_properties(class_id).ok_or_else(|| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg(
+							"NFT GetClassProperties failed",
+							DispatchError::from(module_nft::Error::<Runtime>::ClassIdNotFound),
+						),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(properties.0.bits()),
+				})
+			}
+			Action::GetClassAttribute => {
+				let class_id = input.u32_at(1)?;
+				let key = input.bytes_at(2)?;
+
+				// If the attribute does not exist, return empty bytes.
+				let value = module_nft::Pallet::<Runtime>::collection_attribute(&class_id, &key).unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&value),
+				})
+			}
+			Action::GetTokenAttribute => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+				let key = input.bytes_at(3)?;
+
+				// If the attribute does not exist, return empty bytes.
+				let value = module_nft::Pallet::<Runtime>::attribute(&class_id, &token_id, &key).unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bytes(&value),
+				})
+			}
 		}
 	}
 }
@@ -128,13 +415,325 @@ where
 	Runtime: module_evm::Config + module_prices::Config + module_nft::Config,
 {
 	pub const BASE_COST: u64 = 200;
+	// LOG4 with one word of data
+	pub const TRANSFER_LOG_COST: u64 = 375 + 375 * 4 + 8 * 32;
+	// LOG3 without data
+	pub const CLASS_CREATED_LOG_COST: u64 = 375 + 375 * 3;
 
 	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
 		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
 			handle.input(),
 		);
-		let _action = input.action()?;
-		// TODO: gas cost
-		Ok(Self::BASE_COST)
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::QueryBalance => {
+				// OrmlNFT::TokensByOwner (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::QueryOwner => {
+				// OrmlNFT::Tokens (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::Transfer => {
+				let weight = <Runtime as module_nft::Config>::WeightInfo::transfer();
+
+				WeightToGas::convert(weight).saturating_add(Self::TRANSFER_LOG_COST)
+			}
+			Action::CreateClass => {
+				let weight = <Runtime as module_nft::Config>::WeightInfo::create_class();
+
+				WeightToGas::convert(weight).saturating_add(Self::CLASS_CREATED_LOG_COST)
+			}
+			Action::Mint => {
+				let quantity = input.u32_at(5)?;
+				let weight = <Runtime as module_nft::Config>::WeightInfo::mint(quantity);
+
+				WeightToGas::convert(weight).saturating_add(Self::TRANSFER_LOG_COST.saturating_mul(quantity.into()))
+			}
+			Action::Burn => {
+				let weight = <Runtime as module_nft::Config>::WeightInfo::burn();
+
+				WeightToGas::convert(weight).saturating_add(Self::TRANSFER_LOG_COST)
+			}
+			Action::BurnWithRemark => {
+				let remark_len = input.bytes_at(4)?.len() as u32;
+				let weight = <Runtime as module_nft::Config>::WeightInfo::burn_with_remark(remark_len);
+
+				WeightToGas::convert(weight).saturating_add(Self::TRANSFER_LOG_COST)
+			}
+			Action::UpdateClassProperties => {
+				let weight = <Runtime as module_nft::Config>::WeightInfo::update_class_properties();
+
+				WeightToGas::convert(weight)
+			}
+			Action::GetClassProperties | Action::GetClassAttribute => {
+				// OrmlNFT::Classes (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetTokenAttribute => {
+				// OrmlNFT::Tokens (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::precompile::mock::{
+		alice_evm_addr, bob, bob_evm_addr, new_test_ext, Balances, NFTModule, RuntimeOrigin, Test,
+	};
+	use frame_support::{
+		assert_ok,
+		storage::{with_transaction, TransactionOutcome},
+	};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+
+	type NFTPrecompile = super::NFTPrecompile<Test>;
+
+	fn execute(handle: &mut MockPrecompileHandle) -> PrecompileResult {
+		with_transaction(|| TransactionOutcome::Commit(Ok::<_, DispatchError>(NFTPrecompile::execute(handle)))).unwrap()
+	}
+
+	fn transfer_topics(from: H160, to: H160, token_id: u64) -> Vec<H256> {
+		vec![
+			H256::from(TRANSFER_EVENT),
+			H256::from(from),
+			H256::from(to),
+			H256::from_low_u64_be(token_id),
+		]
+	}
+
+	#[test]
+	fn create_class_mint_and_burn_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// createClass(address,bytes,uint8,bytes[],bytes[]) -> 0xc38f3351
+			// who
+			// offset of metadata
+			// properties: Transferable | Burnable | Mintable | ClassPropertiesMutable
+			// offset of attribute keys
+			// offset of attribute values
+			// metadata len
+			// metadata
+			// attribute keys len
+			// offset of key
+			// key len
+			// key
+			// attribute values len
+			// offset of value
+			// value len
+			// value
+			let input = hex! {"
+				c38f3351
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000000a0
+				00000000000000000000000000000000 0000000000000000000000000000000f
+				00000000000000000000000000000000 000000000000000000000000000000e0
+				00000000000000000000000000000000 00000000000000000000000000000160
+				00000000000000000000000000000000 00000000000000000000000000000003
+				61626300000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				6b000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				76000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(0u32));
+			assert_eq!(
+				handle.logs,
+				vec![(
+					H160::default(),
+					vec![
+						H256::from(CLASS_CREATED_EVENT),
+						H256::from(alice_evm_addr()),
+						H256::zero(),
+					],
+					vec![]
+				)]
+			);
+
+			// fund the class owner for the token deposits
+			let class_owner = NFTModule::collection_owner(&0).unwrap();
+			assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				class_owner.clone(),
+				1_000_000
+			));
+
+			// getClassProperties(uint256) -> 0xe6470059
+			// class_id
+			let input = hex! {"
+				e6470059
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(0x0fu8));
+
+			// getClassAttribute(uint256,bytes) -> 0x5d7975e7
+			// class_id
+			// offset of key
+			// key len
+			// key
+			let input = hex! {"
+				5d7975e7
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000001
+				6b000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_bytes(b"v"));
+
+			// mint(address,address,uint256,bytes,uint256,bytes[],bytes[]) -> 0xcaa1147b
+			// who
+			// to
+			// class_id
+			// offset of metadata
+			// quantity
+			// offset of attribute keys
+			// offset of attribute values
+			// metadata len
+			// metadata
+			// attribute keys len
+			// offset of key
+			// key len
+			// key
+			// attribute values len
+			// offset of value
+			// value len
+			// value
+			let input = hex! {"
+				caa1147b
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000000e0
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000120
+				00000000000000000000000000000000 000000000000000000000000000001a0
+				00000000000000000000000000000000 00000000000000000000000000000003
+				61626300000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				6b000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000001
+				76000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint_array(vec![0u64, 1u64]));
+			assert_eq!(
+				handle.logs,
+				vec![
+					(
+						H160::default(),
+						transfer_topics(H160::zero(), bob_evm_addr(), 0),
+						Output::encode_uint(0u32)
+					),
+					(
+						H160::default(),
+						transfer_topics(H160::zero(), bob_evm_addr(), 1),
+						Output::encode_uint(0u32)
+					),
+				]
+			);
+			assert_eq!(NFTModule::owner(&0, &0), Some(bob()));
+			assert_eq!(NFTModule::owner(&0, &1), Some(bob()));
+
+			// only the class owner or its proxy can mint
+			// attribute keys and values are empty
+			let input = hex! {"
+				caa1147b
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000000e0
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000120
+				00000000000000000000000000000000 00000000000000000000000000000140
+				00000000000000000000000000000000 00000000000000000000000000000003
+				61626300000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_eq!(
+				execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "NFT Mint failed: NoPermission".into(),
+				})
+			);
+
+			// getTokenAttribute(uint256,uint256,bytes) -> 0xe68e29bc
+			// class_id
+			// token_id
+			// offset of key
+			// key len
+			// key
+			let input = hex! {"
+				e68e29bc
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000060
+				00000000000000000000000000000000 00000000000000000000000000000001
+				6b000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_bytes(b"v"));
+
+			// burn(address,uint256,uint256) -> 0xf5298aca
+			// who
+			// class_id
+			// token_id
+			let input = hex! {"
+				f5298aca
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let mut handle = MockPrecompileHandle::new(&input, None, &context, false);
+			let resp = execute(&mut handle).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				handle.logs,
+				vec![(
+					H160::default(),
+					transfer_topics(bob_evm_addr(), H160::zero(), 0),
+					Output::encode_uint(0u32)
+				)]
+			);
+			assert_eq!(NFTModule::owner(&0, &0), None);
+			assert_eq!(NFTModule::balance(&bob()), 1);
+		});
 	}
 }
@@ -20,13 +20,19 @@ use super::{
 	input::{Input, InputPricer, InputT, Output},
 	weights::PrecompileWeights,
 };
-use crate::{Weight, WeightToGas};
+use crate::{TimeStampedPrice, Weight, WeightToGas};
+use frame_support::traits::Get;
 use module_evm::{
 	precompiles::Precompile, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileResult,
 };
-use module_support::{Erc20InfoMapping as Erc20InfoMappingT, PriceProvider as PriceProviderT};
+use module_support::{
+	DEXPriceProvider as DEXPriceProviderT, Erc20InfoMapping as Erc20InfoMappingT, Price,
+	PriceProvider as PriceProviderT,
+};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use sp_runtime::{traits::Convert, RuntimeDebug};
+use orml_traits::DataProviderExtended;
+use primitives::CurrencyId;
+use sp_runtime::{traits::Convert, FixedPointNumber, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// The `Oracle` impl precompile.
@@ -36,6 +42,10 @@ use sp_std::{marker::PhantomData, prelude::*};
 ///
 /// Actions:
 /// - Get price. Rest `input` bytes: `currency_id`.
+/// - Get price with timestamp. Rest `input` bytes: `currency_id`.
+/// - Get locked price. Rest `input` bytes: `currency_id`.
+/// - Get average DEX price. Rest `input` bytes: `base_currency_id`, `quote_currency_id`.
+/// - Get priority average DEX price. Rest `input` bytes: `base_currency_id`, `quote_currency_id`.
 pub struct OraclePrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -43,11 +53,16 @@ pub struct OraclePrecompile<R>(PhantomData<R>);
 #[repr(u32)]
 pub enum Action {
 	GetPrice = "getPrice(address)",
+	GetPriceWithTimestamp = "getPriceWithTimestamp(address)",
+	GetLockedPrice = "getLockedPrice(address)",
+	GetAverageDEXPrice = "getAverageDEXPrice(address,address)",
+	GetPriorityAverageDEXPrice = "getPriorityAverageDEXPrice(address,address)",
 }
 
 impl<Runtime> Precompile for OraclePrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config,
+	Runtime: module_evm::Config + module_prices::Config + module_dex_oracle::Config,
+	<Runtime as module_prices::Config>::Source: DataProviderExtended<CurrencyId, TimeStampedPrice>,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		let gas_cost = Pricer::<Runtime>::cost(handle)?;
@@ -62,48 +77,92 @@ where
 		match action {
 			Action::GetPrice => {
 				let currency_id = input.currency_id_at(1)?;
-				let mut price =
-					<module_prices::RealTimePriceProvider<Runtime>>::get_price(currency_id).unwrap_or_default();
-
-				let maybe_decimals = Runtime::Erc20InfoMapping::decimals(currency_id);
-				let decimals = match maybe_decimals {
-					Some(decimals) => decimals,
-					None => {
-						// If the option is none, let price = 0 to return 0.
-						// Solidity should handle the situation of price 0.
-						price = Default::default();
-						Default::default()
-					}
-				};
-
-				let maybe_adjustment_multiplier = 10u128.checked_pow((18u8.saturating_sub(decimals)).into());
-				let adjustment_multiplier = match maybe_adjustment_multiplier {
-					Some(adjustment_multiplier) => adjustment_multiplier,
-					None => {
-						// If the option is none, let price = 0 to return 0.
-						// Solidity should handle the situation of price 0.
-						price = Default::default();
-						Default::default()
-					}
-				};
-
-				let output = price.into_inner().wrapping_div(adjustment_multiplier);
-
-				log::debug!(target: "evm", "oracle: getPrice currency_id: {:?}, price: {:?}, adjustment_multiplier: {:?}, output: {:?}", currency_id, price, adjustment_multiplier, output);
+				let price = <module_prices::RealTimePriceProvider<Runtime>>::get_price(currency_id).unwrap_or_default();
+				let output = adjust_price::<Runtime>(currency_id, price);
+
+				log::debug!(target: "evm", "oracle: getPrice currency_id: {:?}, price: {:?}, output: {:?}", currency_id, price, output);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(output),
+				})
+			}
+			Action::GetPriceWithTimestamp => {
+				let currency_id = input.currency_id_at(1)?;
+				let price = <module_prices::RealTimePriceProvider<Runtime>>::get_price(currency_id).unwrap_or_default();
+				let output = adjust_price::<Runtime>(currency_id, price);
+
+				// The timestamp of the raw oracle feed, it's 0 if the price is not fed by oracle directly,
+				// such as the stable currency or the derived prices.
+				let timestamp = <Runtime as module_prices::Config>::Source::get_no_op(&currency_id)
+					.map(|v| v.timestamp)
+					.unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getPriceWithTimestamp currency_id: {:?}, price: {:?}, output: {:?}, timestamp: {:?}", currency_id, price, output, timestamp);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint_tuple(vec![output, timestamp.into()]),
+				})
+			}
+			Action::GetLockedPrice => {
+				let currency_id = input.currency_id_at(1)?;
+				let price = <module_prices::LockedPriceProvider<Runtime>>::get_price(currency_id).unwrap_or_default();
+				let output = adjust_price::<Runtime>(currency_id, price);
+
+				log::debug!(target: "evm", "oracle: getLockedPrice currency_id: {:?}, price: {:?}, output: {:?}", currency_id, price, output);
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					output: Output::encode_uint(output),
 				})
 			}
+			Action::GetAverageDEXPrice => {
+				let base = input.currency_id_at(1)?;
+				let quote = input.currency_id_at(2)?;
+				let price = <module_dex_oracle::AverageDEXPriceProvider<Runtime>>::get_relative_price(base, quote)
+					.unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getAverageDEXPrice base: {:?}, quote: {:?}, price: {:?}", base, quote, price);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(price.into_inner()),
+				})
+			}
+			Action::GetPriorityAverageDEXPrice => {
+				let base = input.currency_id_at(1)?;
+				let quote = input.currency_id_at(2)?;
+				let price =
+					<module_dex_oracle::PriorityAverageDEXPriceProvider<Runtime>>::get_relative_price(base, quote)
+						.unwrap_or_default();
+
+				log::debug!(target: "evm", "oracle: getPriorityAverageDEXPrice base: {:?}, quote: {:?}, price: {:?}", base, quote, price);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(price.into_inner()),
+				})
+			}
 		}
 	}
 }
 
+/// Convert the price for 1 basic unit to the price for 1 whole token with 18 decimals.
+/// Returns 0 if the decimals of `currency_id` is unknown. Solidity should handle the situation of price 0.
+fn adjust_price<Runtime>(currency_id: CurrencyId, price: Price) -> u128
+where
+	Runtime: module_evm::Config,
+{
+	let maybe_adjustment_multiplier = Runtime::Erc20InfoMapping::decimals(currency_id)
+		.and_then(|decimals| 10u128.checked_pow((18u8.saturating_sub(decimals)).into()));
+
+	match maybe_adjustment_multiplier {
+		Some(adjustment_multiplier) => price.into_inner().wrapping_div(adjustment_multiplier),
+		None => Default::default(),
+	}
+}
+
 struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config,
+	Runtime: module_evm::Config + module_prices::Config + module_dex_oracle::Config,
 {
 	const BASE_COST: u64 = 200;
 
@@ -121,6 +180,42 @@ where
 				let get_price = WeightToGas::convert(PrecompileWeights::<Runtime>::oracle_get_price());
 				WeightToGas::convert(Weight::from_parts(read_currency, 0)).saturating_add(get_price)
 			}
+			Action::GetPriceWithTimestamp => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+				let get_price = WeightToGas::convert(PrecompileWeights::<Runtime>::oracle_get_price());
+
+				// Oracle::Values (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_currency
+					.saturating_add(get_price)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetLockedPrice => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// AssetRegistry::AssetMetadatas (r: 1)
+				// Prices::LockedPrice (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetAverageDEXPrice | Action::GetPriorityAverageDEXPrice => {
+				let base = input.currency_id_at(1)?;
+				let quote = input.currency_id_at(2)?;
+				let read_base = InputPricer::<Runtime>::read_currency(base);
+				let read_quote = InputPricer::<Runtime>::read_currency(quote);
+
+				// DexOracle::AveragePrices (r: 1)
+				// DEX::LiquidityPool (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+
+				read_base
+					.saturating_add(read_quote)
+					.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(Self::BASE_COST.saturating_add(cost))
 	}
@@ -130,10 +225,14 @@ where
 mod tests {
 	use super::*;
 
-	use crate::precompile::mock::{alice_evm_addr, new_test_ext, Oracle, Price, Test, ALICE, DOT};
+	use crate::precompile::mock::{
+		alice_evm_addr, new_test_ext, DexModule, DexOracle, Oracle, Price, Prices, RuntimeOrigin, Test, ALICE, AUSD,
+		DOT,
+	};
 	use frame_support::{assert_noop, assert_ok};
 	use hex_literal::hex;
 	use module_evm::{precompiles::tests::MockPrecompileHandle, Context, ExitRevert};
+	use module_support::LockablePrice;
 	use orml_traits::DataFeeder;
 
 	type OraclePrecompile = crate::OraclePrecompile<Test>;
@@ -187,6 +286,167 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn get_price_with_timestamp_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getPriceWithTimestamp(address) -> 0xeeb9ef62
+			// DOT
+			let input = hex! {"
+				eeb9ef62
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// no price yet
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			assert_ok!(Oracle::feed_value(Some(ALICE), DOT, Price::from(30_000)));
+
+			// returned price and timestamp
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000065a4da25d3016c00000
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn get_locked_price_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getLockedPrice(address) -> 0x0b52fc29
+			// DOT
+			let input = hex! {"
+				0b52fc29
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			assert_ok!(Oracle::feed_value(Some(ALICE), DOT, Price::from(30_000)));
+
+			// not locked yet
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			assert_ok!(<Prices as LockablePrice<_>>::lock_price(DOT));
+
+			// returned the locked price
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000065a4da25d3016c00000
+			"};
+
+			let resp =
+				OraclePrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn get_average_dex_price_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// enable DOT/AUSD
+			assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AUSD,
+				1_000,
+				1_000_000,
+				0,
+				true
+			));
+
+			// getAverageDEXPrice(address,address) -> 0x23153487
+			// DOT
+			// AUSD
+			let average_input = hex! {"
+				23153487
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// getPriorityAverageDEXPrice(address,address) -> 0xec9164f7
+			// DOT
+			// AUSD
+			let priority_average_input = hex! {"
+				ec9164f7
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			let zero_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let price_output = hex! {"
+				00000000000000000000000000000000 000000000000003635c9adc5dea00000
+			"};
+
+			// average price is not enabled
+			let resp = OraclePrecompile::execute(&mut MockPrecompileHandle::new(&average_input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, zero_output.to_vec());
+
+			// fallback to the current price
+			let resp = OraclePrecompile::execute(&mut MockPrecompileHandle::new(
+				&priority_average_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, price_output.to_vec());
+
+			assert_ok!(DexOracle::enable_average_price(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AUSD,
+				12_000
+			));
+
+			let resp = OraclePrecompile::execute(&mut MockPrecompileHandle::new(&average_input, None, &context, false))
+				.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, price_output.to_vec());
+		});
+	}
+
 	#[test]
 	fn oracle_precompile_should_handle_invalid_input() {
 		new_test_ext().execute_with(|| {
@@ -44,6 +44,7 @@ fn precompile_filter_works_on_acala_precompiles() {
 			gas_used: 0,
 			context: &non_system_caller_context,
 			is_static: false,
+			logs: vec![],
 		};
 		assert_eq!(
 			PrecompilesValue::get().execute(&mut handle),
@@ -75,6 +76,7 @@ fn precompile_filter_does_not_work_on_system_contracts() {
 			gas_used: 0,
 			context: &non_system_caller_context,
 			is_static: false,
+			logs: vec![],
 		};
 		assert!(PrecompilesValue::get().execute(&mut handle).is_none());
 	});
@@ -100,7 +102,57 @@ fn precompile_filter_does_not_work_on_non_system_contracts() {
 			gas_used: 0,
 			context: &non_system_caller_context,
 			is_static: false,
+			logs: vec![],
 		};
 		assert!(PrecompilesValue::get().execute(&mut handle).is_none());
 	});
 }
+
+#[cfg(feature = "tracing")]
+#[test]
+fn precompile_events_are_recorded_in_call_trace() {
+	use crate::precompile::mock::{alice, RuntimeEvent, Test};
+	use module_evm::runner::{tracing, Runner};
+	use parity_scale_codec::Encode;
+	use primitives::evm::tracing::{TraceOutcome, TracerConfig};
+
+	new_test_ext().execute_with(|| {
+		// system contract without code
+		let caller = H160::from_low_u64_be(0x0900);
+
+		// developerEnable(address) -> 0x504eb6b5
+		// who: alice
+		let input = hex! {"
+			504eb6b5
+			000000000000000000000000 1000000000000000000000000000000000000001
+		"};
+
+		let mut tracer = tracing::Tracer::new(TracerConfig::CallTracer);
+		tracing::using(&mut tracer, || {
+			let info = <Test as module_evm::Config>::Runner::call(
+				caller,
+				caller,
+				EVM,
+				input.to_vec(),
+				0,
+				1_000_000,
+				0,
+				vec![],
+				<Test as module_evm::Config>::config(),
+			)
+			.unwrap();
+			assert!(info.exit_reason.is_succeed());
+		});
+
+		match tracer.finalize() {
+			TraceOutcome::Calls(calls) => {
+				assert_eq!(calls.len(), 1);
+				assert_eq!(calls[0].to, EVM);
+				assert!(calls[0].substrate_events.contains(
+					&RuntimeEvent::EVMModule(module_evm::Event::ContractDevelopmentEnabled { who: alice() }).encode()
+				));
+			}
+			_ => panic!("unexpected trace outcome"),
+		}
+	});
+}
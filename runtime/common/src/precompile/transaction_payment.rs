@@ -0,0 +1,485 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2024 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::input::{Input, InputPricer, InputT, Output};
+use crate::WeightToGas;
+use frame_support::traits::Get;
+use module_evm::{
+	precompiles::Precompile, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+use module_support::Erc20InfoMapping;
+use module_transaction_payment::WeightInfo;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sp_core::H160;
+use sp_runtime::{traits::Convert, FixedPointNumber, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The `TransactionPayment` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Query whether the charge fee pool is enabled. Rest `input` bytes: `currency_id`.
+/// - Query the charge fee pool size. Rest `input` bytes: `currency_id`.
+/// - Query the swap balance threshold of charge fee pool. Rest `input` bytes: `currency_id`.
+/// - Query the exchange rate of charge fee pool. Rest `input` bytes: `currency_id`.
+/// - Query the alternative fee swap path. Rest `input` bytes: `who`.
+/// - Set the alternative fee swap path. Rest `input` bytes: `who`, `path`.
+/// - Remove the alternative fee swap path. Rest `input` bytes: `who`.
+pub struct TransactionPaymentPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	IsChargeFeePoolEnabled = "isChargeFeePoolEnabled(address)",
+	GetFeePoolSize = "getFeePoolSize(address)",
+	GetSwapBalanceThreshold = "getSwapBalanceThreshold(address)",
+	GetTokenExchangeRate = "getTokenExchangeRate(address)",
+	GetAlternativeFeeSwapPath = "getAlternativeFeeSwapPath(address)",
+	SetAlternativeFeeSwapPath = "setAlternativeFeeSwapPath(address,address[])",
+	RemoveAlternativeFeeSwapPath = "removeAlternativeFeeSwapPath(address)",
+}
+
+impl<Runtime> Precompile for TransactionPaymentPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_transaction_payment::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let gas_cost = Pricer::<Runtime>::cost(handle)?;
+		handle.record_cost(gas_cost)?;
+
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			<Runtime as module_evm::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+
+		let action = input.action()?;
+
+		match action {
+			Action::IsChargeFeePoolEnabled => {
+				let currency_id = input.currency_id_at(1)?;
+				let enabled = module_transaction_payment::Pallet::<Runtime>::token_exchange_rate(currency_id).is_some();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_bool(enabled),
+				})
+			}
+			Action::GetFeePoolSize => {
+				let currency_id = input.currency_id_at(1)?;
+				let pool_size = module_transaction_payment::Pallet::<Runtime>::pool_size(currency_id);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(pool_size),
+				})
+			}
+			Action::GetSwapBalanceThreshold => {
+				let currency_id = input.currency_id_at(1)?;
+				let threshold = module_transaction_payment::Pallet::<Runtime>::swap_balance_threshold(currency_id);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(threshold),
+				})
+			}
+			Action::GetTokenExchangeRate => {
+				let currency_id = input.currency_id_at(1)?;
+				// The same rate used by `BuyWeightRateOfTransactionFeePool`, returns 0 if the charge fee pool is
+				// not enabled.
+				let rate =
+					module_transaction_payment::Pallet::<Runtime>::token_exchange_rate(currency_id).unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_uint(rate.into_inner()),
+				})
+			}
+			Action::GetAlternativeFeeSwapPath => {
+				let who = input.account_id_at(1)?;
+				let path = module_transaction_payment::Pallet::<Runtime>::alternative_fee_swap_path(&who)
+					.map(|path| path.into_inner())
+					.unwrap_or_default();
+
+				// dynamic gas cost calculation
+				// cost of reading path currencies
+				let cost = path
+					.iter()
+					.map(|x| InputPricer::<Runtime>::read_currency(*x))
+					.sum::<u64>();
+				handle.record_cost(cost)?;
+
+				let path: Vec<H160> = path
+					.into_iter()
+					.flat_map(<Runtime as module_evm::Config>::Erc20InfoMapping::encode_evm_address)
+					.collect();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: Output::encode_address_array(path),
+				})
+			}
+			Action::SetAlternativeFeeSwapPath => {
+				let who = input.account_id_at(1)?;
+				// solidity abi encode array will add an offset at input[2]
+				let path_len = input.u32_at(3)?;
+				let mut path = vec![];
+				for i in 0..path_len {
+					path.push(input.currency_id_at((4 + i) as usize)?);
+				}
+
+				log::debug!(
+					target: "evm",
+					"transaction payment: set_alternative_fee_swap_path who: {:?}, path: {:?}",
+					who, path
+				);
+
+				module_transaction_payment::Pallet::<Runtime>::do_set_alternative_fee_swap_path(&who, Some(path))
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("TransactionPayment SetAlternativeFeeSwapPath failed", e),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+			Action::RemoveAlternativeFeeSwapPath => {
+				let who = input.account_id_at(1)?;
+
+				log::debug!(
+					target: "evm",
+					"transaction payment: remove_alternative_fee_swap_path who: {:?}",
+					who
+				);
+
+				module_transaction_payment::Pallet::<Runtime>::do_set_alternative_fee_swap_path(&who, None).map_err(
+					|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("TransactionPayment RemoveAlternativeFeeSwapPath failed", e),
+					},
+				)?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: vec![],
+				})
+			}
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_transaction_payment::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(handle: &mut impl PrecompileHandle) -> Result<u64, PrecompileFailure> {
+		let input = Input::<
+			Action,
+			Runtime::AccountId,
+			<Runtime as module_evm::Config>::AddressMapping,
+			<Runtime as module_evm::Config>::Erc20InfoMapping,
+		>::new(handle.input());
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::IsChargeFeePoolEnabled
+			| Action::GetFeePoolSize
+			| Action::GetSwapBalanceThreshold
+			| Action::GetTokenExchangeRate => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// TransactionPayment::TokenExchangeRate/PoolSize/SwapBalanceThreshold (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetAlternativeFeeSwapPath => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+
+				// TransactionPayment::AlternativeFeeSwapPath (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SetAlternativeFeeSwapPath => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let path_len = input.u32_at(3)?;
+				let mut read_currency = 0u64;
+				for i in 0..path_len {
+					let currency_id = input.currency_id_at((4 + i) as usize)?;
+					read_currency = read_currency.saturating_add(InputPricer::<Runtime>::read_currency(currency_id));
+				}
+
+				let weight =
+					<Runtime as module_transaction_payment::Config>::WeightInfo::set_alternative_fee_swap_path();
+
+				cost.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::RemoveAlternativeFeeSwapPath => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight =
+					<Runtime as module_transaction_payment::Config>::WeightInfo::set_alternative_fee_swap_path();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{alice, alice_evm_addr, new_test_ext, Balances, Test, AUSD};
+	use crate::Ratio;
+	use frame_support::{assert_noop, traits::NamedReservableCurrency};
+	use hex_literal::hex;
+	use module_evm::{precompiles::tests::MockPrecompileHandle, Context};
+
+	type TransactionPaymentPrecompile = super::TransactionPaymentPrecompile<Test>;
+
+	#[test]
+	fn charge_fee_pool_queries_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// isChargeFeePoolEnabled(address) -> 0xd3bd11d3
+			// AUSD
+			let enabled_input = hex! {"
+				d3bd11d3
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// getFeePoolSize(address) -> 0x09e2a333
+			// AUSD
+			let pool_size_input = hex! {"
+				09e2a333
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// getSwapBalanceThreshold(address) -> 0x9b018007
+			// AUSD
+			let threshold_input = hex! {"
+				9b018007
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			// getTokenExchangeRate(address) -> 0x419aa50b
+			// AUSD
+			let rate_input = hex! {"
+				419aa50b
+				000000000000000000000000 0000000000000000000100000000000000000001
+			"};
+
+			let zero_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			for input in [&enabled_input, &pool_size_input, &threshold_input, &rate_input] {
+				let resp =
+					TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(input, None, &context, false))
+						.unwrap();
+				assert_eq!(resp.exit_status, ExitSucceed::Returned);
+				assert_eq!(resp.output, zero_output.to_vec());
+			}
+
+			module_transaction_payment::TokenExchangeRate::<Test>::insert(AUSD, Ratio::saturating_from_rational(1, 10));
+			module_transaction_payment::PoolSize::<Test>::insert(AUSD, 1_000);
+			module_transaction_payment::SwapBalanceThreshold::<Test>::insert(AUSD, 100);
+
+			let resp = TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(
+				&enabled_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				resp.output,
+				hex! {"00000000000000000000000000000000 00000000000000000000000000000001"}.to_vec()
+			);
+
+			let resp = TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(
+				&pool_size_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				resp.output,
+				hex! {"00000000000000000000000000000000 000000000000000000000000000003e8"}.to_vec()
+			);
+
+			let resp = TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(
+				&threshold_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				resp.output,
+				hex! {"00000000000000000000000000000000 00000000000000000000000000000064"}.to_vec()
+			);
+
+			let resp = TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(
+				&rate_input,
+				None,
+				&context,
+				false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				resp.output,
+				hex! {"00000000000000000000000000000000 0000000000000000016345785d8a0000"}.to_vec()
+			);
+		});
+	}
+
+	#[test]
+	fn alternative_fee_swap_path_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getAlternativeFeeSwapPath(address) -> 0xa644cd96
+			// who
+			let get_input = hex! {"
+				a644cd96
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			// empty array
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let resp = TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(
+				&get_input, None, &context, false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// setAlternativeFeeSwapPath(address,address[]) -> 0xb9dba762
+			// who
+			// offset
+			// path_len
+			// AUSD
+			// ACA
+			let input = hex! {"
+				b9dba762
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+
+			let resp =
+				TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+					.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				Balances::reserved_balance_named(&module_transaction_payment::DEPOSIT_ID, &alice()),
+				1
+			);
+
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000020
+				00000000000000000000000000000000 00000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+
+			let resp = TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(
+				&get_input, None, &context, false,
+			))
+			.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// removeAlternativeFeeSwapPath(address) -> 0x67a379d6
+			// who
+			let input = hex! {"
+				67a379d6
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let resp =
+				TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false))
+					.unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				Balances::reserved_balance_named(&module_transaction_payment::DEPOSIT_ID, &alice()),
+				0
+			);
+			assert_eq!(
+				module_transaction_payment::Pallet::<Test>::alternative_fee_swap_path(&alice()),
+				None
+			);
+
+			// setAlternativeFeeSwapPath(address,address[]) -> 0xb9dba762
+			// who
+			// offset
+			// path_len
+			// ACA
+			let input = hex! {"
+				b9dba762
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000040
+				00000000000000000000000000000000 00000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000000
+			"};
+
+			assert_noop!(
+				TransactionPaymentPrecompile::execute(&mut MockPrecompileHandle::new(&input, None, &context, false)),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "TransactionPayment SetAlternativeFeeSwapPath failed: InvalidSwapPath".into(),
+				}
+			);
+		});
+	}
+}
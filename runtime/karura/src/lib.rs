@@ -51,6 +51,7 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 use frame_system::{EnsureRoot, EnsureSigned, RawOrigin};
+use module_aggregated_dex::AggregatedDexTask;
 use module_asset_registry::{AssetIdMaps, EvmErc20InfoMapping};
 use module_cdp_engine::CollateralCurrencyIds;
 use module_currencies::BasicCurrencyAdapter;
@@ -916,6 +917,7 @@ parameter_types! {
 	pub const GetLiquidCurrencyId: CurrencyId = LKSM;
 	pub const GetStakingCurrencyId: CurrencyId = KSM;
 	pub Erc20HoldingAccount: H160 = primitives::evm::ERC20_HOLDING_ACCOUNT;
+	pub AllowanceDeposit: Balance = deposit(1, 96);
 }
 
 impl module_currencies::Config for Runtime {
@@ -930,6 +932,8 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, KaruraTreasuryAccount>;
+	type DepositCurrency = Balances;
+	type DepositPerAllowance = AllowanceDeposit;
 }
 
 parameter_types! {
@@ -1185,14 +1189,30 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
 	type OnLiquidityPoolUpdated = ();
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type FlashSwapEvmBridge = module_evm_bridge::FlashSwapEvmBridge<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxOrderDuration: BlockNumber = 30 * DAYS;
+	pub OrderDeposit: Balance = deposit(1, 256);
 }
 
 impl module_aggregated_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
 	type DEX = Dex;
 	type StableAsset = RebasedStableAsset;
 	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxOrdersPerAccount = ConstU32<20>;
+	type MaxOrderDuration = MaxOrderDuration;
+	type OrderDeposit = OrderDeposit;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type DexTradingPathLimit = TradingPathLimit;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
 }
 
@@ -1709,6 +1729,7 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		AggregatedDexTask(AggregatedDexTask<Runtime>),
 	}
 }
 
@@ -2318,6 +2339,14 @@ impl_runtime_apis! {
 
 			Self::create(from, data, value, gas_limit, storage_limit, access_list, estimate)
 		}
+
+		fn contract_storage_info(address: H160) -> Option<primitives::evm::ContractStorageInfo<Balance>> {
+			if EVM::is_contract(&address) {
+				Some(EVM::contract_storage_info(&address))
+			} else {
+				None
+			}
+		}
 	}
 
 	#[cfg(feature = "tracing")]
@@ -2331,6 +2360,58 @@ impl_runtime_apis! {
 				Executive::apply_extrinsic(extrinsic)
 			}).map(|_| tracer.finalize())
 		}
+
+		fn trace_call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<module_evm::runner::tracing::TraceOutcome, sp_runtime::DispatchError> {
+			let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config);
+			module_evm::runner::tracing::using(&mut tracer, || {
+				// same as ExecutionMode::View, discard any state changes
+				frame_support::storage::with_transaction(|| {
+					let result = <Runtime as module_evm::Config>::Runner::rpc_call(
+						from,
+						from,
+						to,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+						<Runtime as module_evm::Config>::config(),
+					);
+					sp_runtime::TransactionOutcome::Rollback(result)
+				})
+			}).map(|_| tracer.finalize())
+		}
+
+		fn trace_block(
+			block: Block,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<Vec<(u32, module_evm::runner::tracing::TraceOutcome)>, sp_runtime::transaction_validity::TransactionValidityError> {
+			let (header, extrinsics) = block.deconstruct();
+			Executive::initialize_block(&header);
+
+			let mut traces = Vec::new();
+			for (index, extrinsic) in extrinsics.into_iter().enumerate() {
+				let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config.clone());
+				module_evm::runner::tracing::using(&mut tracer, || {
+					Executive::apply_extrinsic(extrinsic)
+				})?;
+				// skip extrinsics which didn't touch the EVM
+				let outcome = tracer.finalize();
+				if !outcome.is_empty() {
+					traces.push((index as u32, outcome));
+				}
+			}
+			Ok(traces)
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
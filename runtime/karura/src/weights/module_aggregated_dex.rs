@@ -106,4 +106,91 @@ impl<T: frame_system::Config> module_aggregated_dex::WeightInfo for WeightInfo<T
 			.saturating_add(Weight::from_parts(1_408_390, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
+	// Storage: `AggregatedDex::Orders` (r:21 w:1)
+	// Proof: `AggregatedDex::Orders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:0)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `AggregatedDex::NextOrderId` (r:1 w:1)
+	// Proof: `AggregatedDex::NextOrderId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `IdleScheduler::NextTaskId` (r:1 w:1)
+	// Proof: `IdleScheduler::NextTaskId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `IdleScheduler::Tasks` (r:0 w:1)
+	// Proof: `IdleScheduler::Tasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Reserves` (r:1 w:1)
+	// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	fn place_limit_order() -> Weight {
+		// Estimated including the deposit and the quote of the paths, not benchmarked yet.
+		Weight::from_parts(68_214_000, 18092)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: `AggregatedDex::Orders` (r:21 w:1)
+	// Proof: `AggregatedDex::Orders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::TradingPairStatuses` (r:1 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:1 w:0)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `AggregatedDex::NextOrderId` (r:1 w:1)
+	// Proof: `AggregatedDex::NextOrderId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `IdleScheduler::NextTaskId` (r:1 w:1)
+	// Proof: `IdleScheduler::NextTaskId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `IdleScheduler::Tasks` (r:0 w:1)
+	// Proof: `IdleScheduler::Tasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Reserves` (r:1 w:1)
+	// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	fn place_twap_order() -> Weight {
+		// Estimated including the deposit and the quote of the paths, not benchmarked yet.
+		Weight::from_parts(68_637_000, 18092)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: `AggregatedDex::Orders` (r:1 w:1)
+	// Proof: `AggregatedDex::Orders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Reserves` (r:1 w:1)
+	// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(270), added: 2745, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Estimated including the deposit, not benchmarked yet.
+		Weight::from_parts(38_120_000, 10416)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `AggregatedDex::Orders` (r:1 w:0)
+	// Proof: `AggregatedDex::Orders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Dex::TradingPairStatuses` (r:3 w:0)
+	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ExchangeFees` (r:3 w:0)
+	// Proof: `Dex::ExchangeFees` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	// Storage: `Dex::LiquidityPool` (r:3 w:0)
+	// Proof: `Dex::LiquidityPool` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ConcentratedPools` (r:3 w:0)
+	// Proof: `Dex::ConcentratedPools` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[2, 4]`.
+	fn match_order(u: u32, ) -> Weight {
+		// Estimated from five failed quotes of `swap_with_exact_supply` by the paths, not benchmarked yet.
+		Weight::from_parts(21_583_000, 3534)
+			.saturating_add(Weight::from_parts(36_000_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 2670).saturating_mul(u.into()))
+	}
 }
@@ -17,9 +17,12 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{dollar, inject_liquidity, set_balance, LIQUID, NATIVE, STABLECOIN, STAKING};
-use crate::{AccountId, CurrencyId, Runtime};
-use module_aggregated_dex::SwapPath;
+use crate::{AccountId, AggregatedDex, CurrencyId, Runtime, RuntimeOrigin, System};
+use frame_support::{traits::Get, weights::Weight};
+use module_aggregated_dex::{AggregatedDexTask, SwapPath};
+use module_support::{DispatchableTask, Price};
 use runtime_common::{BNC, VSKSM};
+use sp_runtime::{traits::One, FixedPointNumber};
 
 use sp_std::prelude::*;
 
@@ -30,6 +33,20 @@ use orml_benchmarking::runtime_benchmarks;
 
 const CURRENCY_LIST: [CurrencyId; 6] = [NATIVE, STABLECOIN, LIQUID, STAKING, BNC, VSKSM];
 
+fn place_orders(who: &AccountId, count: u32) -> Result<(), &'static str> {
+	let path = vec![SwapPath::Dex(vec![NATIVE, STABLECOIN])];
+	for _ in 0..count {
+		AggregatedDex::place_limit_order(
+			RuntimeOrigin::signed(who.clone()),
+			path.clone(),
+			dollar(NATIVE),
+			Price::saturating_from_integer(1_000),
+			System::block_number() + 100,
+		)?;
+	}
+	Ok(())
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_aggregated_dex }
 
@@ -89,6 +106,71 @@ runtime_benchmarks! {
 			);
 		}
 	}: _(RawOrigin::Root, updates)
+
+	place_limit_order {
+		let maker: AccountId = account("maker", 0, 0);
+		let taker: AccountId = whitelisted_caller();
+		inject_liquidity(maker, NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN), false)?;
+		set_balance(NATIVE, &taker, 10_000 * dollar(NATIVE));
+		let max_orders: u32 = <Runtime as module_aggregated_dex::Config>::MaxOrdersPerAccount::get();
+		place_orders(&taker, max_orders - 1)?;
+	}: _(RawOrigin::Signed(taker), vec![SwapPath::Dex(vec![NATIVE, STABLECOIN])], 100 * dollar(NATIVE), Price::one(), System::block_number() + 100)
+
+	place_twap_order {
+		let maker: AccountId = account("maker", 0, 0);
+		let taker: AccountId = whitelisted_caller();
+		inject_liquidity(maker, NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN), false)?;
+		set_balance(NATIVE, &taker, 10_000 * dollar(NATIVE));
+		let max_orders: u32 = <Runtime as module_aggregated_dex::Config>::MaxOrdersPerAccount::get();
+		place_orders(&taker, max_orders - 1)?;
+	}: _(RawOrigin::Signed(taker), vec![SwapPath::Dex(vec![NATIVE, STABLECOIN])], 100 * dollar(NATIVE), 10 * dollar(NATIVE), 10, Price::one(), System::block_number() + 100)
+
+	cancel_order {
+		let maker: AccountId = account("maker", 0, 0);
+		let taker: AccountId = whitelisted_caller();
+		inject_liquidity(maker, NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN), false)?;
+		set_balance(NATIVE, &taker, 10_000 * dollar(NATIVE));
+		place_orders(&taker, 1)?;
+		let order_id = AggregatedDex::next_order_id() - 1;
+	}: _(RawOrigin::Signed(taker), order_id)
+
+	// the worst case that all the attempts to find the fill amount fail on every hop of the path
+	match_order {
+		let u in 2 .. <Runtime as module_dex::Config>::TradingPathLimit::get();
+
+		let maker: AccountId = account("maker", 0, 0);
+		let taker: AccountId = whitelisted_caller();
+
+		let mut path: Vec<CurrencyId> = vec![];
+		for i in 1 .. u {
+			if i == 1 {
+				let cur0 = CURRENCY_LIST[0];
+				let cur1 = CURRENCY_LIST[1];
+				path.push(cur0);
+				path.push(cur1);
+				inject_liquidity(maker.clone(), cur0, cur1, 10_000 * dollar(cur0), 10_000 * dollar(cur1), false)?;
+			} else {
+				path.push(CURRENCY_LIST[i as usize]);
+				inject_liquidity(maker.clone(), CURRENCY_LIST[i as usize - 1], CURRENCY_LIST[i as usize], 10_000 * dollar(CURRENCY_LIST[i as usize - 1]), 10_000 * dollar(CURRENCY_LIST[i as usize]), false)?;
+			}
+		}
+
+		set_balance(path[0], &taker, 10_000 * dollar(path[0]));
+		AggregatedDex::place_limit_order(
+			RuntimeOrigin::signed(taker.clone()),
+			vec![SwapPath::Dex(path.clone())],
+			100 * dollar(path[0]),
+			Price::saturating_from_integer(1_000),
+			System::block_number() + 100,
+		)?;
+		let order_id = AggregatedDex::next_order_id() - 1;
+		let task = AggregatedDexTask::<Runtime>::MatchOrder { owner: taker.clone(), order_id };
+	}: {
+		let _ = task.dispatch(Weight::MAX);
+	}
+	verify {
+		assert_eq!(AggregatedDex::orders(&taker, order_id).map(|order| order.filled_supply_amount), Some(0));
+	}
 }
 
 #[cfg(test)]
@@ -17,15 +17,22 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{dollar, inject_liquidity, LIQUID, NATIVE, STABLECOIN, STAKING};
-use crate::{AccountId, Currencies, CurrencyId, Dex, ExtendedProvisioningBlocks, Runtime, RuntimeEvent, System};
+use crate::{
+	AccountId, Currencies, CurrencyId, Dex, ExtendedProvisioningBlocks, Price, Runtime, RuntimeEvent, System,
+	TreasuryAccount,
+};
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{TradingPairStatus, MAX_CONCENTRATED_POSITION_TICKS, MAX_CONCENTRATED_SWAP_TICKS};
+use module_support::Ratio;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::TradingPair;
 use runtime_common::{BNC, VSKSM};
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{
+	traits::{One, UniqueSaturatedInto, Zero},
+	FixedPointNumber,
+};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -358,6 +365,100 @@ runtime_benchmarks! {
 
 		System::set_block_number(ExtendedProvisioningBlocks::get() + 1);
 	}: _(RawOrigin::Signed(whitelisted_caller()), trading_pair.first(), trading_pair.second())
+
+	update_exchange_fee {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some((1, 1000)))
+	verify {
+		assert_last_event(module_dex::Event::UpdateExchangeFee{trading_pair, exchange_fee: Some((1, 1000))}.into());
+	}
+
+	// update protocol fee rate of a trading pair which has accrued protocol fee
+	update_protocol_fee_rate {
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		inject_liquidity(maker, trading_pair.first(), trading_pair.second(), 10_000 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), false)?;
+		Dex::update_protocol_fee_rate(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), Some((1, 6)))?;
+
+		// swap to accrue exchange fee
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &taker, (1_000 * dollar(trading_pair.first())).unique_saturated_into())?;
+		Dex::swap_with_exact_supply(RawOrigin::Signed(taker).into(), vec![trading_pair.first(), trading_pair.second()], 1_000 * dollar(trading_pair.first()), 0)?;
+		assert!(!Dex::protocol_fee_k_last(trading_pair).is_zero());
+		assert!(Currencies::free_balance(trading_pair.dex_share_currency_id(), &TreasuryAccount::get()).is_zero());
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some((1, 5)))
+	verify {
+		assert_last_event(module_dex::Event::UpdateProtocolFeeRate{trading_pair, protocol_fee_rate: Some((1, 5))}.into());
+		// the accrued protocol fee has been minted
+		assert!(!Currencies::free_balance(trading_pair.dex_share_currency_id(), &TreasuryAccount::get()).is_zero());
+	}
+
+	create_concentrated_pool {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Price::one(), Ratio::saturating_from_rational(1, 1000))
+	verify {
+		assert_last_event(module_dex::Event::ConcentratedPoolCreated{trading_pair, base_price: Price::one(), tick_step: Ratio::saturating_from_rational(1, 1000)}.into());
+	}
+
+	// add liquidity to t ticks of concentrated liquidity pool
+	add_concentrated_liquidity {
+		let t in 1 .. MAX_CONCENTRATED_POSITION_TICKS;
+
+		let maker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		let lower_tick = -((t / 2) as i32);
+		let upper_tick = lower_tick + t as i32 - 1;
+		let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second());
+		Dex::create_concentrated_pool(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), Price::one(), Ratio::saturating_from_rational(1, 1000))?;
+
+		// set balance
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &maker, (10_000 * dollar(trading_pair.first())).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &maker, (10_000 * dollar(trading_pair.second())).unique_saturated_into())?;
+	}: _(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), 10_000 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), lower_tick, upper_tick)
+
+	// remove liquidity from t ticks of concentrated liquidity pool
+	remove_concentrated_liquidity {
+		let t in 1 .. MAX_CONCENTRATED_POSITION_TICKS;
+
+		let maker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		let lower_tick = -((t / 2) as i32);
+		let upper_tick = lower_tick + t as i32 - 1;
+		let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second());
+		Dex::create_concentrated_pool(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), Price::one(), Ratio::saturating_from_rational(1, 1000))?;
+
+		// set balance
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &maker, (10_000 * dollar(trading_pair.first())).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &maker, (10_000 * dollar(trading_pair.second())).unique_saturated_into())?;
+		Dex::add_concentrated_liquidity(RawOrigin::Signed(maker.clone()).into(), trading_pair.first(), trading_pair.second(), 10_000 * dollar(trading_pair.first()), 10_000 * dollar(trading_pair.second()), lower_tick, upper_tick)?;
+	}: _(RawOrigin::Signed(maker), trading_pair.first(), trading_pair.second(), lower_tick, upper_tick, 0, 0)
+
+	// swap through t ticks of concentrated liquidity pool
+	concentrated_swap {
+		let t in 1 .. MAX_CONCENTRATED_SWAP_TICKS;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second());
+		Dex::create_concentrated_pool(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), Price::one(), Ratio::saturating_from_rational(1, 1000))?;
+
+		// put token 1 to the t ticks not above the current tick, so that the swap must go through all of them
+		let liquidity_per_tick = 1_000 * dollar(trading_pair.second());
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &maker, (liquidity_per_tick * t as u128).unique_saturated_into())?;
+		Dex::add_concentrated_liquidity(RawOrigin::Signed(maker).into(), trading_pair.first(), trading_pair.second(), 0, liquidity_per_tick * t as u128, -(t as i32 - 1), 0)?;
+
+		// the protocol fee is charged from the swap
+		Dex::update_protocol_fee_rate(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), Some((1, 5)))?;
+
+		// set balance
+		let max_supply_amount = 2_000 * dollar(trading_pair.first()) * t as u128;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &taker, max_supply_amount.unique_saturated_into())?;
+	}: swap_with_exact_target(RawOrigin::Signed(taker), vec![trading_pair.first(), trading_pair.second()], liquidity_per_tick * t as u128, max_supply_amount)
+	verify {
+		assert_eq!(Dex::concentrated_ticks(trading_pair, -(t as i32 - 1)).reserve_1, 0);
+		assert!(!Currencies::free_balance(trading_pair.first(), &TreasuryAccount::get()).is_zero());
+	}
 }
 
 #[cfg(test)]
@@ -20,12 +20,12 @@ use crate::{AccountId, EvmAccounts, Runtime, RuntimeEvent, RuntimeOrigin, System
 
 use super::utils::{dollar, set_balance, NATIVE};
 use frame_system::RawOrigin;
-use module_evm::MaxCodeSize;
+use module_evm::{BatchCallMode, MaxBatchCalls, MaxCodeSize};
 use module_support::AddressMapping;
 use orml_benchmarking::{runtime_benchmarks, whitelist_account};
 use sp_core::{H160, H256};
 use sp_io::hashing::keccak_256;
-use sp_runtime::DispatchError;
+use sp_runtime::{BoundedVec, DispatchError};
 use sp_std::{str::FromStr, vec};
 
 fn contract_addr() -> H160 {
@@ -155,6 +155,22 @@ runtime_benchmarks! {
 		assert!(module_evm::Codes::<Runtime>::contains_key(code_hash));
 	}
 
+	create_deterministic_predeploy_contract {
+		let account_id = <Runtime as module_evm::Config>::TreasuryAccount::get();
+		set_balance(NATIVE, &account_id, 1_000_000 * dollar(NATIVE));
+		let salt = H256::repeat_byte(1);
+		let address = module_evm::create2_address(
+			<Runtime as module_evm::Config>::NetworkContractSource::get(),
+			salt,
+			module_evm::code_hash(&EMPTY_CONTRACT),
+		);
+	}: _(RawOrigin::Root, salt, EMPTY_CONTRACT.to_vec(), 0, 2_100_000, 15_000, vec![])
+	verify {
+		let code_hash = EVM::code_hash_at_address(&address);
+		assert!(module_evm::Codes::<Runtime>::contains_key(code_hash));
+		assert!(module_evm::DeterministicDeployments::<Runtime>::contains_key(address));
+	}
+
 	call {
 		// Storage.store(1)
 		let input = hex_literal::hex!("6057361d0000000000000000000000000000000000000000000000000000000000000001").to_vec();
@@ -178,6 +194,33 @@ runtime_benchmarks! {
 		assert_eq!(module_evm::AccountStorages::<Runtime>::get(&contract_address, H256::zero()), H256::from_low_u64_be(1));
 	}
 
+	batch_call {
+		let c in 1..MaxBatchCalls::get();
+		let b in 0..65_536;
+
+		// Storage.store(1), the extra input is ignored by the contract
+		let mut input = hex_literal::hex!("6057361d0000000000000000000000000000000000000000000000000000000000000001").to_vec();
+		let alice_account = alice_account_id();
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+
+		// contract address when it gets deployed
+		let contract_address = H160::from(hex_literal::hex!("5e0b4bfa0b55932a3587e648c3552a6515ba56b1"));
+
+		frame_support::assert_ok!(EVM::create(RuntimeOrigin::signed(alice_account.clone()), STORAGE_CONTRACT.to_vec(), 0, 21_000_000, 100_000, vec![]));
+
+		// Storage::number
+		let hashed_key = module_evm::AccountStorages::<Runtime>::hashed_key_for(&contract_address, H256::zero());
+		frame_benchmarking::benchmarking::add_to_whitelist(hashed_key.into());
+
+		let mut calls = vec![(contract_address, input.clone(), 0); c as usize];
+		input.resize(input.len() + b as usize, 0);
+		calls[0].1 = input;
+		let calls: BoundedVec<_, MaxBatchCalls> = calls.try_into().unwrap();
+	}: _(RawOrigin::Signed(alice_account), calls, BatchCallMode::Atomic, 21_000_000, 100_000, vec![])
+	verify {
+		assert_eq!(module_evm::AccountStorages::<Runtime>::get(&contract_address, H256::zero()), H256::from_low_u64_be(1));
+	}
+
 	transfer_maintainer {
 		let alice_account = alice_account_id();
 
@@ -47,6 +47,7 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned, RawOrigin};
+use module_aggregated_dex::AggregatedDexTask;
 use module_asset_registry::{AssetIdMaps, EvmErc20InfoMapping};
 use module_cdp_engine::CollateralCurrencyIds;
 use module_currencies::BasicCurrencyAdapter;
@@ -954,6 +955,7 @@ parameter_types! {
 	pub const GetNativeCurrencyId: CurrencyId = ACA;
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub Erc20HoldingAccount: H160 = primitives::evm::ERC20_HOLDING_ACCOUNT;
+	pub AllowanceDeposit: Balance = deposit(1, 96);
 }
 
 impl module_currencies::Config for Runtime {
@@ -968,6 +970,8 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
+	type DepositCurrency = Balances;
+	type DepositPerAllowance = AllowanceDeposit;
 }
 
 pub struct EnsureRootOrTreasury;
@@ -1224,14 +1228,30 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
 	type OnLiquidityPoolUpdated = ();
+	type TreasuryAccount = TreasuryAccount;
+	type FlashSwapEvmBridge = module_evm_bridge::FlashSwapEvmBridge<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxOrderDuration: BlockNumber = 30 * DAYS;
+	pub OrderDeposit: Balance = deposit(1, 256);
 }
 
 impl module_aggregated_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
 	type DEX = Dex;
 	type StableAsset = RebasedStableAsset;
 	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxOrdersPerAccount = ConstU32<20>;
+	type MaxOrderDuration = MaxOrderDuration;
+	type OrderDeposit = OrderDeposit;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type DexTradingPathLimit = TradingPathLimit;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
 }
 
@@ -1701,6 +1721,8 @@ impl<I: From<Balance>> frame_support::traits::Get<I> for TxFeePerGasV2 {
 #[cfg(feature = "with-ethereum-compatibility")]
 static SHANGHAI_CONFIG: module_evm_utility::evm::Config = module_evm_utility::evm::Config::shanghai();
 
+static CANCUN_FEATURES: module_evm::EvmFeatures = module_evm::EvmFeatures::cancun();
+
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type Currency = Balances;
@@ -1731,6 +1753,10 @@ impl module_evm::Config for Runtime {
 	fn config() -> &'static module_evm_utility::evm::Config {
 		&SHANGHAI_CONFIG
 	}
+
+	fn features() -> &'static module_evm::EvmFeatures {
+		&CANCUN_FEATURES
+	}
 }
 
 impl module_evm_bridge::Config for Runtime {
@@ -1844,6 +1870,7 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		AggregatedDexTask(AggregatedDexTask<Runtime>),
 	}
 }
 
@@ -2493,6 +2520,14 @@ impl_runtime_apis! {
 
 			Self::create(from, data, value, gas_limit, storage_limit, access_list, estimate)
 		}
+
+		fn contract_storage_info(address: H160) -> Option<primitives::evm::ContractStorageInfo<Balance>> {
+			if EVM::is_contract(&address) {
+				Some(EVM::contract_storage_info(&address))
+			} else {
+				None
+			}
+		}
 	}
 
 	#[cfg(feature = "tracing")]
@@ -2506,6 +2541,58 @@ impl_runtime_apis! {
 				Executive::apply_extrinsic(extrinsic)
 			}).map(|_| tracer.finalize())
 		}
+
+		fn trace_call(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<module_evm::runner::tracing::TraceOutcome, sp_runtime::DispatchError> {
+			let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config);
+			module_evm::runner::tracing::using(&mut tracer, || {
+				// same as ExecutionMode::View, discard any state changes
+				frame_support::storage::with_transaction(|| {
+					let result = <Runtime as module_evm::Config>::Runner::rpc_call(
+						from,
+						from,
+						to,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+						<Runtime as module_evm::Config>::config(),
+					);
+					sp_runtime::TransactionOutcome::Rollback(result)
+				})
+			}).map(|_| tracer.finalize())
+		}
+
+		fn trace_block(
+			block: Block,
+			tracer_config: primitives::evm::tracing::TracerConfig,
+		) -> Result<Vec<(u32, module_evm::runner::tracing::TraceOutcome)>, sp_runtime::transaction_validity::TransactionValidityError> {
+			let (header, extrinsics) = block.deconstruct();
+			Executive::initialize_block(&header);
+
+			let mut traces = Vec::new();
+			for (index, extrinsic) in extrinsics.into_iter().enumerate() {
+				let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config.clone());
+				module_evm::runner::tracing::using(&mut tracer, || {
+					Executive::apply_extrinsic(extrinsic)
+				})?;
+				// skip extrinsics which didn't touch the EVM
+				let outcome = tracer.finalize();
+				if !outcome.is_empty() {
+					traces.push((index as u32, outcome));
+				}
+			}
+			Ok(traces)
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -2875,4 +2962,180 @@ mod tests {
 		let block_weight = RuntimeBlockWeights::get().max_block.div(3).mul(2);
 		assert!(weight.all_lt(block_weight));
 	}
+
+	#[cfg(feature = "tracing")]
+	#[test]
+	fn trace_call_works() {
+		use module_evm_rpc_runtime_api::runtime_decl_for_evm_trace_api::EVMTraceApiV2;
+		use primitives::evm::tracing::{CallType, FourByteCall, TraceOutcome, TracerConfig};
+
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let from = H160::from_low_u64_be(1);
+			let to = H160::from_low_u64_be(2);
+
+			let outcome = Runtime::trace_call(
+				from,
+				to,
+				vec![1, 2, 3, 4],
+				0,
+				100_000,
+				0,
+				None,
+				TracerConfig::CallTracer,
+			)
+			.unwrap();
+			match outcome {
+				TraceOutcome::Calls(calls) => {
+					assert_eq!(calls.len(), 1);
+					assert_eq!(calls[0].call_type, CallType::CALL);
+					assert_eq!(calls[0].from, from);
+					assert_eq!(calls[0].to, to);
+					assert_eq!(calls[0].input, vec![1, 2, 3, 4]);
+					assert_eq!(calls[0].depth, 0);
+				}
+				_ => panic!("unexpected trace outcome"),
+			}
+
+			let outcome = Runtime::trace_call(
+				from,
+				to,
+				vec![1, 2, 3, 4, 5],
+				0,
+				100_000,
+				0,
+				None,
+				TracerConfig::FourByteTracer,
+			)
+			.unwrap();
+			match outcome {
+				TraceOutcome::FourByte(trace) => {
+					assert_eq!(
+						trace.calls,
+						vec![FourByteCall {
+							selector: [1, 2, 3, 4],
+							calldata_size: 1,
+							count: 1,
+						}]
+					);
+					assert_eq!(trace.gas.len(), 1);
+					assert_eq!(trace.gas[0].selector, [1, 2, 3, 4]);
+				}
+				_ => panic!("unexpected trace outcome"),
+			}
+		});
+	}
+
+	#[cfg(feature = "tracing")]
+	#[test]
+	fn trace_block_skips_extrinsics_without_evm_traces() {
+		use module_evm_rpc_runtime_api::runtime_decl_for_evm_trace_api::EVMTraceApiV2;
+		use primitives::evm::tracing::TracerConfig;
+		use sp_runtime::traits::Header as HeaderT;
+
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let header = Header::new(
+				1,
+				Default::default(),
+				Default::default(),
+				Default::default(),
+				Default::default(),
+			);
+			let timestamp = <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::new(
+				RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 1 }),
+				None,
+			)
+			.unwrap();
+
+			assert_eq!(
+				Runtime::trace_block(Block::new(header.clone(), vec![]), TracerConfig::CallTracer),
+				Ok(vec![])
+			);
+			assert_eq!(
+				Runtime::trace_block(Block::new(header, vec![timestamp]), TracerConfig::FourByteTracer),
+				Ok(vec![])
+			);
+		});
+	}
+
+	#[cfg(feature = "tracing")]
+	#[test]
+	fn trace_block_returns_evm_traces_at_extrinsic_index() {
+		use module_evm_rpc_runtime_api::runtime_decl_for_evm_trace_api::EVMTraceApiV2;
+		use primitives::evm::tracing::{CallType, TraceOutcome, TracerConfig};
+		use sp_keyring::AccountKeyring;
+		use sp_runtime::traits::{Extrinsic, Header as HeaderT};
+
+		let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		t.execute_with(|| {
+			let alice: AccountId = AccountKeyring::Alice.to_account_id();
+			let _ =
+				<Balances as frame_support::traits::Currency<AccountId>>::deposit_creating(&alice, 100 * dollar(ACA));
+			let target = H160::from_low_u64_be(2);
+
+			let header = Header::new(
+				1,
+				Default::default(),
+				Default::default(),
+				Default::default(),
+				Default::default(),
+			);
+			let timestamp =
+				UncheckedExtrinsic::new(RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 1 }), None).unwrap();
+
+			let call = RuntimeCall::EVM(module_evm::Call::call {
+				target,
+				input: vec![1, 2, 3, 4],
+				value: 0,
+				gas_limit: 100_000,
+				storage_limit: 0,
+				access_list: vec![],
+			});
+			let extra: SignedExtra = (
+				frame_system::CheckNonZeroSender::<Runtime>::new(),
+				frame_system::CheckSpecVersion::<Runtime>::new(),
+				frame_system::CheckTxVersion::<Runtime>::new(),
+				frame_system::CheckGenesis::<Runtime>::new(),
+				frame_system::CheckEra::<Runtime>::from(generic::Era::Immortal),
+				runtime_common::CheckNonce::<Runtime>::from(0),
+				frame_system::CheckWeight::<Runtime>::new(),
+				module_evm::SetEvmOrigin::<Runtime>::new(),
+				module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+			);
+			let raw_payload = SignedPayload::new(call, extra).unwrap();
+			let signature = raw_payload.using_encoded(|payload| AccountKeyring::Alice.sign(payload));
+			let (call, extra, _) = raw_payload.deconstruct();
+			let evm_call =
+				UncheckedExtrinsic::new(call, Some((alice.clone().into(), signature.into(), extra))).unwrap();
+
+			// the timestamp inherent is skipped, the trace of the EVM call is at index 1
+			let traces =
+				Runtime::trace_block(Block::new(header, vec![timestamp, evm_call]), TracerConfig::CallTracer).unwrap();
+			assert_eq!(traces.len(), 1);
+			assert_eq!(traces[0].0, 1);
+			match &traces[0].1 {
+				TraceOutcome::Calls(calls) => {
+					assert_eq!(calls.len(), 1);
+					assert_eq!(calls[0].call_type, CallType::CALL);
+					assert_eq!(
+						calls[0].from,
+						EvmAddressMapping::<Runtime>::get_evm_address(&alice).unwrap()
+					);
+					assert_eq!(calls[0].to, target);
+					assert_eq!(calls[0].input, vec![1, 2, 3, 4]);
+				}
+				_ => panic!("unexpected trace outcome"),
+			}
+		});
+	}
 }
@@ -153,6 +153,30 @@ impl<T: frame_system::Config> module_evm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(9))
 	}
+	// Storage: `EVM::Accounts` (r:2 w:2)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EvmAccounts::Accounts` (r:2 w:0)
+	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `EVM::CodeInfos` (r:2 w:1)
+	// Proof: `EVM::CodeInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::AccountStorages` (r:1 w:0)
+	// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::ContractStorageSizes` (r:1 w:1)
+	// Proof: `EVM::ContractStorageSizes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::Codes` (r:0 w:1)
+	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::DeterministicDeployments` (r:0 w:1)
+	// Proof: `EVM::DeterministicDeployments` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	fn create_deterministic_predeploy_contract() -> Weight {
+		// Estimated from `create_predeploy_contract` plus the `DeterministicDeployments` write, not benchmarked yet.
+		Weight::from_parts(166_386_000, 9870)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
 	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
 	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	// Storage: `EVM::Accounts` (r:2 w:1)
@@ -178,6 +202,37 @@ impl<T: frame_system::Config> module_evm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Accounts` (r:2 w:1)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EvmAccounts::Accounts` (r:2 w:0)
+	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Digest` (r:1 w:0)
+	// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::Codes` (r:1 w:0)
+	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::ContractStorageSizes` (r:1 w:1)
+	// Proof: `EVM::ContractStorageSizes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::Accounts` (r:1 w:0) per call
+	// Storage: `EVM::Codes` (r:1 w:0) per call
+	// Storage: `EVM::ContractStorageSizes` (r:1 w:1) per call
+	/// The range of component `c` is `[1, 32]`.
+	/// The range of component `b` is `[0, 65536]`.
+	fn batch_call(c: u32, b: u32, ) -> Weight {
+		// Estimated from `call` plus the storage accessed by each call and the input copied, not benchmarked yet.
+		Weight::from_parts(134_446_000, 10224)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
 	// Storage: `EVM::Accounts` (r:1 w:1)
 	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)